@@ -74,6 +74,18 @@ impl DiskIo {
             .and_then(|name_ext| self.save_image(&name_ext, pixel_map))
     }
 
+    pub fn save_pareto_image(
+        &self,
+        name: &str,
+        point_idx: usize,
+        pixel_map: &PixelMap,
+    ) -> AppResult<String> {
+        extend_basename(name, &format!("_pareto_{}", point_idx))
+            .ok_or(String::from("Failed to generate file with extension."))
+            .map_err(AppError::IoError)
+            .and_then(|name_ext| self.save_image(&name_ext, pixel_map))
+    }
+
     pub fn save_conversion_image(&self, name: &str, pixel_map: &PixelMap) -> AppResult<String> {
         extend_basename(name, "_conv")
             .ok_or(format!("Failed to generate file with extension."))
@@ -81,6 +93,13 @@ impl DiskIo {
             .and_then(|name_ext| self.save_image(&name_ext, pixel_map))
     }
 
+    pub fn save_segments_image(&self, name: &str, pixel_map: &PixelMap) -> AppResult<String> {
+        extend_basename(name, "_segments")
+            .ok_or(String::from("Failed to generate file with extension."))
+            .map_err(AppError::IoError)
+            .and_then(|name_ext| self.save_image(&name_ext, pixel_map))
+    }
+
     pub fn load_data(&self, path: &str) -> AppResult<Data> {
         Data::from_file(path)
             .map_err(|_| format!("Failed to load data {}", path))
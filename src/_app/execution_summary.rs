@@ -1,4 +1,4 @@
-use crate::ant_colony::runner::{CycleSummary, EpochSummary};
+use crate::ant_colony::runner::{CycleSummary, RunSummary};
 use crate::steganography::data::Data;
 use crate::steganography::quality_assessment::QualityOption;
 
@@ -97,17 +97,20 @@ impl ToString for ExtractionSummary {
 
 pub struct TspSummary {
     cycle: CycleSummary,
-    epoch: EpochSummary,
+    run: RunSummary,
 }
 
 impl TspSummary {
-    pub fn new(cycle: CycleSummary, epoch: EpochSummary) -> Self {
-        TspSummary { epoch, cycle }
+    pub fn new(cycle: CycleSummary, run: RunSummary) -> Self {
+        TspSummary { run, cycle }
     }
 }
 
 impl ToString for TspSummary {
     fn to_string(&self) -> String {
-        format!("{}", self.epoch.to_string())
+        match self.run.epochs.as_slice() {
+            [single_epoch] => single_epoch.to_string(),
+            _ => self.run.to_string(),
+        }
     }
 }
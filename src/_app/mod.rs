@@ -3,19 +3,27 @@ mod execution_summary;
 
 use rand::{prelude::StdRng, SeedableRng};
 use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::cli::{EmbedCommand, ExtractCommand, Opts, SubCommand, TspCommand};
 use crate::common::cli_output::{CliOutput, CliOutputs};
 use crate::common::errors::AppError;
+use crate::common::utils::compare_float;
 
-use crate::ant_colony::ant_dispatcher::Dispatchers;
-use crate::ant_colony::colony::{Colony, Config, ConfigurableColony, StepwiseParallelColony};
+use crate::ant_colony::ant_dispatcher::{DispatcherStringConfig, Dispatchers};
+use crate::ant_colony::colony::{
+    Colony, Config, ConfigurableColony, IslandColony, MultiObjectiveColony, MultiObjectiveConfig,
+    Objective, ParetoPoint, StepwiseParallelColony,
+};
 use crate::ant_colony::graph::Graph;
 use crate::ant_colony::guiding_config::GuidingConfig;
-use crate::ant_colony::pheromone_updater::Updaters;
-use crate::ant_colony::runner::ColonyRunner;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone_updater::{UpdaterStringConfig, Updaters};
+use crate::ant_colony::runner::{ColonyRunner, StoppingCriterion};
 
-use crate::images::image_graph_converter::{Converters, ImageGraphConverter};
+use crate::images::image_graph_converter::{
+    Converters, ImageGraphConverter, ObjectivesStringConfig,
+};
 use crate::images::pixel_map::PixelMap;
 
 use crate::steganography::image_embedder::{EmbedInImage, MaskImageEmbedder};
@@ -25,8 +33,16 @@ use disk_io::DiskIo;
 use execution_summary::{EmbeddingSummary, ExecutionSummary, ExtractionSummary, TspSummary};
 
 pub type AppResult<T> = Result<T, AppError>;
-type UnionizedColony = StepwiseParallelColony<Updaters, Dispatchers, StdRng>;
+type UnionizedColony = IslandColony<StepwiseParallelColony<Updaters, Dispatchers, StdRng>>;
 type UnionizedColonyRunner = ColonyRunner<UnionizedColony, CliOutputs>;
+type MultiObjectiveUnionizedColony = MultiObjectiveColony<Updaters, Dispatchers, StdRng>;
+type MultiObjectiveColonyRunner = ColonyRunner<MultiObjectiveUnionizedColony, CliOutputs>;
+/// mask, visualized conversion, visualized segmentation and masks of the pareto front
+type MaskImages = (PixelMap, Option<PixelMap>, Option<PixelMap>, Vec<PixelMap>);
+
+/// weight of pheromone seeded from the coarser pyramid level,
+/// the rest are initial trails of the colony, so no edge starts without pheromone
+const PYRAMID_SEED_WEIGHT: f32 = 0.5;
 
 pub struct App {
     opts: Opts,
@@ -56,7 +72,7 @@ impl App {
 
         let transport_image = self.disk_io.load_image(img_name)?;
         let data = self.disk_io.load_data(&embed_opts.data)?;
-        let (pheromone_image, conversion_image) =
+        let (pheromone_image, conversion_image, segments_image, pareto_images) =
             self.generate_pheromone_mask(&self.opts, &transport_image)?;
 
         let (embedder, scaled_pheromone) =
@@ -74,6 +90,12 @@ impl App {
         if let Some(img) = conversion_image {
             let _ = self.disk_io.save_conversion_image(img_name, &img)?;
         }
+        if let Some(img) = segments_image {
+            let _ = self.disk_io.save_segments_image(img_name, &img)?;
+        }
+        for (point_idx, img) in pareto_images.iter().enumerate() {
+            let _ = self.disk_io.save_pareto_image(img_name, point_idx, img)?;
+        }
         let output_path = self.disk_io.save_steg_image(img_name, &steganogram)?;
 
         let summary = EmbeddingSummary::new(
@@ -94,7 +116,8 @@ impl App {
         let transport_image = self.disk_io.load_image(&extract_opts.image)?;
         let steg_image = self.disk_io.load_image(&extract_opts.steg)?;
 
-        let (pheromone_image, _) = self.generate_pheromone_mask(&self.opts, &transport_image)?;
+        let (pheromone_image, _, _, _) =
+            self.generate_pheromone_mask(&self.opts, &transport_image)?;
 
         let (embedder, _) = Self::prepare_embedder_and_mask(&self.opts, &pheromone_image);
         let extracted = embedder.extract(&steg_image);
@@ -108,36 +131,242 @@ impl App {
         let mut rng = StdRng::seed_from_u64(self.opts.seed);
 
         let graph = self.read_tsp_graph(&mut rng, tsp_opts)?;
-        let colony_runner = self.run_colony(&self.opts, rng, graph)?;
-        let (last_cycle, last_epoch) = colony_runner
+        let colony_runner = self.run_colony(&self.opts, rng, graph, Option::None)?;
+        let (last_cycle, _) = colony_runner
             .last_summaries()
             .ok_or(AppError::ColonyExecutionFailed)?;
 
-        let summary = TspSummary::new(last_cycle, last_epoch);
+        let summary = TspSummary::new(last_cycle, colony_runner.run_summary());
 
         Result::Ok(summary)
     }
 
+    /// Returns the mask, visualized conversion, visualized segmentation
+    /// and masks of the pareto front (multi-objective only)
     fn generate_pheromone_mask(
         &self,
         opts: &Opts,
         transport_image: &PixelMap,
-    ) -> AppResult<(PixelMap, Option<PixelMap>)> {
-        let rng = StdRng::seed_from_u64(opts.seed);
+    ) -> AppResult<MaskImages> {
+        if let Option::Some(objectives) = opts.objectives.as_ref() {
+            if opts.pyramid_levels > 1 {
+                return Result::Err(AppError::ConfigError(format!(
+                    "pyramid levels can not be combined with objectives"
+                )));
+            }
+
+            return self.generate_multi_objective_mask(opts, objectives, transport_image);
+        }
+
         let downscaled_transport_image = Self::downscale_transport_image(opts, transport_image);
+        let pyramid = downscaled_transport_image.pyramid(opts.pyramid_levels);
+        let level_cycles = Self::parse_pyramid_cycles(opts, pyramid.len())?;
+        let level_converters = pyramid
+            .iter()
+            .map(|level_image| Self::parse_image_converter(opts, level_image))
+            .collect::<AppResult<Vec<_>>>()?;
+
+        // every level but the coarsest is seeded, so it is checked before any colony runs
+        if !pyramid
+            .iter()
+            .zip(level_converters.iter())
+            .skip(1)
+            .all(|(level_image, converter)| converter.can_seed_from_coarser_level(level_image))
+        {
+            return Result::Err(AppError::ConfigError(format!(
+                "converter can not seed pheromone from a coarser pyramid level"
+            )));
+        }
 
-        let img_graph_converter = Self::parse_image_converter(opts, &downscaled_transport_image)?;
-        let conversion_visualization = img_graph_converter.visualize_conversion();
-        let graph = img_graph_converter.img_to_graph();
+        // only the last (finest) level produces the mask, coarser ones seed its pheromone
+        let (img_graph_converter, pheromone) = pyramid
+            .iter()
+            .zip(level_converters)
+            .zip(level_cycles)
+            .enumerate()
+            .try_fold(
+                Option::None,
+                |previous_level, (level_idx, ((level_image, converter), cycles))| {
+                    let seed = match previous_level {
+                        Option::Some((previous_converter, previous_pheromone)) => {
+                            let seed = converter.seed_from_coarser_level(
+                                level_image,
+                                &previous_converter,
+                                &previous_pheromone,
+                            );
+
+                            Option::Some(seed.ok_or(AppError::ConfigError(format!(
+                                "converter can not seed pheromone from a coarser pyramid level"
+                            )))?)
+                        }
+                        Option::None => Option::None,
+                    };
+
+                    if pyramid.len() > 1 {
+                        self.cli.print(&format!(
+                            "Pyramid level #{}: {}x{}, cycles: {}",
+                            level_idx,
+                            level_image.width,
+                            level_image.height,
+                            cycles.map_or(format!("-"), |cycles| cycles.to_string())
+                        ));
+                    }
+
+                    let level_opts = Opts {
+                        cycles,
+                        ..opts.clone()
+                    };
+                    let rng = StdRng::seed_from_u64(opts.seed);
+                    let colony_runner =
+                        self.run_colony(&level_opts, rng, converter.img_to_graph(), seed)?;
+                    let pheromone = colony_runner.get_colony().get_pheromone().clone();
+
+                    AppResult::Ok(Option::Some((converter, pheromone)))
+                },
+            )?
+            .ok_or(AppError::ColonyExecutionFailed)?;
 
-        let colony_runner = self.run_colony(opts, rng, graph)?;
-        let colony = colony_runner.get_colony();
-        let pheromone = colony.get_pheromone();
+        let conversion_visualization = img_graph_converter.visualize_conversion();
+        let segments_visualization = img_graph_converter.visualize_segments();
         let visualized_pheromone = img_graph_converter
-            .visualize_pheromone(pheromone)
+            .visualize_pheromone(&pheromone)
             .resize(transport_image.width, transport_image.height);
 
-        Result::Ok((visualized_pheromone, conversion_visualization))
+        Result::Ok((
+            visualized_pheromone,
+            conversion_visualization,
+            segments_visualization,
+            Vec::new(),
+        ))
+    }
+
+    /// Masks are visualized with the converter of the first objective
+    fn generate_multi_objective_mask(
+        &self,
+        opts: &Opts,
+        objectives: &ObjectivesStringConfig,
+        transport_image: &PixelMap,
+    ) -> AppResult<MaskImages> {
+        let rng = StdRng::seed_from_u64(opts.seed);
+        let downscaled_transport_image = Self::downscale_transport_image(opts, transport_image);
+
+        let converters = objectives
+            .0
+            .iter()
+            .map(|config| {
+                Converters::from_string_config_and_pixel_map(&downscaled_transport_image, config)
+                    .ok_or(String::from("invalid objective converter arg"))
+                    .map_err(AppError::IoError)
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+        let graphs = converters
+            .iter()
+            .map(|converter| Arc::new(converter.img_to_graph()))
+            .collect::<Vec<_>>();
+        let conversion_visualization = converters[0].visualize_conversion();
+        let segments_visualization = converters[0].visualize_segments();
+
+        if graphs
+            .iter()
+            .any(|graph| graph.get_edge_index() != graphs[0].get_edge_index())
+        {
+            return Result::Err(AppError::IoError(String::from(
+                "objectives must produce graphs of the same structure",
+            )));
+        }
+
+        let colony_runner = self.run_multi_objective_colony(opts, rng, graphs)?;
+        let colony = colony_runner.get_colony();
+        let pareto_front = colony.get_pareto_front();
+        let pareto_images = pareto_front
+            .iter()
+            .map(|point| {
+                converters[0]
+                    .visualize_pheromone(&point.pheromone)
+                    .resize(transport_image.width, transport_image.height)
+            })
+            .collect::<Vec<_>>();
+        let capacities = pareto_images
+            .iter()
+            .map(|img| MaskImageEmbedder::new(img).estimate_embeddable_bits())
+            .collect::<Vec<_>>();
+
+        pareto_front
+            .iter()
+            .zip(capacities.iter())
+            .enumerate()
+            .for_each(|(point_idx, (point, capacity))| {
+                self.cli.print(&format!(
+                    "Pareto point #{}: {}, capacity: {} bits",
+                    point_idx, point, capacity
+                ))
+            });
+
+        let selected_idx = Self::select_pareto_point(opts, &pareto_front, &capacities)?;
+        self.cli
+            .print(&format!("Selected pareto point #{}", selected_idx));
+
+        Result::Ok((
+            pareto_images[selected_idx].clone(),
+            conversion_visualization,
+            segments_visualization,
+            pareto_images,
+        ))
+    }
+
+    /// Point closest to given weights, otherwise the one which fits target capacity
+    /// with the least surplus, otherwise the one closest to equal weights
+    fn select_pareto_point(
+        opts: &Opts,
+        pareto_front: &[ParetoPoint],
+        capacities: &[usize],
+    ) -> AppResult<usize> {
+        let n_objectives = pareto_front
+            .first()
+            .map(|point| point.weights.len())
+            .ok_or(AppError::ColonyExecutionFailed)?;
+        let equal_weights = vec![1.0 / n_objectives as f32; n_objectives];
+
+        let closest_to = |weights: &[f32]| {
+            pareto_front
+                .iter()
+                .map(|point| {
+                    point
+                        .weights
+                        .iter()
+                        .zip(weights.iter())
+                        .map(|(a, b)| (a - b).powi(2))
+                        .sum::<f32>()
+                })
+                .enumerate()
+                .min_by(|(_, dist_a), (_, dist_b)| compare_float(dist_a, dist_b))
+                .map(|(idx, _)| idx)
+                .unwrap_or_default()
+        };
+
+        match (&opts.objective_weights, opts.target_capacity) {
+            (Option::Some(weights), _) => iif!(
+                weights.len() == n_objectives,
+                Result::Ok(closest_to(weights.get_weights())),
+                Result::Err(AppError::IoError(String::from(
+                    "objective weights must be given for each objective"
+                )))
+            ),
+            (Option::None, Option::Some(capacity)) => capacities
+                .iter()
+                .enumerate()
+                .filter(|(_, point_capacity)| **point_capacity >= capacity.bits())
+                .min_by_key(|(_, point_capacity)| **point_capacity)
+                .or_else(|| {
+                    capacities
+                        .iter()
+                        .enumerate()
+                        .max_by_key(|(_, point_capacity)| **point_capacity)
+                })
+                .map(|(idx, _)| idx)
+                .ok_or(AppError::ColonyExecutionFailed),
+            (Option::None, Option::None) => Result::Ok(closest_to(&equal_weights)),
+        }
     }
 
     fn run_colony(
@@ -145,8 +374,90 @@ impl App {
         opts: &Opts,
         rng: StdRng,
         graph: Graph,
+        seed: Option<Pheromone>,
     ) -> AppResult<UnionizedColonyRunner> {
-        let graph = Rc::new(graph);
+        let graph = Arc::new(graph);
+        let ant_count = opts.ants.unwrap_or(graph.get_amount_of_nodes());
+        let num_of_steps_per_cycle = opts.steps.unwrap_or(graph.get_amount_of_nodes());
+        let guide = GuidingConfig::from_graph(
+            ant_count,
+            num_of_steps_per_cycle,
+            opts.updater.clone(),
+            &graph,
+        );
+        let n_islands = opts.islands.unwrap_or(1).max(1);
+
+        // first island keeps the given rng, so a single island behaves like a plain colony
+        let rngs = std::iter::once(rng).chain(
+            (1..n_islands as u64)
+                .map(|island_idx| StdRng::seed_from_u64(opts.seed.wrapping_add(island_idx))),
+        );
+        let differs_per_island =
+            opts.island_updaters.is_some() || opts.island_dispatchers.is_some();
+
+        self.cli.print(&guide);
+
+        let islands = rngs
+            .enumerate()
+            .map(|(island_idx, rng)| {
+                let updater_config = opts
+                    .island_updaters
+                    .as_ref()
+                    .and_then(|configs| configs.get(island_idx))
+                    .unwrap_or(&opts.updater);
+                let dispatcher_config = opts
+                    .island_dispatchers
+                    .as_ref()
+                    .and_then(|configs| configs.get(island_idx))
+                    .unwrap_or(&opts.dispatcher);
+                let island_guide = GuidingConfig {
+                    pheromone_updater_type: updater_config.clone(),
+                    ..guide.clone()
+                };
+
+                let config = Config {
+                    ant_count,
+                    num_of_steps_per_cycle,
+                    pheromone_updater: Self::parse_pheromone_updater(
+                        updater_config,
+                        &island_guide,
+                    )?,
+                    ant_dispatcher: Self::parse_dispatcher(dispatcher_config, &island_guide)?,
+                    rng,
+                    stagnation: opts.stagnation,
+                    problem: opts.problem.clone(),
+                };
+
+                // islands share the configuration unless given per island, then it is enough to show it once
+                if island_idx == 0 || differs_per_island {
+                    self.cli.print(&config);
+                }
+
+                let island = StepwiseParallelColony::new(config, Arc::clone(&graph));
+
+                // each island is seeded separately to keep the scale of its own trails
+                Result::Ok(Self::seed_pheromone(island, seed.as_ref()))
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let colony = IslandColony::new(islands, opts.migration_interval, opts.migration);
+
+        if n_islands > 1 {
+            self.cli.print(&colony);
+        }
+
+        let runner = ColonyRunner::new(colony, Arc::clone(&graph), Rc::clone(&self.cli));
+
+        Self::execute_runner(runner, &opts)
+    }
+
+    fn run_multi_objective_colony(
+        &self,
+        opts: &Opts,
+        rng: StdRng,
+        graphs: Vec<Arc<Graph>>,
+    ) -> AppResult<MultiObjectiveColonyRunner> {
+        let graph = Arc::clone(&graphs[0]);
         let ant_count = opts.ants.unwrap_or(graph.get_amount_of_nodes());
         let num_of_steps_per_cycle = opts.steps.unwrap_or(graph.get_amount_of_nodes());
         let guide = GuidingConfig::from_graph(
@@ -155,24 +466,47 @@ impl App {
             opts.updater.clone(),
             &graph,
         );
-        let ant_dispatcher = Self::parse_dispatcher(&opts, &guide)?;
-        let pheromone_updater = Self::parse_pheromone_updater(&opts, &guide)?;
 
-        let config = Config {
+        self.cli.print(&guide);
+
+        // distances of objectives may differ in scale, so each updater gets its own guide
+        let objectives = graphs
+            .into_iter()
+            .map(|graph| {
+                let objective_guide = GuidingConfig::from_graph(
+                    ant_count,
+                    num_of_steps_per_cycle,
+                    opts.updater.clone(),
+                    &graph,
+                );
+
+                Result::Ok(Objective {
+                    pheromone_updater: Self::parse_pheromone_updater(
+                        &opts.updater,
+                        &objective_guide,
+                    )?,
+                    graph,
+                })
+            })
+            .collect::<AppResult<Vec<_>>>()?;
+
+        let config = MultiObjectiveConfig {
             ant_count,
             num_of_steps_per_cycle,
-            pheromone_updater,
-            ant_dispatcher,
+            objectives,
+            ant_dispatcher: Self::parse_dispatcher(&opts.dispatcher, &guide)?,
             rng,
+            problem: opts.problem.clone(),
+            stagnation: opts.stagnation,
+            weight_divisions: opts.weight_divisions,
         };
+        let colony = MultiObjectiveColony::new(config);
 
-        self.cli.print(&guide);
-        self.cli.print(&config);
+        self.cli.print(&colony);
 
-        let colony = StepwiseParallelColony::new(config, Rc::clone(&graph));
-        let runner = ColonyRunner::new(colony, Rc::clone(&graph), Rc::clone(&self.cli));
+        let runner = ColonyRunner::new(colony, graph, Rc::clone(&self.cli));
 
-        Self::execute_runner(runner, &opts)
+        Self::execute_runner(runner, opts)
     }
 
     fn downscale_transport_image(opts: &Opts, transport_image: &PixelMap) -> PixelMap {
@@ -191,36 +525,97 @@ impl App {
         }
     }
 
-    fn parse_dispatcher(opts: &Opts, guide: &GuidingConfig) -> AppResult<Dispatchers> {
-        Dispatchers::from_string_config(&opts.dispatcher, Option::Some(guide))
+    fn parse_dispatcher(
+        config: &DispatcherStringConfig,
+        guide: &GuidingConfig,
+    ) -> AppResult<Dispatchers> {
+        Dispatchers::from_string_config(config, Option::Some(guide))
             .ok_or(format!("invalid dispatcher arg"))
             .map_err(AppError::IoError)
     }
 
-    fn parse_pheromone_updater(opts: &Opts, guide: &GuidingConfig) -> AppResult<Updaters> {
-        Updaters::from_string_config(&opts.updater, Option::Some(guide))
+    fn parse_pheromone_updater(
+        config: &UpdaterStringConfig,
+        guide: &GuidingConfig,
+    ) -> AppResult<Updaters> {
+        Updaters::from_string_config(config, Option::Some(guide))
             .ok_or(format!("invalid updater arg"))
             .map_err(AppError::IoError)
     }
 
+    /// Cycles of each pyramid level, by default every level trains for the given cycles
+    /// Seed only guides the search, trails keep the scale of the initial ones
+    fn seed_pheromone<C: Colony>(colony: C, seed: Option<&Pheromone>) -> C {
+        match seed {
+            Option::Some(seed) => {
+                let initial = colony.get_pheromone().clone();
+                let seed = seed
+                    .clone()
+                    .scale_all_pheromone_values(initial.get_max_value());
+
+                colony.replace_pheromone(initial.blend(&seed, PYRAMID_SEED_WEIGHT))
+            }
+            Option::None => colony,
+        }
+    }
+
+    fn parse_pyramid_cycles(opts: &Opts, n_levels: usize) -> AppResult<Vec<Option<usize>>> {
+        if opts.pyramid_cycles.is_empty() {
+            return Result::Ok(vec![opts.cycles; n_levels]);
+        }
+
+        iif!(
+            opts.pyramid_cycles.len() == n_levels,
+            Option::Some(
+                opts.pyramid_cycles
+                    .iter()
+                    .cloned()
+                    .map(Option::Some)
+                    .collect()
+            ),
+            Option::None
+        )
+        .ok_or(format!(
+            "pyramid cycles must be given for each of {} levels",
+            n_levels
+        ))
+        .map_err(AppError::ConfigError)
+    }
+
     fn parse_image_converter(opts: &Opts, pixel_map: &PixelMap) -> AppResult<Converters> {
         Converters::from_string_config_and_pixel_map(pixel_map, &opts.converter)
             .ok_or(format!("invalid converter arg"))
             .map_err(AppError::IoError)
     }
 
-    fn execute_runner(
-        runner: UnionizedColonyRunner,
+    fn execute_runner<C: Colony>(
+        runner: ColonyRunner<C, CliOutputs>,
         opts: &Opts,
-    ) -> AppResult<UnionizedColonyRunner> {
-        if let Option::Some(n_cycles) = opts.cycles {
-            Option::Some(runner.train(1, n_cycles))
-        } else if let Option::Some(n_until) = opts.stop_after {
-            Option::Some(runner.train_n_until_no_improvement(n_until))
-        } else {
-            Option::None
-        }
-        .ok_or(format!("you must specify cycles or stop_after"))
+    ) -> AppResult<ColonyRunner<C, CliOutputs>> {
+        let criteria = vec![
+            opts.cycles.map(StoppingCriterion::Cycles),
+            opts.stop_after.map(StoppingCriterion::NoImprovement),
+            opts.time_limit.map(StoppingCriterion::TimeLimit),
+            opts.target_distance.map(StoppingCriterion::TargetDistance),
+            opts.min_pheromone_variance
+                .map(StoppingCriterion::MinPheromoneVariance),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+
+        iif!(
+            criteria.is_empty(),
+            Option::None,
+            Option::Some(runner.train_epochs_until(
+                opts.epochs,
+                &criteria,
+                opts.epoch_transition
+            ))
+        )
+        .ok_or(format!(
+            "you must specify cycles, stop_after, time_limit, target_distance or min_pheromone_variance"
+        ))
         .map_err(AppError::IoError)
     }
 
@@ -242,14 +637,23 @@ impl App {
 
     fn read_tsp_graph(&self, rng: &mut StdRng, tsp_opts: &TspCommand) -> AppResult<Graph> {
         if let Option::Some(n_cities) = tsp_opts.n_cities {
-            Some(Graph::random_tsp_graph(rng, n_cities))
+            iif!(
+                tsp_opts.directed,
+                Some(Graph::random_atsp_graph(rng, n_cities)),
+                Some(Graph::random_tsp_graph(rng, n_cities))
+            )
         } else if let Option::Some(path) = tsp_opts.graph.as_ref() {
             let csv = self.disk_io.load_csv(path)?;
             Some(Graph::from_coordinate_csv(&csv))
+        } else if let Option::Some(path) = tsp_opts.matrix.as_ref() {
+            let csv = self.disk_io.load_csv(path)?;
+            let graph = Graph::from_distance_matrix_csv(&csv).map_err(AppError::IoError)?;
+
+            Some(graph)
         } else {
             None
         }
-        .ok_or(format!("you must specify n-cities or graph"))
+        .ok_or(format!("you must specify n-cities, graph or matrix"))
         .map_err(AppError::IoError)
     }
 }
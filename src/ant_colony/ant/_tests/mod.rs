@@ -24,4 +24,26 @@ mod graph_tests {
         assert!(ant.has_visited(&44));
         assert!(ant.has_visited(&45));
     }
+
+    #[test]
+    fn it_records_the_order_of_visited_nodes() {
+        let ant = Ant::with_capacity(2, 4)
+            .move_to_node(0)
+            .move_to_node(3)
+            .move_to_node(1);
+
+        assert_eq!(ant.get_path(), &[2, 0, 3, 1]);
+    }
+
+    #[test]
+    fn it_forgets_visited_nodes_on_reset() {
+        let ant = Ant::with_capacity(2, 4).move_to_node(0).reset(3);
+
+        assert_eq!(ant.inital_node, 3);
+        assert_eq!(ant.current_node, 3);
+        assert_eq!(ant.get_path(), &[3]);
+        assert!(ant.has_visited(&3));
+        assert!(!ant.has_visited(&2));
+        assert!(!ant.has_visited(&0));
+    }
 }
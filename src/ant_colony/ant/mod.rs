@@ -4,53 +4,78 @@ use std::collections::HashSet;
 use std::fmt::Display;
 
 use crate::ant_colony::graph::NodeId;
+use crate::common::utils::BitSet;
 
 #[derive(Debug)]
 pub struct Ant {
     pub inital_node: NodeId,
     pub current_node: NodeId,
-    visited: HashSet<NodeId>,
+    /// tabu list, node ids are used directly as bit positions
+    visited: BitSet,
+    /// nodes in order of visiting, starting with the initial node
+    path: Vec<NodeId>,
 }
 
 impl Ant {
     pub fn new(inital_node: NodeId) -> Self {
-        let mut visited = HashSet::new();
+        Ant::with_capacity(inital_node, 0)
+    }
+
+    /// Creates an ant with memory preallocated for given number of nodes
+    pub fn with_capacity(inital_node: NodeId, n_nodes: usize) -> Self {
+        let ant = Ant {
+            inital_node,
+            current_node: inital_node,
+            visited: BitSet::with_capacity(n_nodes),
+            path: Vec::with_capacity(n_nodes + 1),
+        };
+
+        ant.reset(inital_node)
+    }
+
+    /// Places the ant on a new initial node, keeping its allocated memory
+    pub fn reset(mut self, inital_node: NodeId) -> Self {
+        self.visited.clear();
+        self.path.clear();
 
-        visited.insert(inital_node);
+        self.visited.insert(inital_node as usize);
+        self.path.push(inital_node);
 
         Ant {
             inital_node,
             current_node: inital_node,
-            visited,
+            ..self
         }
     }
 
     pub fn move_to_node(mut self, next_node: NodeId) -> Self {
-        self.visited.insert(next_node);
+        self.visited.insert(next_node as usize);
+        self.path.push(next_node);
+        self.current_node = next_node;
 
-        Ant {
-            current_node: next_node,
-            ..self
-        }
+        self
     }
 
     pub fn has_visited(&self, node_id: &NodeId) -> bool {
-        self.visited.contains(node_id)
+        self.visited.contains(*node_id as usize)
     }
 
     pub fn get_visited(&self) -> HashSet<NodeId> {
-        self.visited.clone()
+        self.path.iter().cloned().collect()
+    }
+
+    pub fn get_path(&self) -> &[NodeId] {
+        &self.path
     }
 }
 
 impl Display for Ant {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let path = self.visited.iter().collect::<Vec<_>>();
-        let path_length = path.len();
+        let path_length = self.path.len();
         let exceeds_display_length = path_length > 15;
         let ellispis = iif!(exceeds_display_length, "...", "");
 
-        let vector_to_display = iif!(exceeds_display_length, &path[0..15], &path[..]);
+        let vector_to_display = iif!(exceeds_display_length, &self.path[0..15], &self.path[..]);
 
         write!(
             f,
@@ -28,6 +28,7 @@ mod ant_dispatcher_tests {
                 to: 0,
                 distance: 1.0,
                 visibility: 1.0,
+                idx: Option::None,
             },
             AdjacencyListEntry {
                 key: 1,
@@ -35,6 +36,7 @@ mod ant_dispatcher_tests {
                 to: 0,
                 distance: 2.0,
                 visibility: 0.5,
+                idx: Option::None,
             },
             AdjacencyListEntry {
                 key: 2,
@@ -42,6 +44,7 @@ mod ant_dispatcher_tests {
                 to: 0,
                 distance: 5.0,
                 visibility: 0.2,
+                idx: Option::None,
             },
         ]
     }
@@ -1,9 +1,9 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph};
-use crate::ant_colony::pheromone::Pheromone;
-use crate::ant_colony::{ant::Ant, guiding_config::WithGuidingConfig};
+use crate::ant_colony::graph::AdjacencyListEntry;
+use crate::ant_colony::guiding_config::WithGuidingConfig;
+use crate::ant_colony::pheromone::PheromoneRead;
 
 use super::{
     AntDispatcher, BasicAntDispatcher, BiasedAntDispatcher, ColonyAntDispatcher,
@@ -30,24 +30,32 @@ impl Display for Dispatchers {
 }
 
 impl AntDispatcher for Dispatchers {
-    fn select_next_edge(
+    fn select_next_edge<P: PheromoneRead>(
         &self,
-        ant: &Ant,
-        graph: &Graph,
-        pheromone: &Pheromone,
+        possible_next_edges: &[AdjacencyListEntry],
+        pheromone: &P,
         sample_seed: f32,
         strategy_seed: f32,
     ) -> Option<AdjacencyListEntry> {
         match self {
-            Dispatchers::Basic(dispatcher) => {
-                dispatcher.select_next_edge(ant, graph, pheromone, sample_seed, strategy_seed)
-            }
-            Dispatchers::Biased(dispatcher) => {
-                dispatcher.select_next_edge(ant, graph, pheromone, sample_seed, strategy_seed)
-            }
-            Dispatchers::Colony(dispatcher) => {
-                dispatcher.select_next_edge(ant, graph, pheromone, sample_seed, strategy_seed)
-            }
+            Dispatchers::Basic(dispatcher) => dispatcher.select_next_edge(
+                possible_next_edges,
+                pheromone,
+                sample_seed,
+                strategy_seed,
+            ),
+            Dispatchers::Biased(dispatcher) => dispatcher.select_next_edge(
+                possible_next_edges,
+                pheromone,
+                sample_seed,
+                strategy_seed,
+            ),
+            Dispatchers::Colony(dispatcher) => dispatcher.select_next_edge(
+                possible_next_edges,
+                pheromone,
+                sample_seed,
+                strategy_seed,
+            ),
         }
     }
 }
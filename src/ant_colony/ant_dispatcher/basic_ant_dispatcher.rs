@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 use crate::ant_colony::graph::AdjacencyListEntry;
 use crate::ant_colony::guiding_config::WithGuidingConfig;
-use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone::PheromoneRead;
 
 use super::LikelihoodAntDispatcher;
 
@@ -14,15 +14,15 @@ pub struct BasicAntDispatcher;
 ///
 /// p_{if}=\frac{\tau_{ij}}{d_{ij}}
 impl LikelihoodAntDispatcher for BasicAntDispatcher {
-    fn cacluclate_node_likelihoods(
+    fn cacluclate_node_likelihoods<P: PheromoneRead>(
         &self,
         possible_next_edges: &[AdjacencyListEntry],
-        pheromone: &Pheromone,
+        pheromone: &P,
     ) -> Vec<f32> {
         possible_next_edges
             .iter()
             .map(|edge| {
-                let pheromone_level = pheromone.get_pheromone_for_edge(edge.key);
+                let pheromone_level = pheromone.get_pheromone_for_edge(edge);
 
                 edge.visibility * pheromone_level
             })
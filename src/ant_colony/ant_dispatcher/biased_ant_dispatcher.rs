@@ -4,7 +4,7 @@ use std::str::FromStr;
 
 use crate::ant_colony::graph::AdjacencyListEntry;
 use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
-use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone::PheromoneRead;
 use crate::ant_colony::pheromone_updater::UpdaterStringConfig;
 
 use super::LikelihoodAntDispatcher;
@@ -29,15 +29,15 @@ impl BiasedAntDispatcher {
 }
 
 impl LikelihoodAntDispatcher for BiasedAntDispatcher {
-    fn cacluclate_node_likelihoods(
+    fn cacluclate_node_likelihoods<P: PheromoneRead>(
         &self,
         possible_next_edges: &[AdjacencyListEntry],
-        pheromone: &Pheromone,
+        pheromone: &P,
     ) -> Vec<f32> {
         possible_next_edges
             .iter()
             .map(|edge| {
-                let pheromone_level = pheromone.get_pheromone_for_edge(edge.key);
+                let pheromone_level = pheromone.get_pheromone_for_edge(edge);
 
                 edge.visibility.powf(self.visibility_bias)
                     * pheromone_level.powf(self.pheromone_bias)
@@ -69,7 +69,7 @@ impl WithGuidingConfig for BiasedAntDispatcher {
         let (pheromone_bias, visibility_bias) = match guide.pheromone_updater_type {
             UpdaterStringConfig::Const(_) => (2.0, 2.0),
             UpdaterStringConfig::Average(_) => (1.0, 1.0),
-            UpdaterStringConfig::Cyclical(_) => (1.0, 2.5),
+            UpdaterStringConfig::Cyclical(_) | UpdaterStringConfig::Elitist(_) => (1.0, 2.5),
             UpdaterStringConfig::MaxMin(_) => (1.0, 2.0),
             _ => (1.0, 1.0),
         };
@@ -2,10 +2,9 @@ use itertools::Itertools;
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::ant_colony::ant::Ant;
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph};
+use crate::ant_colony::graph::AdjacencyListEntry;
 use crate::ant_colony::guiding_config::WithGuidingConfig;
-use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone::PheromoneRead;
 use crate::common::utils::{compare_float, weighted_sample};
 
 use super::AntDispatcher;
@@ -31,16 +30,16 @@ impl ColonyAntDispatcher {
         }
     }
 
-    fn evalutate_edge(&self, pheromone: &Pheromone, edge: &AdjacencyListEntry) -> f32 {
-        let pheromone_level = pheromone.get_pheromone_for_edge(edge.key);
+    fn evalutate_edge<P: PheromoneRead>(&self, pheromone: &P, edge: &AdjacencyListEntry) -> f32 {
+        let pheromone_level = pheromone.get_pheromone_for_edge(edge);
 
         edge.visibility.powf(self.visibility_bias) * pheromone_level
     }
 
-    fn try_expoit_best_edge(
+    fn try_expoit_best_edge<P: PheromoneRead>(
         &self,
         possible_next_edges: &[AdjacencyListEntry],
-        pheromone: &Pheromone,
+        pheromone: &P,
         strategy_seed: f32,
     ) -> Option<AdjacencyListEntry> {
         if strategy_seed < self.exploitation_rate {
@@ -58,10 +57,10 @@ impl ColonyAntDispatcher {
             .map(|edge| edge.to_owned())
     }
 
-    fn get_explored_edge(
+    fn get_explored_edge<P: PheromoneRead>(
         &self,
         possible_next_edges: &[AdjacencyListEntry],
-        pheromone: &Pheromone,
+        pheromone: &P,
         sample_seed: f32,
     ) -> Option<AdjacencyListEntry> {
         let node_likelihood = possible_next_edges
@@ -69,23 +68,20 @@ impl ColonyAntDispatcher {
             .map(|edge| self.evalutate_edge(pheromone, edge))
             .collect::<Vec<_>>();
 
-        weighted_sample(&possible_next_edges, &node_likelihood, sample_seed)
+        weighted_sample(possible_next_edges, &node_likelihood, sample_seed)
     }
 }
 
 impl AntDispatcher for ColonyAntDispatcher {
-    fn select_next_edge(
+    fn select_next_edge<P: PheromoneRead>(
         &self,
-        ant: &Ant,
-        graph: &Graph,
-        pheromone: &Pheromone,
+        possible_next_edges: &[AdjacencyListEntry],
+        pheromone: &P,
         sample_seed: f32,
         strategy_seed: f32,
     ) -> Option<AdjacencyListEntry> {
-        let possible_next_edges = self.get_possible_next_edges_for_ant(ant, graph);
-
-        self.try_expoit_best_edge(&possible_next_edges, pheromone, strategy_seed)
-            .or_else(|| self.get_explored_edge(&possible_next_edges, pheromone, sample_seed))
+        self.try_expoit_best_edge(possible_next_edges, pheromone, strategy_seed)
+            .or_else(|| self.get_explored_edge(possible_next_edges, pheromone, sample_seed))
     }
 }
 
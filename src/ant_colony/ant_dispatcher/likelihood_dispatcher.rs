@@ -1,10 +1,9 @@
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::ant_colony::ant::Ant;
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph};
+use crate::ant_colony::graph::AdjacencyListEntry;
 use crate::ant_colony::guiding_config::WithGuidingConfig;
-use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone::PheromoneRead;
 use crate::common::utils::weighted_sample;
 
 use super::AntDispatcher;
@@ -16,27 +15,24 @@ use super::AntDispatcher;
 /// Dispatcher implementing the trait are only required to calculate a vector
 /// of probabilities coresponding to given edges.
 pub trait LikelihoodAntDispatcher: WithGuidingConfig + Display + Send + Sync + FromStr {
-    fn cacluclate_node_likelihoods(
+    fn cacluclate_node_likelihoods<P: PheromoneRead>(
         &self,
         possible_next_edges: &[AdjacencyListEntry],
-        pheromone: &Pheromone,
+        pheromone: &P,
     ) -> Vec<f32>;
 }
 
 impl<D: LikelihoodAntDispatcher> AntDispatcher for D {
     #[cfg_attr(feature = "profiler", flame)]
-    fn select_next_edge(
+    fn select_next_edge<P: PheromoneRead>(
         &self,
-        ant: &Ant,
-        graph: &Graph,
-        pheromone: &Pheromone,
+        possible_next_edges: &[AdjacencyListEntry],
+        pheromone: &P,
         sample_seed: f32,
         _strategy_seed: f32,
     ) -> Option<AdjacencyListEntry> {
-        let possible_next_edges = self.get_possible_next_edges_for_ant(ant, graph);
-
-        let node_likelihood = self.cacluclate_node_likelihoods(&possible_next_edges, pheromone);
+        let node_likelihood = self.cacluclate_node_likelihoods(possible_next_edges, pheromone);
 
-        weighted_sample(&possible_next_edges, &node_likelihood, sample_seed)
+        weighted_sample(possible_next_edges, &node_likelihood, sample_seed)
     }
 }
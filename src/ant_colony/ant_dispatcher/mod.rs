@@ -10,9 +10,9 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 use crate::ant_colony::ant::Ant;
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph};
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
 use crate::ant_colony::guiding_config::WithGuidingConfig;
-use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone::PheromoneRead;
 
 pub use _union::Dispatchers;
 pub use basic_ant_dispatcher::BasicAntDispatcher;
@@ -27,47 +27,43 @@ pub trait AntDispatcher: WithGuidingConfig + Display + Send + Sync + Sized + Fro
         graph: &Graph,
         rng: &mut R,
     ) -> Vec<Ant> {
-        let node_ids = graph.get_node_ids();
+        self.replace_ants_on_graph(Vec::new(), num_of_ants, graph, graph.get_node_ids(), rng)
+    }
+
+    /// Same as `place_ants_on_graph`, but reuses memory of the given ants
+    /// and places them only on the given start nodes
+    fn replace_ants_on_graph<R: Rng>(
+        &self,
+        ants: Vec<Ant>,
+        num_of_ants: usize,
+        graph: &Graph,
+        start_nodes: &[NodeId],
+        rng: &mut R,
+    ) -> Vec<Ant> {
+        let capacity = graph
+            .get_node_ids()
+            .last()
+            .map_or(0, |node_id| *node_id as usize + 1);
+        let mut reusable_ants = ants.into_iter();
 
         (0..num_of_ants)
-            .filter_map(|_| node_ids.iter().choose(rng).cloned())
-            .map(Ant::new)
+            .filter_map(|_| start_nodes.iter().choose(rng).cloned())
+            .map(|node_id| match reusable_ants.next() {
+                Option::Some(ant) => ant.reset(node_id),
+                Option::None => Ant::with_capacity(node_id, capacity),
+            })
             .collect()
     }
 
-    fn select_next_edge(
+    /// Selects one of the edges that are feasible for the ant,
+    /// feasibility is determined by the solved `Problem`
+    fn select_next_edge<P: PheromoneRead>(
         &self,
-        ant: &Ant,
-        graph: &Graph,
-        pheromone: &Pheromone,
+        possible_next_edges: &[AdjacencyListEntry],
+        pheromone: &P,
         sample_seed: f32,
         strategy_seed: f32,
     ) -> Option<AdjacencyListEntry>;
-
-    #[cfg_attr(feature = "profiler", flame)]
-    fn get_possible_next_edges_for_ant(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
-        let adjacent_edges = graph.get_adjacent_edges(&ant.current_node);
-
-        let possible_next_edges = adjacent_edges
-            .iter()
-            .filter(|edge| !ant.has_visited(&edge.to))
-            .map(|edge| edge.to_owned())
-            .collect::<Vec<_>>();
-
-        if possible_next_edges.len() > 0 {
-            return possible_next_edges;
-        }
-
-        // in case of tsp-like tasks, we want to close the cycle
-        // this means, that if there is no nodes that have not been visited
-        // it might be because ant has travelled all possible nodes,
-        // here, we allow the ant to go back to the initial node
-        let edge_leading_to_inital_node = adjacent_edges
-            .into_iter()
-            .find(|edge| edge.to == ant.inital_node);
-
-        edge_leading_to_inital_node.map_or(possible_next_edges, |edge| vec![edge])
-    }
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::{RouteBatchWithHoles, RouteCollection};
+use crate::ant_colony::guiding_config::WithGuidingConfig;
+use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
+use crate::ant_colony::pheromone_updater::{ElitistPheromoneUpdater, PheromoneUpdater};
+
+/// Elitist updater shared with the test, so its best-so-far route
+/// can be inspected while the colony owns the updater
+pub struct SharedElitistUpdater(pub Arc<ElitistPheromoneUpdater>);
+
+impl PheromoneUpdater for SharedElitistUpdater {
+    fn get_initial_value(&self) -> PheromoneLevel {
+        self.0.get_initial_value()
+    }
+
+    fn reset(&self) {
+        self.0.reset()
+    }
+
+    fn on_after_step(&self, pheromone: Pheromone, taken_edges: &RouteBatchWithHoles) -> Pheromone {
+        self.0.on_after_step(pheromone, taken_edges)
+    }
+
+    fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
+        self.0.on_after_cycle(pheromone, taken_routes)
+    }
+}
+
+impl WithGuidingConfig for SharedElitistUpdater {}
+
+impl FromStr for SharedElitistUpdater {
+    type Err = &'static str;
+
+    fn from_str(_opts: &str) -> Result<Self, Self::Err> {
+        Err("Shared updater can not be parsed")
+    }
+}
+
+impl Display for SharedElitistUpdater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
@@ -1,18 +1,52 @@
+#[cfg(test)]
+mod _mocks;
+
 #[cfg(test)]
 mod colony_tests {
     use rand::{prelude::StdRng, SeedableRng};
-    use std::rc::Rc;
+    use std::sync::Arc;
 
-    use super::super::{Colony, Config, ConfigurableColony, StepwiseParallelColony};
-    use crate::ant_colony::ant_dispatcher::BasicAntDispatcher;
-    use crate::ant_colony::graph::Graph;
-    use crate::ant_colony::pheromone_updater::ConstantPheromoneUpdater;
+    use super::super::stepping;
+    use super::super::{
+        Colony, Config, ConfigurableColony, IslandColony, IslandConfigs, Migration,
+        MultiObjectiveColony, MultiObjectiveConfig, Objective, ParetoPoint, StagnationCriterion,
+        StepwiseParallelColony,
+    };
+    use super::_mocks::SharedElitistUpdater;
+    use crate::ant_colony::ant::Ant;
+    use crate::ant_colony::ant_dispatcher::{
+        BasicAntDispatcher, ColonyAntDispatcher, DispatcherStringConfig,
+    };
+    use crate::ant_colony::graph::{mock_graph_vector, AdjacencyListEntry, Graph};
+    use crate::ant_colony::pheromone::Pheromone;
+    use crate::ant_colony::pheromone_updater::{
+        ConstantPheromoneUpdater, CyclicalPheromoneUpdater, ElitistPheromoneUpdater,
+        LocalColonyPheromoneUpdater, PheromoneUpdater,
+    };
+    use crate::ant_colony::problem::{Problems, ShortestPath};
+    use crate::common::utils::{compare_float, SplitSeed};
 
     fn get_sample_colony(
         seed: u64,
         ant_count: usize,
         num_of_steps_per_cycle: usize,
-        graph: Rc<Graph>,
+        graph: Arc<Graph>,
+    ) -> StepwiseParallelColony<ConstantPheromoneUpdater, BasicAntDispatcher, StdRng> {
+        get_sample_colony_with_stagnation(
+            seed,
+            ant_count,
+            num_of_steps_per_cycle,
+            graph,
+            StagnationCriterion::Never,
+        )
+    }
+
+    fn get_sample_colony_with_stagnation(
+        seed: u64,
+        ant_count: usize,
+        num_of_steps_per_cycle: usize,
+        graph: Arc<Graph>,
+        stagnation: StagnationCriterion,
     ) -> StepwiseParallelColony<ConstantPheromoneUpdater, BasicAntDispatcher, StdRng> {
         let config = Config {
             ant_count,
@@ -20,6 +54,8 @@ mod colony_tests {
             pheromone_updater: ConstantPheromoneUpdater::new(1.0, 0.1, 0.1),
             ant_dispatcher: BasicAntDispatcher,
             rng: StdRng::seed_from_u64(seed),
+            stagnation,
+            problem: Problems::default(),
         };
 
         StepwiseParallelColony::new(config, graph)
@@ -28,10 +64,10 @@ mod colony_tests {
     #[test]
     fn it_is_reproducible() {
         let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
-        let graph = Rc::new(graph);
+        let graph = Arc::new(graph);
 
-        let colony_a = get_sample_colony(42, 20, 5, Rc::clone(&graph)).execute_n_cycles(2);
-        let colony_b = get_sample_colony(42, 20, 5, Rc::clone(&graph)).execute_n_cycles(2);
+        let colony_a = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
+        let colony_b = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
 
         assert_eq!(colony_a.get_pheromone(), colony_b.get_pheromone());
         assert_eq!(
@@ -43,9 +79,9 @@ mod colony_tests {
     #[test]
     fn it_generates_routes_for_each_ant() {
         let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
-        let graph = Rc::new(graph);
+        let graph = Arc::new(graph);
 
-        let colony = get_sample_colony(42, 20, 5, Rc::clone(&graph)).execute_n_cycles(2);
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
 
         assert_eq!(colony.get_routes().get_routes().len(), 20);
         assert!(colony
@@ -58,9 +94,9 @@ mod colony_tests {
     #[test]
     fn it_generates_routes_that_match_ant_visited_set() {
         let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
-        let graph = Rc::new(graph);
+        let graph = Arc::new(graph);
 
-        let colony = get_sample_colony(42, 20, 5, Rc::clone(&graph)).execute_n_cycles(2);
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
 
         let ants = colony.get_ants();
         let routes = colony.get_routes();
@@ -78,4 +114,534 @@ mod colony_tests {
                     .all(|node_id| ant_node_ids.contains(node_id)));
             })
     }
+
+    #[test]
+    fn it_records_ant_paths_matching_routes() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
+
+        colony
+            .get_ants()
+            .iter()
+            .zip(colony.get_routes().get_routes())
+            .for_each(|(ant, route)| assert_eq!(ant.get_path(), &route.get_nodes()[..]));
+    }
+
+    #[test]
+    fn it_does_not_reinitialize_trails_without_stagnation_criterion() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(2);
+
+        assert!(!colony.was_reinitialized());
+        assert!(colony.get_best_route().is_some());
+    }
+
+    #[test]
+    fn it_reinitializes_trails_and_keeps_best_route_on_stagnation() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let fresh_colony = get_sample_colony(42, 20, 5, Arc::clone(&graph));
+        let stagnating_colony = get_sample_colony_with_stagnation(
+            42,
+            20,
+            5,
+            Arc::clone(&graph),
+            StagnationCriterion::NoImprovement(0),
+        )
+        .execute_n_cycles(2);
+
+        let shortest_dist = stagnating_colony
+            .get_routes()
+            .get_shortest_route()
+            .map(|route| route.get_distance())
+            .unwrap();
+        let best_dist = stagnating_colony
+            .get_best_route()
+            .map(|route| route.get_distance())
+            .unwrap();
+
+        assert!(stagnating_colony.was_reinitialized());
+        assert_eq!(
+            stagnating_colony.get_pheromone(),
+            fresh_colony.get_pheromone()
+        );
+        assert!(best_dist <= shortest_dist);
+    }
+
+    fn get_elitist_colony(
+        updater: &Arc<ElitistPheromoneUpdater>,
+        stagnation: StagnationCriterion,
+    ) -> StepwiseParallelColony<SharedElitistUpdater, BasicAntDispatcher, StdRng> {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let config = Config {
+            ant_count: 20,
+            num_of_steps_per_cycle: 5,
+            pheromone_updater: SharedElitistUpdater(Arc::clone(updater)),
+            ant_dispatcher: BasicAntDispatcher,
+            rng: StdRng::seed_from_u64(42),
+            stagnation,
+            problem: Problems::default(),
+        };
+
+        StepwiseParallelColony::new(config, Arc::new(graph))
+    }
+
+    #[test]
+    fn it_keeps_best_so_far_route_of_updater_on_stagnation() {
+        let updater = Arc::new(ElitistPheromoneUpdater::new(1.0, 0.1, 0.1, 2, 5));
+
+        let colony =
+            get_elitist_colony(&updater, StagnationCriterion::NoImprovement(0)).execute_n_cycles(2);
+
+        assert!(colony.was_reinitialized());
+        assert_eq!(
+            updater.get_best_so_far().get_best().map(|(_, cost)| cost),
+            colony.get_best_route_cost()
+        );
+    }
+
+    #[test]
+    fn it_forgets_best_so_far_route_of_updater_on_reset() {
+        let updater = Arc::new(ElitistPheromoneUpdater::new(1.0, 0.1, 0.1, 2, 5));
+
+        let colony = get_elitist_colony(&updater, StagnationCriterion::Never).execute_n_cycles(2);
+        assert!(updater.get_best_so_far().get_best().is_some());
+
+        let _ = colony.reset_pheromone();
+        assert!(updater.get_best_so_far().get_best().is_none());
+    }
+
+    #[test]
+    fn it_resets_trails_to_initial_values() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let fresh_colony = get_sample_colony(42, 20, 5, Arc::clone(&graph));
+        let trained_colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(3);
+        let best_dist = trained_colony
+            .get_best_route()
+            .map(|route| route.get_distance());
+
+        let reset_colony = trained_colony.reset_pheromone();
+
+        assert_eq!(reset_colony.get_pheromone(), fresh_colony.get_pheromone());
+        assert_eq!(
+            reset_colony
+                .get_best_route()
+                .map(|route| route.get_distance()),
+            best_dist
+        );
+    }
+
+    #[test]
+    fn it_solves_open_path_problem() {
+        let graph = Arc::new(Graph::from_node_vector(mock_graph_vector()));
+
+        let config = Config {
+            ant_count: 10,
+            num_of_steps_per_cycle: 3,
+            pheromone_updater: ConstantPheromoneUpdater::new(1.0, 0.1, 0.1),
+            ant_dispatcher: BasicAntDispatcher,
+            rng: StdRng::seed_from_u64(42),
+            stagnation: StagnationCriterion::Never,
+            problem: Problems::ShortestPath(ShortestPath::new(0, 3)),
+        };
+
+        let colony = StepwiseParallelColony::new(config, graph).execute_n_cycles(3);
+        let best_route = colony.get_best_route().unwrap();
+
+        assert!(colony.get_ants().iter().all(|ant| ant.inital_node == 0));
+        assert_eq!(best_route.get_nodes(), vec![0, 3]);
+    }
+
+    #[test]
+    fn it_reinforces_only_paths_reaching_the_target() {
+        // cheap edges 0 - 1 - 2 lead into a dead end, target 4 is reachable via 3 only
+        let graph = Arc::new(Graph::from_neighbour_tuples(vec![
+            (0, 1, 0.1),
+            (1, 2, 0.1),
+            (0, 3, 5.0),
+            (3, 4, 5.0),
+        ]));
+
+        let config = Config {
+            ant_count: 10,
+            num_of_steps_per_cycle: 4,
+            pheromone_updater: CyclicalPheromoneUpdater::new(1.0, 0.1, 1.0, 4),
+            ant_dispatcher: BasicAntDispatcher,
+            rng: StdRng::seed_from_u64(42),
+            stagnation: StagnationCriterion::Never,
+            problem: Problems::ShortestPath(ShortestPath::new(0, 4)),
+        };
+
+        let colony = StepwiseParallelColony::new(config, Arc::clone(&graph)).execute_n_cycles(3);
+        let best_route = colony.get_best_route().unwrap();
+        let pheromone = colony.get_pheromone();
+
+        assert_eq!(best_route.get_nodes(), vec![0, 3, 4]);
+        assert_eq!(colony.get_best_route_cost(), Option::Some(10.0));
+        assert!(
+            pheromone.get_pheromone_for_edge(AdjacencyListEntry::new(3, 4, 5.0).key)
+                > pheromone.get_pheromone_for_edge(AdjacencyListEntry::new(1, 2, 0.1).key)
+        );
+    }
+
+    #[test]
+    fn it_applies_local_updates_before_next_ant_moves() {
+        // two equally long paths from 0 to 3, via 1 and via 2
+        let edges = vec![(0, 1), (0, 2), (1, 3), (2, 3)]
+            .into_iter()
+            .flat_map(|(from, to)| {
+                vec![
+                    AdjacencyListEntry::new(from, to, 1.0),
+                    AdjacencyListEntry::new(to, from, 1.0),
+                ]
+            })
+            .collect();
+        let graph = Arc::new(Graph::from_edges(edges));
+
+        let config = Config {
+            ant_count: 3,
+            num_of_steps_per_cycle: 2,
+            pheromone_updater: LocalColonyPheromoneUpdater::new(1.0, 0.5, 0.1, 2),
+            // always exploits the best edge
+            ant_dispatcher: ColonyAntDispatcher::new(0.0, 1.0),
+            rng: StdRng::seed_from_u64(42),
+            stagnation: StagnationCriterion::Never,
+            problem: Problems::ShortestPath(ShortestPath::new(0, 3)),
+        };
+        let pheromone = graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| {
+                let value = iif!(edge.key == AdjacencyListEntry::get_key(0, 1), 2.0, 1.9);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, value)
+            },
+        );
+
+        let colony = StepwiseParallelColony::new(config, graph)
+            .replace_pheromone(pheromone)
+            .execute_cycle(0);
+        let first_nodes = colony
+            .get_routes()
+            .get_routes()
+            .iter()
+            .map(|route| route.get_edges()[0].to)
+            .collect::<Vec<_>>();
+
+        // 0-1 decays to 1.5 after the first ant, 0-2 to 1.45 after the second one
+        assert_eq!(first_nodes, vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn it_selects_edges_in_parallel_as_if_ants_moved_one_after_another() {
+        // ring of 6 nodes, so ants on neighbouring nodes share edges
+        let edges = (0..6)
+            .flat_map(|node| {
+                let next = (node + 1) % 6;
+
+                vec![
+                    AdjacencyListEntry::new(node, next, 1.0),
+                    AdjacencyListEntry::new(next, node, 1.0),
+                ]
+            })
+            .collect();
+        let graph = Graph::from_edges(edges);
+        let init_pheromone = graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| pheromone.initialize_pheromone_for_edge(edge.key, 1.0),
+        );
+        let ants = || (0..12).map(|idx| Ant::new(idx % 6)).collect::<Vec<_>>();
+        let cycle_seed = SplitSeed::new(42);
+
+        let select_edge = |_ant_idx, ant: &Ant, pheromone: &Pheromone, (sample, _)| {
+            graph
+                .get_adjacent_edges(&ant.current_node)
+                .iter()
+                .max_by(|a, b| {
+                    let a_level = pheromone.get_pheromone_for_edge(*a) + 0.1 * sample;
+                    let b_level = pheromone.get_pheromone_for_edge(*b);
+
+                    compare_float(&a_level, &b_level)
+                })
+                .cloned()
+        };
+        let decay = |pheromone: Pheromone, edge: &AdjacencyListEntry| {
+            pheromone.update_pheromone_value(edge.key, |value| value * 0.5)
+        };
+
+        let (_, taken_edges, pheromone) = stepping::execute_step_for_all_ants(
+            ants(),
+            init_pheromone.clone(),
+            &cycle_seed,
+            0,
+            true,
+            select_edge,
+            decay,
+        );
+
+        let (expected_edges, expected_pheromone) = ants().into_iter().enumerate().fold(
+            (Vec::new(), init_pheromone),
+            |(mut taken_edges, pheromone), (ant_idx, ant)| {
+                let seeds = cycle_seed.split(ant_idx as u64).split(0).unit_pair();
+                let next_edge = select_edge(ant_idx, &ant, &pheromone, seeds).unwrap();
+                taken_edges.push(Option::Some(next_edge));
+
+                (taken_edges, decay(pheromone, &next_edge))
+            },
+        );
+
+        assert_eq!(taken_edges, expected_edges);
+        assert_eq!(pheromone, expected_pheromone);
+    }
+
+    #[test]
+    fn it_parses_stagnation_criterion() {
+        assert_eq!("none".parse(), Ok(StagnationCriterion::Never));
+        assert_eq!(
+            "unchanged:20".parse(),
+            Ok(StagnationCriterion::NoImprovement(20))
+        );
+        assert_eq!(
+            "branching:0.05,1.1".parse(),
+            Ok(StagnationCriterion::BranchingFactor {
+                lambda: 0.05,
+                threshold: 1.1
+            })
+        );
+        assert!("unchanged".parse::<StagnationCriterion>().is_err());
+    }
+
+    #[test]
+    fn it_behaves_like_single_colony_with_one_island() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(3);
+        let island_colony = IslandColony::new(
+            vec![get_sample_colony(42, 20, 5, Arc::clone(&graph))],
+            1,
+            Migration::BestRoute,
+        )
+        .execute_n_cycles(3);
+
+        assert_eq!(island_colony.get_pheromone(), colony.get_pheromone());
+        assert_eq!(
+            island_colony.get_routes().get_routes(),
+            colony.get_routes().get_routes()
+        );
+    }
+
+    #[test]
+    fn it_combines_routes_of_all_islands() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let islands = (0..3)
+            .map(|seed| get_sample_colony(seed, 20, 5, Arc::clone(&graph)))
+            .collect();
+        let island_colony = IslandColony::new(islands, 2, Migration::BestRoute).execute_n_cycles(3);
+
+        assert_eq!(island_colony.get_routes().get_routes().len(), 60);
+        assert!(island_colony.get_pheromone().get_max_value() <= 1.0);
+    }
+
+    #[test]
+    fn it_aligns_island_trails_on_full_blend() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let islands = (0..2)
+            .map(|seed| get_sample_colony(seed, 20, 5, Arc::clone(&graph)))
+            .collect();
+        let island_colony =
+            IslandColony::new(islands, 1, Migration::Blend(1.0)).execute_n_cycles(2);
+
+        let islands = island_colony.get_islands();
+        let values_a = islands[0].get_pheromone().normalize().get_values();
+        let values_b = islands[1].get_pheromone().normalize().get_values();
+
+        for (key, value) in values_a {
+            assert_delta!(value, values_b[&key]);
+        }
+    }
+
+    #[test]
+    fn it_parses_migration() {
+        assert_eq!("best".parse(), Ok(Migration::BestRoute));
+        assert_eq!("blend:0.5".parse(), Ok(Migration::Blend(0.5)));
+        assert!("blend".parse::<Migration>().is_err());
+    }
+
+    #[test]
+    fn it_parses_island_configs_and_repeats_them() {
+        let configs = "basic;colony:0.9"
+            .parse::<IslandConfigs<DispatcherStringConfig>>()
+            .unwrap();
+        let names = (0..4)
+            .map(|island_idx| configs.get(island_idx).unwrap().to_string())
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["basic:", "colony:0.9", "basic:", "colony:0.9"]);
+        assert!("basic;unknown"
+            .parse::<IslandConfigs<DispatcherStringConfig>>()
+            .is_err());
+    }
+
+    #[test]
+    fn it_picks_best_route_of_islands_by_its_cost() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let islands = (0..3)
+            .map(|seed| get_sample_colony(seed, 20, 5, Arc::clone(&graph)))
+            .collect();
+        let island_colony = IslandColony::new(islands, 2, Migration::BestRoute).execute_n_cycles(3);
+
+        let min_cost = island_colony
+            .get_islands()
+            .iter()
+            .filter_map(|island| island.get_best_route_cost())
+            .fold(f32::INFINITY, f32::min);
+        let best_island = island_colony
+            .get_islands()
+            .iter()
+            .find(|island| island.get_best_route_cost() == Option::Some(min_cost))
+            .unwrap();
+
+        assert_eq!(island_colony.get_best_route_cost(), Option::Some(min_cost));
+        assert_eq!(island_colony.get_best_route(), best_island.get_best_route());
+    }
+
+    fn get_multi_objective_colony<U: PheromoneUpdater>(
+        seed: u64,
+        objectives: Vec<Objective<U>>,
+    ) -> MultiObjectiveColony<U, BasicAntDispatcher, StdRng> {
+        get_multi_objective_colony_with_stagnation(seed, objectives, StagnationCriterion::Never)
+    }
+
+    fn get_multi_objective_colony_with_stagnation<U: PheromoneUpdater>(
+        seed: u64,
+        objectives: Vec<Objective<U>>,
+        stagnation: StagnationCriterion,
+    ) -> MultiObjectiveColony<U, BasicAntDispatcher, StdRng> {
+        let config = MultiObjectiveConfig {
+            ant_count: 20,
+            num_of_steps_per_cycle: 5,
+            objectives,
+            ant_dispatcher: BasicAntDispatcher,
+            rng: StdRng::seed_from_u64(seed),
+            problem: Problems::default(),
+            stagnation,
+            weight_divisions: 4,
+        };
+
+        MultiObjectiveColony::new(config)
+    }
+
+    #[test]
+    fn it_behaves_like_plain_colony_with_single_objective() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let graph = Arc::new(graph);
+
+        let objective = Objective {
+            graph: Arc::clone(&graph),
+            pheromone_updater: ConstantPheromoneUpdater::new(1.0, 0.1, 0.1),
+        };
+        let multi_objective_colony =
+            get_multi_objective_colony(42, vec![objective]).execute_n_cycles(3);
+        let colony = get_sample_colony(42, 20, 5, Arc::clone(&graph)).execute_n_cycles(3);
+
+        assert_eq!(
+            multi_objective_colony.get_objective_pheromones()[0],
+            *colony.get_pheromone()
+        );
+        assert_eq!(
+            multi_objective_colony.get_routes().get_routes(),
+            colony.get_routes().get_routes()
+        );
+    }
+
+    #[test]
+    fn it_finds_non_dominated_trade_offs() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+
+        let colony = get_multi_objective_colony(42, get_two_objectives(&graph)).execute_n_cycles(5);
+        let front = colony.get_pareto_front();
+
+        assert!(!front.is_empty());
+        assert!(front.len() <= 5);
+        for point in front.iter() {
+            assert_delta!(point.weights.iter().sum::<f32>(), 1.0);
+            assert!(front.iter().all(|other| !other.dominates(point)));
+        }
+    }
+
+    #[test]
+    fn it_compares_pareto_points() {
+        let point = |costs: Vec<f32>| ParetoPoint {
+            weights: vec![0.5, 0.5],
+            costs,
+            pheromone: Pheromone::new(),
+        };
+
+        assert!(point(vec![1.0, 1.0]).dominates(&point(vec![1.0, 2.0])));
+        assert!(!point(vec![1.0, 1.0]).dominates(&point(vec![1.0, 1.0])));
+        assert!(!point(vec![0.0, 2.0]).dominates(&point(vec![1.0, 1.0])));
+    }
+
+    fn get_two_objectives(graph: &Graph) -> Vec<Objective<CyclicalPheromoneUpdater>> {
+        vec![graph.clone(), graph.clone().invert_distances()]
+            .into_iter()
+            .map(|graph| Objective {
+                graph: Arc::new(graph),
+                pheromone_updater: CyclicalPheromoneUpdater::new(1.0, 0.1, 1.0, 5),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_spreads_ants_over_weight_lattice_by_stride() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+        let colony = get_multi_objective_colony(42, get_two_objectives(&graph));
+
+        // 20 ants over 5 lattice points, every point is explored by 4 consecutive ants
+        let first_weights = (0..20)
+            .map(|ant_idx| colony.get_ant_weights(ant_idx)[0])
+            .collect::<Vec<_>>();
+        let expected = [1.0, 0.75, 0.5, 0.25, 0.0]
+            .iter()
+            .flat_map(|weight| vec![*weight; 4])
+            .collect::<Vec<_>>();
+
+        assert_eq!(first_weights, expected);
+    }
+
+    #[test]
+    fn it_reinitializes_trails_of_all_objectives_on_stagnation() {
+        let graph = Graph::random_tsp_graph(&mut StdRng::seed_from_u64(42), 10);
+
+        let fresh_colony = get_multi_objective_colony(42, get_two_objectives(&graph));
+        let plain_colony =
+            get_multi_objective_colony(42, get_two_objectives(&graph)).execute_n_cycles(2);
+        let stagnating_colony = get_multi_objective_colony_with_stagnation(
+            42,
+            get_two_objectives(&graph),
+            StagnationCriterion::NoImprovement(0),
+        )
+        .execute_n_cycles(2);
+
+        assert!(!plain_colony.was_reinitialized());
+        assert!(stagnating_colony.was_reinitialized());
+        assert_eq!(
+            stagnating_colony.get_objective_pheromones(),
+            fresh_colony.get_objective_pheromones()
+        );
+        assert!(stagnating_colony.get_best_route().is_some());
+    }
 }
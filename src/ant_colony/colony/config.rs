@@ -4,6 +4,9 @@ use rand::Rng;
 
 use crate::ant_colony::ant_dispatcher::AntDispatcher;
 use crate::ant_colony::pheromone_updater::PheromoneUpdater;
+use crate::ant_colony::problem::Problems;
+
+use super::StagnationCriterion;
 
 pub struct Config<U: PheromoneUpdater, D: AntDispatcher, R: Rng> {
     pub ant_count: usize,
@@ -11,6 +14,8 @@ pub struct Config<U: PheromoneUpdater, D: AntDispatcher, R: Rng> {
     pub pheromone_updater: U,
     pub ant_dispatcher: D,
     pub rng: R,
+    pub stagnation: StagnationCriterion,
+    pub problem: Problems,
 }
 
 impl<U: PheromoneUpdater, D: AntDispatcher, R: Rng> Display for Config<U, D, R> {
@@ -18,14 +23,18 @@ impl<U: PheromoneUpdater, D: AntDispatcher, R: Rng> Display for Config<U, D, R>
         write!(
             f,
             "Config:\n\t\
+            problem: {}\n\t\
             ant count: {}\n\t\
             number of steps per cycle: {}\n\t\
             dispatcher: {}\n\t\
-            updater: {}",
+            updater: {}\n\t\
+            stagnation: {}",
+            self.problem,
             self.ant_count,
             self.num_of_steps_per_cycle,
             self.ant_dispatcher,
-            self.pheromone_updater
+            self.pheromone_updater,
+            self.stagnation
         )
     }
 }
@@ -0,0 +1,334 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "singlethread")] {
+    } else {
+        use rayon::prelude::*;
+    }
+}
+
+use itertools::Itertools;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{Route, RouteCollection};
+use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::compare_float;
+
+use super::Colony;
+
+/// Determines how islands exchange information every few cycles
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Migration {
+    /// best route found by any island is reinforced in every island,
+    /// its edges are raised to the island's strongest trail
+    BestRoute,
+    /// every island moves its trails towards the (normalized) mean of all islands by given weight
+    Blend(f32),
+}
+
+impl FromStr for Migration {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse migration type";
+
+        let mut config_iter = s.split(':');
+        let name = config_iter.next().unwrap_or_default();
+        let opts = config_iter.next().unwrap_or_default();
+
+        match name {
+            "best" => Ok(Self::BestRoute),
+            "blend" => opts.parse::<f32>().map(Self::Blend).map_err(|_| error),
+            _ => Err(error),
+        }
+    }
+}
+
+impl Display for Migration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::BestRoute => write!(f, "best route"),
+            Self::Blend(weight) => write!(f, "blend ({})", weight),
+        }
+    }
+}
+
+/// Configs of consecutive islands separated with ';', e.g. updaters or dispatchers.
+/// Islands beyond the listed ones repeat the configs from the first one.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IslandConfigs<C>(pub Vec<C>);
+
+impl<C> IslandConfigs<C> {
+    pub fn get(&self, island_idx: usize) -> Option<&C> {
+        iif!(
+            self.0.is_empty(),
+            Option::None,
+            self.0.get(island_idx % self.0.len())
+        )
+    }
+}
+
+impl<C: FromStr> FromStr for IslandConfigs<C> {
+    type Err = C::Err;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(';')
+            .map(C::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+/// Island model, runs multiple independent colonies (islands) in parallel.
+///
+/// Islands may differ in seed or even in updater and dispatcher,
+/// every `migration_interval` cycles they exchange information according to `Migration`.
+///
+/// Exposed pheromone is the mean of normalized pheromone of all islands,
+/// routes are the routes of all islands. With a single island, it behaves exactly like the island.
+pub struct IslandColony<C: Colony + Send> {
+    islands: Vec<C>,
+    migration_interval: usize,
+    migration: Migration,
+    executed_cycles: usize,
+    combined_pheromone: Option<Pheromone>,
+    combined_routes: Option<RouteCollection>,
+    best_island_idx: usize,
+}
+
+impl<C: Colony + Send> IslandColony<C> {
+    pub fn new(islands: Vec<C>, migration_interval: usize, migration: Migration) -> Self {
+        assert!(
+            !islands.is_empty(),
+            "Island model needs at least one colony"
+        );
+
+        IslandColony {
+            islands,
+            migration_interval: migration_interval.max(1),
+            migration,
+            executed_cycles: 0,
+            combined_pheromone: Option::None,
+            combined_routes: Option::None,
+            best_island_idx: 0,
+        }
+        .combine()
+    }
+
+    pub fn get_islands(&self) -> &[C] {
+        &self.islands
+    }
+
+    fn migrate(self) -> Self {
+        let islands = match self.migration {
+            Migration::BestRoute => Self::migrate_best_route(self.islands),
+            Migration::Blend(weight) => Self::migrate_blended_pheromone(self.islands, weight),
+        };
+
+        IslandColony { islands, ..self }
+    }
+
+    fn migrate_best_route(islands: Vec<C>) -> Vec<C> {
+        let maybe_best_route = Self::find_best_route(&islands);
+
+        match maybe_best_route {
+            Option::None => islands,
+            Option::Some(best_route) => islands
+                .into_iter()
+                .map(|island| {
+                    let max = island.get_pheromone().get_max_value();
+                    let pheromone = island.get_pheromone().clone();
+
+                    let reinforced_pheromone =
+                        best_route
+                            .get_edges()
+                            .iter()
+                            .fold(pheromone, |pheromone, edge| {
+                                let increment = max - pheromone.get_pheromone_for_edge(edge);
+
+                                pheromone.increase_pheromone_value(edge, increment)
+                            });
+
+                    island.replace_pheromone(reinforced_pheromone)
+                })
+                .collect(),
+        }
+    }
+
+    fn migrate_blended_pheromone(islands: Vec<C>, weight: f32) -> Vec<C> {
+        let mean_pheromone = Self::calc_mean_pheromone(&islands);
+
+        islands
+            .into_iter()
+            .map(|island| {
+                // islands may use different updaters, hence different scales of trails
+                let max = island.get_pheromone().get_max_value();
+                let scaled_mean = mean_pheromone.clone().scale_all_pheromone_values(max);
+                let pheromone = island.get_pheromone().clone().blend(&scaled_mean, weight);
+
+                island.replace_pheromone(pheromone)
+            })
+            .collect()
+    }
+
+    fn find_best_route(islands: &[C]) -> Option<Route> {
+        Self::find_best_island(islands)
+            .and_then(|island| island.get_best_route())
+            .cloned()
+    }
+
+    /// Island with the lowest cost of its best route, islands are compared
+    /// by the same measure they track their best route with
+    fn find_best_island(islands: &[C]) -> Option<&C> {
+        islands
+            .iter()
+            .filter_map(|island| island.get_best_route_cost().map(|cost| (island, cost)))
+            .min_by(|(_, cost_a), (_, cost_b)| compare_float(cost_a, cost_b))
+            .map(|(island, _)| island)
+    }
+
+    /// Running mean of normalized pheromone of all islands
+    fn calc_mean_pheromone(islands: &[C]) -> Pheromone {
+        islands
+            .iter()
+            .map(|island| island.get_pheromone().normalize())
+            .zip(1..)
+            .fold(Pheromone::new(), |mean, (pheromone, n)| {
+                iif!(n == 1, pheromone, mean.blend(&pheromone, 1.0 / n as f32))
+            })
+    }
+
+    fn combine(self) -> Self {
+        let best_island_idx = self
+            .islands
+            .iter()
+            .position_min_by(|a, b| {
+                let dist_a = a.get_routes().get_shortest_route_distance();
+                let dist_b = b.get_routes().get_shortest_route_distance();
+
+                compare_float(
+                    &dist_a.unwrap_or(f32::INFINITY),
+                    &dist_b.unwrap_or(f32::INFINITY),
+                )
+            })
+            .unwrap_or_default();
+
+        if self.islands.len() == 1 {
+            return IslandColony {
+                best_island_idx,
+                ..self
+            };
+        }
+
+        let combined_routes = self
+            .islands
+            .iter()
+            .flat_map(|island| island.get_routes().get_routes().iter().cloned())
+            .collect::<Vec<_>>();
+
+        IslandColony {
+            combined_pheromone: Option::Some(Self::calc_mean_pheromone(&self.islands)),
+            combined_routes: Option::Some(RouteCollection::from_routes(combined_routes)),
+            best_island_idx,
+            ..self
+        }
+    }
+}
+
+impl<C: Colony + Send> Colony for IslandColony<C> {
+    fn execute_n_cycles(self, n_cycles: usize) -> Self {
+        (0..n_cycles).fold(self, IslandColony::execute_cycle)
+    }
+
+    fn execute_cycle(self, cycle: usize) -> Self {
+        cfg_if! {
+            if #[cfg(feature = "singlethread")] {
+                let workload = self.islands.into_iter();
+            } else {
+                let workload = self.islands.into_par_iter();
+            }
+        }
+
+        let islands = workload
+            .map(|island| island.execute_cycle(cycle))
+            .collect::<Vec<_>>();
+
+        let executed_cycles = self.executed_cycles + 1;
+        let colony = IslandColony {
+            islands,
+            executed_cycles,
+            ..self
+        };
+
+        let should_migrate =
+            colony.islands.len() > 1 && executed_cycles % colony.migration_interval == 0;
+
+        iif!(should_migrate, colony.migrate(), colony).combine()
+    }
+
+    fn get_pheromone(&self) -> &Pheromone {
+        self.combined_pheromone
+            .as_ref()
+            .unwrap_or_else(|| self.islands[self.best_island_idx].get_pheromone())
+    }
+
+    fn replace_pheromone(self, pheromone: Pheromone) -> Self {
+        let islands = self
+            .islands
+            .into_iter()
+            .map(|island| island.replace_pheromone(pheromone.clone()))
+            .collect();
+
+        IslandColony { islands, ..self }.combine()
+    }
+
+    fn reset_pheromone(self) -> Self {
+        let islands = self
+            .islands
+            .into_iter()
+            .map(Colony::reset_pheromone)
+            .collect();
+
+        IslandColony { islands, ..self }.combine()
+    }
+
+    fn get_routes(&self) -> &RouteCollection {
+        self.combined_routes
+            .as_ref()
+            .unwrap_or_else(|| self.islands[self.best_island_idx].get_routes())
+    }
+
+    /// Ants of the island that found the shortest route in the last cycle
+    fn get_ants(&self) -> &[Ant] {
+        self.islands[self.best_island_idx].get_ants()
+    }
+
+    fn get_best_route(&self) -> Option<&Route> {
+        Self::find_best_island(&self.islands).and_then(|island| island.get_best_route())
+    }
+
+    fn get_best_route_cost(&self) -> Option<f32> {
+        Self::find_best_island(&self.islands).and_then(|island| island.get_best_route_cost())
+    }
+
+    fn was_reinitialized(&self) -> bool {
+        self.islands.iter().any(|island| island.was_reinitialized())
+    }
+}
+
+impl<C: Colony + Send> Display for IslandColony<C> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Island model\n\t\
+            islands:            {:>5}\n\t\
+            migration interval: {:>5}\n\t\
+            migration:          {}",
+            self.islands.len(),
+            self.migration_interval,
+            self.migration
+        )
+    }
+}
@@ -1,17 +1,26 @@
 use rand::Rng;
-use std::rc::Rc;
+use std::sync::Arc;
 
 mod _tests;
 mod config;
+mod island_colony;
+mod multi_objective_colony;
+mod stagnation;
+mod stepping;
 mod stepwise_parallel_colony;
 
 use crate::ant_colony::ant::Ant;
 use crate::ant_colony::ant_dispatcher::AntDispatcher;
-use crate::ant_colony::graph::{Graph, RouteCollection};
+use crate::ant_colony::graph::{Graph, Route, RouteCollection};
 use crate::ant_colony::pheromone::Pheromone;
 use crate::ant_colony::pheromone_updater::PheromoneUpdater;
 
 pub use config::Config;
+pub use island_colony::{IslandColony, IslandConfigs, Migration};
+pub use multi_objective_colony::{
+    MultiObjectiveColony, MultiObjectiveConfig, Objective, ParetoPoint,
+};
+pub use stagnation::StagnationCriterion;
 pub use stepwise_parallel_colony::StepwiseParallelColony;
 
 pub trait Colony {
@@ -21,9 +30,24 @@ pub trait Colony {
 
     fn get_pheromone(&self) -> &Pheromone;
 
+    /// Replaces trails, e.g. with pheromone migrated from other colony
+    fn replace_pheromone(self, pheromone: Pheromone) -> Self;
+
+    /// Resets trails to their initial values, e.g. when restarting the search
+    fn reset_pheromone(self) -> Self;
+
     fn get_routes(&self) -> &RouteCollection;
 
     fn get_ants(&self) -> &[Ant];
+
+    /// Shortest route found in all cycles so far, survives trail reinitialization
+    fn get_best_route(&self) -> Option<&Route>;
+
+    /// Cost of the best route as evaluated by the problem, colonies compare routes by it
+    fn get_best_route_cost(&self) -> Option<f32>;
+
+    /// Whether trails were reinitialized due to stagnation in the last cycle
+    fn was_reinitialized(&self) -> bool;
 }
 
 pub trait ConfigurableColony {
@@ -31,6 +55,8 @@ pub trait ConfigurableColony {
     type Dispatcher: AntDispatcher;
     type Random: Rng;
 
-    fn new(config: Config<Self::Updater, Self::Dispatcher, Self::Random>, graph: Rc<Graph>)
-        -> Self;
+    fn new(
+        config: Config<Self::Updater, Self::Dispatcher, Self::Random>,
+        graph: Arc<Graph>,
+    ) -> Self;
 }
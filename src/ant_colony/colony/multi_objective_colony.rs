@@ -0,0 +1,595 @@
+use itertools::Itertools;
+use rand::Rng;
+use std::fmt::Display;
+use std::sync::Arc;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::ant_dispatcher::AntDispatcher;
+use crate::ant_colony::graph::{
+    AdjacencyListEntry, Graph, Route, RouteBatchWithHoles, RouteCollection,
+};
+use crate::ant_colony::pheromone::{Pheromone, WeightedPheromone};
+use crate::ant_colony::pheromone_updater::PheromoneUpdater;
+use crate::ant_colony::problem::{Problem, Problems};
+use crate::common::utils::{simplex_lattice, SplitSeed};
+
+use super::stepping;
+use super::{Colony, StagnationCriterion};
+
+/// Single criterion of the search, e.g. texture complexity or colour change.
+///
+/// Graphs of all objectives are expected to share structure (nodes and edge keys),
+/// only distances differ.
+pub struct Objective<U: PheromoneUpdater> {
+    pub graph: Arc<Graph>,
+    pub pheromone_updater: U,
+}
+
+pub struct MultiObjectiveConfig<U: PheromoneUpdater, D: AntDispatcher, R: Rng> {
+    pub ant_count: usize,
+    pub num_of_steps_per_cycle: usize,
+    pub objectives: Vec<Objective<U>>,
+    pub ant_dispatcher: D,
+    pub rng: R,
+    pub problem: Problems,
+    pub stagnation: StagnationCriterion,
+    /// weights of objectives are multiples of 1 / weight_divisions
+    pub weight_divisions: usize,
+}
+
+/// Trade-off between objectives, obtained by aggregating trails with given weights
+#[derive(Debug, Clone)]
+pub struct ParetoPoint {
+    pub weights: Vec<f32>,
+    /// mean distance of each objective, weighted by the aggregated trails
+    pub costs: Vec<f32>,
+    pub pheromone: Pheromone,
+}
+
+impl ParetoPoint {
+    /// Not worse in any objective and better in at least one
+    pub fn dominates(&self, other: &ParetoPoint) -> bool {
+        let pairs = self.costs.iter().zip_eq(other.costs.iter());
+
+        pairs.clone().all(|(cost, other_cost)| cost <= other_cost)
+            && pairs.clone().any(|(cost, other_cost)| cost < other_cost)
+    }
+}
+
+impl Display for ParetoPoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "weights: [{}], costs: [{}]",
+            self.weights.iter().map(|w| format!("{:.2}", w)).join(", "),
+            self.costs.iter().map(|c| format!("{:.4}", c)).join(", ")
+        )
+    }
+}
+
+/// Multi-objective colony (`Iredi2001BicriterionACO`, `Doerner2004PACO`),
+/// keeps separate pheromone for each objective.
+///
+/// Every ant weighs objectives differently, ants are spread evenly over the weight lattice.
+/// Ant reads trails and distances aggregated with its weights
+///         \tau = \prod_k \tau_k^{w_k}, d = \prod_k d_k^{w_k}
+/// and trails of each objective are updated with routes measured in that objective only.
+///
+/// Exposed pheromone aggregates objectives with equal weights,
+/// other trade-offs are available via `get_pareto_front`.
+pub struct MultiObjectiveColony<U, D, R>
+where
+    U: PheromoneUpdater,
+    D: AntDispatcher,
+    R: Rng,
+{
+    ants: Vec<Ant>,
+    pheromones: Vec<Pheromone>,
+    combined_pheromone: Pheromone,
+    routes: RouteCollection,
+    config: MultiObjectiveConfig<U, D, R>,
+    weight_lattice: Vec<Vec<f32>>,
+    equal_weights: Vec<f32>,
+    cycle_seed: SplitSeed,
+    best_route: Option<(Route, f32)>,
+    cycles_without_improvement: usize,
+    reinitialized: bool,
+}
+
+impl<U, D, R> Colony for MultiObjectiveColony<U, D, R>
+where
+    U: PheromoneUpdater,
+    D: AntDispatcher,
+    R: Rng,
+{
+    fn execute_n_cycles(self, n_cycles: usize) -> Self {
+        (0..n_cycles).fold(self, MultiObjectiveColony::execute_cycle)
+    }
+
+    fn execute_cycle(self, _cycle: usize) -> Self {
+        let steps = 0..self.config.num_of_steps_per_cycle;
+        let init_colony = self
+            .initialize_ants()
+            .initialize_cycle_seed()
+            .initialize_routes();
+
+        let colony = steps.fold(init_colony, MultiObjectiveColony::execute_step_for_all_ants);
+
+        let projected_routes = colony
+            .config
+            .objectives
+            .iter()
+            .map(|objective| {
+                let routes = Self::project_routes(&colony.routes, &objective.graph);
+
+                colony.evaluate_routes(routes, &objective.graph)
+            })
+            .collect::<Vec<_>>();
+        let pheromones = colony
+            .config
+            .objectives
+            .iter()
+            .zip_eq(colony.pheromones)
+            .zip_eq(projected_routes.iter())
+            .map(|((objective, pheromone), routes)| {
+                objective
+                    .pheromone_updater
+                    .on_after_cycle(pheromone, routes)
+            })
+            .collect();
+
+        MultiObjectiveColony {
+            pheromones,
+            ..colony
+        }
+        .combine_pheromone()
+        .track_best_route()
+        .reinitialize_on_stagnation()
+    }
+
+    fn get_pheromone(&self) -> &Pheromone {
+        &self.combined_pheromone
+    }
+
+    /// Given trails replace trails of every objective, scaled to the objective's maximum
+    fn replace_pheromone(self, pheromone: Pheromone) -> Self {
+        let normalized = pheromone.normalize();
+        let pheromones = self
+            .pheromones
+            .iter()
+            .map(|current| {
+                normalized
+                    .clone()
+                    .scale_all_pheromone_values(current.get_max_value())
+            })
+            .collect();
+
+        MultiObjectiveColony { pheromones, ..self }.combine_pheromone()
+    }
+
+    /// Updaters forget their state as well, unlike on reinitialization after stagnation
+    fn reset_pheromone(self) -> Self {
+        for objective in self.config.objectives.iter() {
+            objective.pheromone_updater.reset();
+        }
+
+        MultiObjectiveColony {
+            cycles_without_improvement: 0,
+            ..self
+        }
+        .initialize_pheromones()
+    }
+
+    fn get_routes(&self) -> &RouteCollection {
+        &self.routes
+    }
+
+    fn get_ants(&self) -> &[Ant] {
+        &self.ants
+    }
+
+    /// Best route when objectives are weighted equally
+    fn get_best_route(&self) -> Option<&Route> {
+        self.best_route.as_ref().map(|(route, _)| route)
+    }
+
+    fn get_best_route_cost(&self) -> Option<f32> {
+        self.best_route.as_ref().map(|(_, cost)| *cost)
+    }
+
+    fn was_reinitialized(&self) -> bool {
+        self.reinitialized
+    }
+}
+
+impl<U, D, R> MultiObjectiveColony<U, D, R>
+where
+    U: PheromoneUpdater,
+    D: AntDispatcher,
+    R: Rng,
+{
+    pub fn new(config: MultiObjectiveConfig<U, D, R>) -> Self {
+        assert!(
+            !config.objectives.is_empty(),
+            "Multi-objective colony needs at least one objective"
+        );
+
+        let n_objectives = config.objectives.len();
+        let weight_lattice = simplex_lattice(n_objectives, config.weight_divisions);
+
+        MultiObjectiveColony {
+            ants: Vec::new(),
+            pheromones: Vec::new(),
+            combined_pheromone: Pheromone::new(),
+            routes: RouteCollection::default(),
+            config,
+            weight_lattice,
+            equal_weights: vec![1.0 / n_objectives as f32; n_objectives],
+            cycle_seed: SplitSeed::new(0),
+            best_route: Option::None,
+            cycles_without_improvement: 0,
+            reinitialized: false,
+        }
+        .initialize_pheromones()
+    }
+
+    pub fn get_objective_pheromones(&self) -> &[Pheromone] {
+        &self.pheromones
+    }
+
+    /// Weights of the ant with given index, ants are assigned lattice points
+    /// with an even stride, from the first objective towards the last one
+    pub fn get_ant_weights(&self, ant_idx: usize) -> &[f32] {
+        Self::weights_of_ant(&self.weight_lattice, ant_idx, self.config.ant_count)
+    }
+
+    /// Evaluates every weight vector of the lattice and keeps the non-dominated ones,
+    /// points are ordered as in the lattice (from the first objective towards the last)
+    pub fn get_pareto_front(&self) -> Vec<ParetoPoint> {
+        let points = self
+            .weight_lattice
+            .iter()
+            .map(|weights| self.evaluate_weights(weights))
+            .collect::<Vec<_>>();
+
+        points
+            .iter()
+            .filter(|point| !points.iter().any(|other| other.dominates(point)))
+            .cloned()
+            .collect()
+    }
+
+    pub fn evaluate_weights(&self, weights: &[f32]) -> ParetoPoint {
+        let pheromone = WeightedPheromone::new(&self.pheromones, weights).to_pheromone();
+        let total: f32 = pheromone.iter().map(|(_, value)| value).sum();
+
+        let costs = self
+            .config
+            .objectives
+            .iter()
+            .map(|objective| {
+                let weighted_distance: f32 = pheromone
+                    .iter()
+                    .map(|(key, value)| {
+                        value
+                            * objective
+                                .graph
+                                .get_edge(key)
+                                .map_or(0.0, |edge| edge.distance)
+                    })
+                    .sum();
+
+                iif!(total > 0.0, weighted_distance / total, 0.0)
+            })
+            .collect();
+
+        ParetoPoint {
+            weights: weights.to_vec(),
+            costs,
+            pheromone,
+        }
+    }
+
+    fn execute_step_for_all_ants(self, step: usize) -> Self {
+        let MultiObjectiveColony {
+            ants: init_ants,
+            pheromones: init_pheromones,
+            routes: init_routes,
+            config,
+            weight_lattice,
+            cycle_seed,
+            ..
+        } = self;
+
+        let objectives = &config.objectives;
+        let shared_graph = objectives[0].graph.as_ref();
+        let problem = &config.problem;
+        let ant_dispatcher = &config.ant_dispatcher;
+        let ant_count = config.ant_count;
+        let lattice = &weight_lattice;
+        let select_edge = |ant_idx, ant: &Ant, pheromones: &Vec<Pheromone>, seeds| {
+            let (sample_seed, strategy_seed) = seeds;
+            let weights = Self::weights_of_ant(lattice, ant_idx, ant_count);
+            let weighted_pheromone = WeightedPheromone::new(pheromones, weights);
+            let possible_next_edges = problem
+                .get_feasible_edges(ant, shared_graph)
+                .iter()
+                .map(|edge| Self::weigh_edge(objectives, edge, weights))
+                .collect::<Vec<_>>();
+
+            ant_dispatcher.select_next_edge(
+                &possible_next_edges,
+                &weighted_pheromone,
+                sample_seed,
+                strategy_seed,
+            )
+        };
+
+        let has_local_update = objectives
+            .iter()
+            .any(|objective| objective.pheromone_updater.has_local_update());
+
+        let (ants, taken_edges, step_pheromones) = stepping::execute_step_for_all_ants(
+            init_ants,
+            init_pheromones,
+            &cycle_seed,
+            step,
+            has_local_update,
+            select_edge,
+            |pheromones, next_edge| Self::apply_local_update(objectives, pheromones, next_edge),
+        );
+
+        let pheromones = objectives
+            .iter()
+            .zip_eq(step_pheromones)
+            .map(|(objective, pheromone)| {
+                let objective_edges = taken_edges
+                    .iter()
+                    .map(|maybe_edge| {
+                        maybe_edge.map(|edge| Self::project_edge(&edge, &objective.graph))
+                    })
+                    .collect::<RouteBatchWithHoles>();
+
+                objective
+                    .pheromone_updater
+                    .on_after_step(pheromone, &objective_edges)
+            })
+            .collect();
+
+        let routes = init_routes.add_steps(&taken_edges);
+
+        MultiObjectiveColony {
+            ants,
+            pheromones,
+            routes,
+            config,
+            weight_lattice,
+            cycle_seed,
+            ..self
+        }
+    }
+
+    /// Each ant takes the lattice point in the middle of its share of the lattice,
+    /// so trade-offs are spread evenly even with fewer ants than lattice points
+    fn weights_of_ant(weight_lattice: &[Vec<f32>], ant_idx: usize, ant_count: usize) -> &[f32] {
+        let point_idx = (2 * ant_idx + 1) * weight_lattice.len() / (2 * ant_count);
+
+        &weight_lattice[point_idx.min(weight_lattice.len() - 1)]
+    }
+
+    /// Trails of each objective are updated with the edge measured in that objective
+    fn apply_local_update(
+        objectives: &[Objective<U>],
+        pheromones: Vec<Pheromone>,
+        taken_edge: &AdjacencyListEntry,
+    ) -> Vec<Pheromone> {
+        objectives
+            .iter()
+            .zip_eq(pheromones)
+            .map(|(objective, pheromone)| {
+                let objective_edge = Self::project_edge(taken_edge, &objective.graph);
+
+                objective
+                    .pheromone_updater
+                    .on_after_ant_move(pheromone, &objective_edge)
+            })
+            .collect()
+    }
+
+    /// Edge with distance aggregated over objectives, the same way trails are
+    fn weigh_edge(
+        objectives: &[Objective<U>],
+        edge: &AdjacencyListEntry,
+        weights: &[f32],
+    ) -> AdjacencyListEntry {
+        let distance = objectives
+            .iter()
+            .zip(weights.iter())
+            .map(|(objective, weight)| {
+                Self::project_edge(edge, &objective.graph)
+                    .distance
+                    .powf(*weight)
+            })
+            .product();
+
+        edge.with_distance(distance)
+    }
+
+    /// Edge with distance measured in the objective given by its graph
+    fn project_edge(edge: &AdjacencyListEntry, graph: &Graph) -> AdjacencyListEntry {
+        graph.get_edge(edge).map_or(*edge, |objective_edge| {
+            edge.with_distance(objective_edge.distance)
+        })
+    }
+
+    fn project_routes(routes: &RouteCollection, graph: &Graph) -> RouteCollection {
+        let projected = routes
+            .get_routes()
+            .iter()
+            .map(|route| {
+                route
+                    .get_edges()
+                    .iter()
+                    .fold(Route::new(route.get_length()), |projected, edge| {
+                        projected.add_step(Self::project_edge(edge, graph))
+                    })
+            })
+            .collect();
+
+        RouteCollection::from_routes(projected)
+    }
+
+    fn initialize_cycle_seed(self) -> Self {
+        let mut config = self.config;
+        let cycle_seed = stepping::initialize_cycle_seed(&mut config.rng);
+
+        MultiObjectiveColony {
+            cycle_seed,
+            config,
+            ..self
+        }
+    }
+
+    fn initialize_routes(self) -> Self {
+        let routes =
+            stepping::initialize_routes(self.config.ant_count, self.config.num_of_steps_per_cycle);
+
+        MultiObjectiveColony { routes, ..self }
+    }
+
+    fn initialize_ants(self) -> Self {
+        let mut config = self.config;
+        let ants = stepping::initialize_ants(
+            self.ants,
+            config.ant_count,
+            &config.objectives[0].graph,
+            &config.problem,
+            &config.ant_dispatcher,
+            &mut config.rng,
+        );
+
+        MultiObjectiveColony {
+            ants,
+            config,
+            ..self
+        }
+    }
+
+    fn initialize_pheromones(self) -> Self {
+        let pheromones = self
+            .config
+            .objectives
+            .iter()
+            .map(|objective| {
+                let graph = &objective.graph;
+                let empty_pheromone = Pheromone::with_edge_index(graph.get_edge_index());
+
+                objective
+                    .pheromone_updater
+                    .initialize(empty_pheromone, graph.get_all_edges())
+            })
+            .collect();
+
+        MultiObjectiveColony { pheromones, ..self }.combine_pheromone()
+    }
+
+    fn combine_pheromone(self) -> Self {
+        let combined_pheromone =
+            WeightedPheromone::new(&self.pheromones, &self.equal_weights).to_pheromone();
+
+        MultiObjectiveColony {
+            combined_pheromone,
+            ..self
+        }
+    }
+
+    /// Routes are assigned costs of the problem, so updaters reinforce only feasible ones
+    fn evaluate_routes(&self, routes: RouteCollection, graph: &Graph) -> RouteCollection {
+        let target_num_of_steps = self.config.num_of_steps_per_cycle;
+        let problem = &self.config.problem;
+        let costs = self
+            .ants
+            .iter()
+            .zip_eq(routes.get_routes())
+            .map(|(ant, route)| problem.calc_solution_cost(ant, route, graph, target_num_of_steps))
+            .collect();
+
+        routes.with_costs(costs)
+    }
+
+    fn track_best_route(self) -> Self {
+        let objectives = &self.config.objectives;
+        let weights = &self.equal_weights;
+
+        let weighted_routes = self
+            .routes
+            .get_routes()
+            .iter()
+            .map(|route| {
+                route
+                    .get_edges()
+                    .iter()
+                    .fold(Route::new(route.get_length()), |weighted, edge| {
+                        weighted.add_step(Self::weigh_edge(objectives, edge, weights))
+                    })
+            })
+            .collect();
+        let weighted_routes = self.evaluate_routes(
+            RouteCollection::from_routes(weighted_routes),
+            &objectives[0].graph,
+        );
+        let (best_route, improved) = stepping::track_best_route(
+            self.best_route,
+            &weighted_routes,
+            self.config.num_of_steps_per_cycle,
+        );
+
+        MultiObjectiveColony {
+            best_route,
+            cycles_without_improvement: iif!(improved, 0, self.cycles_without_improvement + 1),
+            ..self
+        }
+    }
+
+    /// Resets trails of all objectives to their updaters' initial values, keeping
+    /// best-so-far routes, stagnation is judged on the trails aggregated with equal weights
+    fn reinitialize_on_stagnation(self) -> Self {
+        let is_stagnating = self.config.stagnation.is_stagnating(
+            &self.combined_pheromone,
+            &self.config.objectives[0].graph,
+            self.cycles_without_improvement,
+        );
+
+        if !is_stagnating {
+            return MultiObjectiveColony {
+                reinitialized: false,
+                ..self
+            };
+        }
+
+        MultiObjectiveColony {
+            cycles_without_improvement: 0,
+            reinitialized: true,
+            ..self
+        }
+        .initialize_pheromones()
+    }
+}
+
+impl<U, D, R> Display for MultiObjectiveColony<U, D, R>
+where
+    U: PheromoneUpdater,
+    D: AntDispatcher,
+    R: Rng,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Multi-objective colony\n\t\
+            objectives:     {:>5}\n\t\
+            weight vectors: {:>5}",
+            self.config.objectives.len(),
+            self.weight_lattice.len()
+        )
+    }
+}
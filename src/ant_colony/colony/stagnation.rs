@@ -0,0 +1,84 @@
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::Graph;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::ant_colony::pheromone_reader::PheromoneReader;
+
+/// Determines when the colony considers the search stagnated
+/// and reinitializes its trails
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StagnationCriterion {
+    /// trails are never reinitialized
+    Never,
+    /// average lambda-branching factor dropped to the threshold
+    BranchingFactor { lambda: f32, threshold: f32 },
+    /// best-so-far route did not improve for given number of cycles
+    NoImprovement(usize),
+}
+
+impl Default for StagnationCriterion {
+    fn default() -> Self {
+        Self::Never
+    }
+}
+
+impl StagnationCriterion {
+    pub fn is_stagnating(
+        &self,
+        pheromone: &Pheromone,
+        graph: &Graph,
+        cycles_without_improvement: usize,
+    ) -> bool {
+        match *self {
+            Self::Never => false,
+            Self::BranchingFactor { lambda, threshold } => {
+                PheromoneReader::calc_branching_factor(pheromone, graph, lambda) <= threshold
+            }
+            Self::NoImprovement(n_cycles) => cycles_without_improvement >= n_cycles,
+        }
+    }
+}
+
+impl FromStr for StagnationCriterion {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse stagnation criterion";
+
+        let mut config_iter = s.split(':');
+        let name = config_iter.next().unwrap_or_default();
+        let opts = config_iter.next().unwrap_or_default();
+
+        match name {
+            "none" => Ok(Self::Never),
+            "branching" => {
+                let (lambda, threshold): (f32, f32) = opts
+                    .splitn(2, ',')
+                    .map(str::parse)
+                    .filter_map(Result::ok)
+                    .collect_tuple()
+                    .ok_or(error)?;
+
+                Ok(Self::BranchingFactor { lambda, threshold })
+            }
+            "unchanged" => opts
+                .parse::<usize>()
+                .map(Self::NoImprovement)
+                .map_err(|_| error),
+            _ => Err(error),
+        }
+    }
+}
+
+impl Display for StagnationCriterion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Never => write!(f, "none"),
+            Self::BranchingFactor { lambda, threshold } => {
+                write!(f, "branching factor (lambda {}) <= {}", lambda, threshold)
+            }
+            Self::NoImprovement(n_cycles) => write!(f, "no improvement in {} cycles", n_cycles),
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use cfg_if::cfg_if;
+
+cfg_if! {
+    if #[cfg(feature = "singlethread")] {
+    } else {
+        use rayon::prelude::*;
+    }
+}
+
+use std::collections::HashSet;
+
+use rand::Rng;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::ant_dispatcher::AntDispatcher;
+use crate::ant_colony::graph::{
+    AdjacencyListEntry, Graph, Route, RouteBatchWithHoles, RouteCollection,
+};
+use crate::ant_colony::problem::{Problem, Problems};
+use crate::common::utils::SplitSeed;
+
+/// Moves all ants by a single step, shared by colonies regardless of how they keep trails.
+///
+/// Each ant draws its seeds from a separate stream derived from the cycle seed,
+/// ant index and step, so results do not depend on the number of threads.
+///
+/// With local update, every ant observes local updates of ants with lower indices,
+/// as if ants moved one after another. Ants still select their edges in parallel,
+/// against trails from the beginning of the step; then the updates are applied in order
+/// of ants, and only an ant, whose node was touched by an edge taken earlier in the step,
+/// selects again. That relies on `select_edge` reading trails of edges leaving the ant's node
+/// only, and on the local update changing the trail of the taken edge only.
+pub fn execute_step_for_all_ants<T, S, L>(
+    ants: Vec<Ant>,
+    trails: T,
+    cycle_seed: &SplitSeed,
+    step: usize,
+    has_local_update: bool,
+    select_edge: S,
+    apply_local_update: L,
+) -> (Vec<Ant>, RouteBatchWithHoles, T)
+where
+    T: Sync,
+    S: Fn(usize, &Ant, &T, (f32, f32)) -> Option<AdjacencyListEntry> + Sync + Send,
+    L: Fn(T, &AdjacencyListEntry) -> T,
+{
+    let ants_w_seeds = ants
+        .into_iter()
+        .enumerate()
+        .map(|(ant_idx, ant)| {
+            let ant_seed = cycle_seed.split(ant_idx as u64).split(step as u64);
+
+            (ant_idx, ant, ant_seed.unit_pair())
+        })
+        .collect::<Vec<_>>();
+
+    cfg_if! {
+        if #[cfg(feature = "singlethread")] {
+            let workload = ants_w_seeds.into_iter();
+        } else {
+            let workload = ants_w_seeds.into_par_iter();
+        }
+    }
+
+    let selections = workload
+        .map(|(ant_idx, ant, seeds)| {
+            let maybe_next_edge = select_edge(ant_idx, &ant, &trails, seeds);
+
+            (ant_idx, ant, seeds, maybe_next_edge)
+        })
+        .collect::<Vec<_>>();
+
+    if !has_local_update {
+        let (ants, taken_edges) = selections
+            .into_iter()
+            .map(|(_, ant, _, maybe_next_edge)| (move_ant(ant, &maybe_next_edge), maybe_next_edge))
+            .unzip();
+
+        return (ants, taken_edges, trails);
+    }
+
+    let (ants, taken_edges, trails, _) = selections.into_iter().fold(
+        (Vec::new(), Vec::new(), trails, HashSet::new()),
+        |(mut ants, mut taken_edges, trails, mut touched_nodes),
+         (ant_idx, ant, seeds, selected_edge)| {
+            let maybe_next_edge = iif!(
+                touched_nodes.contains(&ant.current_node),
+                select_edge(ant_idx, &ant, &trails, seeds),
+                selected_edge
+            );
+            let trails = match &maybe_next_edge {
+                Option::Some(next_edge) => {
+                    touched_nodes.insert(next_edge.from);
+                    touched_nodes.insert(next_edge.to);
+
+                    apply_local_update(trails, next_edge)
+                }
+                Option::None => trails,
+            };
+
+            ants.push(move_ant(ant, &maybe_next_edge));
+            taken_edges.push(maybe_next_edge);
+
+            (ants, taken_edges, trails, touched_nodes)
+        },
+    );
+
+    (ants, taken_edges, trails)
+}
+
+fn move_ant(ant: Ant, maybe_next_edge: &Option<AdjacencyListEntry>) -> Ant {
+    match maybe_next_edge {
+        Option::Some(next_edge) => ant.move_to_node(next_edge.to),
+        Option::None => ant,
+    }
+}
+
+/// Seed of the cycle, ants derive their own seeds from it
+pub fn initialize_cycle_seed<R: Rng>(rng: &mut R) -> SplitSeed {
+    SplitSeed::new(rng.gen())
+}
+
+pub fn initialize_routes(ant_count: usize, num_of_steps_per_cycle: usize) -> RouteCollection {
+    RouteCollection::new(ant_count, num_of_steps_per_cycle)
+}
+
+/// Ants from the previous cycle are placed again, so their memory is reused
+pub fn initialize_ants<D: AntDispatcher, R: Rng>(
+    ants: Vec<Ant>,
+    ant_count: usize,
+    graph: &Graph,
+    problem: &Problems,
+    ant_dispatcher: &D,
+    rng: &mut R,
+) -> Vec<Ant> {
+    let start_nodes = problem.get_start_nodes(graph);
+
+    ant_dispatcher.replace_ants_on_graph(ants, ant_count, graph, &start_nodes, rng)
+}
+
+/// Returns the best-so-far route, replaced by the best route of the cycle if that one is cheaper,
+/// and whether it was replaced
+pub fn track_best_route(
+    best_route: Option<(Route, f32)>,
+    routes: &RouteCollection,
+    target_num_of_steps: usize,
+) -> (Option<(Route, f32)>, bool) {
+    let maybe_candidate = routes.get_best_route(target_num_of_steps);
+
+    let improved = match (&maybe_candidate, &best_route) {
+        (Option::Some((_, candidate_cost)), Option::Some((_, best_cost))) => {
+            candidate_cost < best_cost
+        }
+        (Option::Some(_), Option::None) => true,
+        (Option::None, _) => false,
+    };
+
+    iif!(improved, (maybe_candidate, true), (best_route, false))
+}
@@ -1,23 +1,17 @@
-use cfg_if::cfg_if;
-
-cfg_if! {
-    if #[cfg(feature = "singlethread")] {
-    } else {
-        use rayon::prelude::*;
-    }
-}
-
-use rand::{distributions::Uniform, Rng};
+use itertools::Itertools;
+use rand::Rng;
 use std::fmt::Display;
-use std::rc::Rc;
+use std::sync::Arc;
 
 use crate::ant_colony::ant::Ant;
 use crate::ant_colony::ant_dispatcher::AntDispatcher;
-use crate::ant_colony::graph::{Graph, RouteBatchWithHoles, RouteCollection};
+use crate::ant_colony::graph::{Graph, Route, RouteCollection};
 use crate::ant_colony::pheromone::Pheromone;
 use crate::ant_colony::pheromone_updater::PheromoneUpdater;
-use crate::common::utils::random_pair_iter;
+use crate::ant_colony::problem::Problem;
+use crate::common::utils::SplitSeed;
 
+use super::stepping;
 use super::{Colony, Config, ConfigurableColony};
 
 pub struct StepwiseParallelColony<U, D, R>
@@ -27,10 +21,14 @@ where
     R: Rng,
 {
     ants: Vec<Ant>,
-    graph: Rc<Graph>,
+    graph: Arc<Graph>,
     pheromone: Pheromone,
     routes: RouteCollection,
     config: Config<U, D, R>,
+    cycle_seed: SplitSeed,
+    best_route: Option<(Route, f32)>,
+    cycles_without_improvement: usize,
+    reinitialized: bool,
 }
 
 impl<U, D, R> Colony for StepwiseParallelColony<U, D, R>
@@ -46,12 +44,17 @@ where
     #[cfg_attr(feature = "profiler", flame)]
     fn execute_cycle(self, _cycle: usize) -> Self {
         let steps = 0..self.config.num_of_steps_per_cycle;
-        let init_colony = self.initialize_ants().initialize_routes();
-
-        let colony = steps.fold(
-            init_colony,
-            StepwiseParallelColony::execute_step_for_all_ants,
-        );
+        let init_colony = self
+            .initialize_ants()
+            .initialize_cycle_seed()
+            .initialize_routes();
+
+        let colony = steps
+            .fold(
+                init_colony,
+                StepwiseParallelColony::execute_step_for_all_ants,
+            )
+            .evaluate_routes();
 
         let pheromone = colony
             .config
@@ -62,12 +65,30 @@ where
             pheromone,
             ..colony
         }
+        .track_best_route()
+        .reinitialize_on_stagnation()
     }
 
     fn get_pheromone(&self) -> &Pheromone {
         &self.pheromone
     }
 
+    fn replace_pheromone(self, pheromone: Pheromone) -> Self {
+        StepwiseParallelColony { pheromone, ..self }
+    }
+
+    /// Updater forgets its state as well, unlike on reinitialization after stagnation
+    fn reset_pheromone(self) -> Self {
+        self.config.pheromone_updater.reset();
+
+        StepwiseParallelColony {
+            pheromone: Pheromone::new(),
+            cycles_without_improvement: 0,
+            ..self
+        }
+        .initialize_pheromone()
+    }
+
     fn get_routes(&self) -> &RouteCollection {
         &self.routes
     }
@@ -75,6 +96,18 @@ where
     fn get_ants(&self) -> &[Ant] {
         &self.ants
     }
+
+    fn get_best_route(&self) -> Option<&Route> {
+        self.best_route.as_ref().map(|(route, _)| route)
+    }
+
+    fn get_best_route_cost(&self) -> Option<f32> {
+        self.best_route.as_ref().map(|(_, cost)| *cost)
+    }
+
+    fn was_reinitialized(&self) -> bool {
+        self.reinitialized
+    }
 }
 
 impl<U, D, R> ConfigurableColony for StepwiseParallelColony<U, D, R>
@@ -89,7 +122,7 @@ where
 
     fn new(
         config: Config<Self::Updater, Self::Dispatcher, Self::Random>,
-        graph: Rc<Graph>,
+        graph: Arc<Graph>,
     ) -> Self {
         StepwiseParallelColony {
             graph,
@@ -97,6 +130,10 @@ where
             routes: RouteCollection::default(),
             ants: Vec::new(),
             pheromone: Pheromone::new(),
+            cycle_seed: SplitSeed::new(0),
+            best_route: Option::None,
+            cycles_without_improvement: 0,
+            reinitialized: false,
         }
         .initialize_pheromone()
     }
@@ -109,56 +146,48 @@ where
     R: Rng,
 {
     #[cfg_attr(feature = "profiler", flame)]
-    fn execute_step_for_all_ants(self, _step: usize) -> Self {
+    fn execute_step_for_all_ants(self, step: usize) -> Self {
         let StepwiseParallelColony {
             ants: init_ants,
             graph,
             pheromone: init_pheromone,
             routes: init_routes,
             config,
+            cycle_seed,
             ..
         } = self;
 
         let Config {
             pheromone_updater,
             ant_dispatcher,
-            mut rng,
             ..
         } = config;
 
-        let seeds = random_pair_iter(&mut rng, Uniform::new::<f32, f32>(0.0, 1.0));
-
-        let ants_w_seeds = init_ants.into_iter().zip(seeds).collect::<Vec<_>>();
-
-        cfg_if! {
-            if #[cfg(feature = "singlethread")] {
-                let workload = ants_w_seeds.into_iter();
-            } else {
-                let workload = ants_w_seeds.into_par_iter();
-            }
-        }
-
         let shared_graph = graph.as_ref();
-        let (ants, taken_edges): (Vec<Ant>, RouteBatchWithHoles) = workload
-            .map(|(ant, (sample_seed, strategy_seed))| {
-                let maybe_next_edge = ant_dispatcher.select_next_edge(
-                    &ant,
-                    shared_graph,
-                    &init_pheromone,
-                    sample_seed,
-                    strategy_seed,
-                );
-
-                let next_ant = match maybe_next_edge {
-                    Option::Some(next_edge) => ant.move_to_node(next_edge.to),
-                    Option::None => ant,
-                };
-
-                (next_ant, maybe_next_edge)
-            })
-            .unzip();
-
-        let pheromone = pheromone_updater.on_after_step(init_pheromone, &taken_edges);
+        let problem = &config.problem;
+        let select_edge = |_ant_idx, ant: &Ant, pheromone: &Pheromone, seeds| {
+            let (sample_seed, strategy_seed) = seeds;
+            let possible_next_edges = problem.get_feasible_edges(ant, shared_graph);
+
+            ant_dispatcher.select_next_edge(
+                &possible_next_edges,
+                pheromone,
+                sample_seed,
+                strategy_seed,
+            )
+        };
+
+        let (ants, taken_edges, step_pheromone) = stepping::execute_step_for_all_ants(
+            init_ants,
+            init_pheromone,
+            &cycle_seed,
+            step,
+            pheromone_updater.has_local_update(),
+            select_edge,
+            |pheromone, next_edge| pheromone_updater.on_after_ant_move(pheromone, next_edge),
+        );
+
+        let pheromone = pheromone_updater.on_after_step(step_pheromone, &taken_edges);
 
         let routes = init_routes.add_steps(&taken_edges);
 
@@ -169,27 +198,40 @@ where
             pheromone,
             config: Config {
                 ant_dispatcher,
-                rng,
                 pheromone_updater,
                 ..config
             },
+            cycle_seed,
+            ..self
+        }
+    }
+
+    fn initialize_cycle_seed(self) -> Self {
+        let mut config = self.config;
+        let cycle_seed = stepping::initialize_cycle_seed(&mut config.rng);
+
+        StepwiseParallelColony {
+            cycle_seed,
+            config,
             ..self
         }
     }
 
     fn initialize_routes(self) -> Self {
         let routes =
-            RouteCollection::new(self.config.ant_count, self.config.num_of_steps_per_cycle);
+            stepping::initialize_routes(self.config.ant_count, self.config.num_of_steps_per_cycle);
 
         StepwiseParallelColony { routes, ..self }
     }
 
     fn initialize_ants(self) -> Self {
         let mut config = self.config;
-
-        let ants = config.ant_dispatcher.place_ants_on_graph(
+        let ants = stepping::initialize_ants(
+            self.ants,
             config.ant_count,
             &self.graph,
+            &config.problem,
+            &config.ant_dispatcher,
             &mut config.rng,
         );
 
@@ -200,13 +242,71 @@ where
         }
     }
 
+    /// Routes are assigned costs of the problem, so updaters reinforce only feasible ones
+    fn evaluate_routes(self) -> Self {
+        let target_num_of_steps = self.config.num_of_steps_per_cycle;
+        let problem = &self.config.problem;
+        let graph = self.graph.as_ref();
+        let costs = self
+            .ants
+            .iter()
+            .zip_eq(self.routes.get_routes())
+            .map(|(ant, route)| problem.calc_solution_cost(ant, route, graph, target_num_of_steps))
+            .collect();
+
+        StepwiseParallelColony {
+            routes: self.routes.with_costs(costs),
+            ..self
+        }
+    }
+
+    fn track_best_route(self) -> Self {
+        let (best_route, improved) = stepping::track_best_route(
+            self.best_route,
+            &self.routes,
+            self.config.num_of_steps_per_cycle,
+        );
+
+        StepwiseParallelColony {
+            best_route,
+            cycles_without_improvement: iif!(improved, 0, self.cycles_without_improvement + 1),
+            ..self
+        }
+    }
+
+    /// Resets trails to the updater's initial value,
+    /// best-so-far routes of the colony and of the updater are kept
+    fn reinitialize_on_stagnation(self) -> Self {
+        let is_stagnating = self.config.stagnation.is_stagnating(
+            &self.pheromone,
+            &self.graph,
+            self.cycles_without_improvement,
+        );
+
+        if !is_stagnating {
+            return StepwiseParallelColony {
+                reinitialized: false,
+                ..self
+            };
+        }
+
+        StepwiseParallelColony {
+            pheromone: Pheromone::new(),
+            cycles_without_improvement: 0,
+            reinitialized: true,
+            ..self
+        }
+        .initialize_pheromone()
+    }
+
     fn initialize_pheromone(self) -> Self {
         let edges = self.graph.get_all_edges();
+        let empty_pheromone = Pheromone::with_edge_index(self.graph.get_edge_index());
 
         let pheromone = self
             .config
             .pheromone_updater
-            .initialize(self.pheromone, &edges);
+            .initialize(empty_pheromone, edges);
 
         StepwiseParallelColony { pheromone, ..self }
     }
@@ -1,19 +1,40 @@
 #[cfg(test)]
 mod graph_tests {
     use super::super::_mocks::{mock_coordinates, mock_graph_tuple, mock_graph_vector};
-    use crate::ant_colony::graph::Graph;
+    use crate::ant_colony::graph::{AdjacencyListEntry, EdgeMode, Graph};
 
     #[test]
     fn it_returns_correct_node_ids() {
         let graph = Graph::from_node_vector(mock_graph_vector());
         let expected_ids: Vec<_> = mock_graph_vector().iter().map(|node| node.id).collect();
 
-        let mut result = graph.get_node_ids();
+        let mut result = graph.get_node_ids().to_vec();
         result.sort();
 
         assert_eq!(result, expected_ids);
     }
 
+    #[test]
+    fn it_returns_both_directions_of_edges_by_keys_in_order_of_graph() {
+        let graph = Graph::from_node_vector(mock_graph_vector());
+        let keys = vec![
+            AdjacencyListEntry::get_key(2, 1),
+            AdjacencyListEntry::get_key(0, 1),
+            AdjacencyListEntry::get_key(0, 1),
+            AdjacencyListEntry::get_key(100, 101),
+        ];
+
+        let expected = graph
+            .get_all_edges()
+            .iter()
+            .filter(|edge| keys.contains(&edge.key))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        assert_eq!(expected.len(), 4);
+        assert_eq!(graph.get_edges(&keys), expected);
+    }
+
     #[test]
     fn it_returns_correct_adjacent_nodes() {
         let graph = Graph::from_node_vector(mock_graph_vector());
@@ -52,4 +73,143 @@ mod graph_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn it_assigns_compact_index_to_each_edge() {
+        let graph = Graph::from_node_vector(mock_graph_vector());
+        let edge_index = graph.get_edge_index();
+
+        let mut indices = graph
+            .get_all_edges()
+            .iter()
+            .map(|edge| edge.key)
+            .filter_map(|key| edge_index.get_idx(key))
+            .collect::<Vec<_>>();
+        indices.sort();
+        indices.dedup();
+
+        // both directions of an edge share the index
+        assert_eq!(edge_index.len(), mock_graph_tuple().len());
+        assert_eq!(indices, (0..mock_graph_tuple().len()).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn it_supports_initialization_from_flat_edges() {
+        let edges = mock_graph_vector()
+            .into_iter()
+            .rev()
+            .flat_map(|node| node.adjacency_list)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            Graph::from_edges(edges),
+            Graph::from_node_vector(mock_graph_vector())
+        )
+    }
+
+    #[test]
+    fn it_keeps_isolated_nodes_if_given() {
+        let edges = vec![
+            AdjacencyListEntry::new(0, 1, 1.0),
+            AdjacencyListEntry::new(1, 0, 1.0),
+        ];
+
+        let graph = Graph::from_nodes_and_edges(vec![0, 1, 2], edges.clone());
+
+        assert_eq!(graph.get_node_ids(), vec![0, 1, 2]);
+        assert!(graph.get_adjacent_edges(&2).is_empty());
+        assert_eq!(Graph::from_edges(edges).get_node_ids(), vec![0, 1]);
+    }
+
+    #[test]
+    fn it_assigns_compact_index_to_edges() {
+        let graph = Graph::from_node_vector(mock_graph_vector());
+        let edge_index = graph.get_edge_index();
+
+        assert!(graph
+            .get_all_edges()
+            .iter()
+            .all(|edge| edge.idx.map(|idx| idx as usize) == edge_index.get_idx(edge.key)));
+    }
+
+    #[test]
+    fn it_looks_up_edges_by_key() {
+        let graph = Graph::from_node_vector(mock_graph_vector());
+        let key = AdjacencyListEntry::get_key(2, 3);
+
+        let result = graph.get_edge(key).map(|edge| edge.distance);
+
+        assert_eq!(result, Some(3.0));
+        assert_eq!(graph.get_edge(AdjacencyListEntry::get_key(2, 7)), None);
+    }
+
+    #[test]
+    fn it_returns_no_edges_for_unknown_node() {
+        let graph = Graph::from_node_vector(mock_graph_vector());
+
+        assert!(graph.get_adjacent_edges(&42).is_empty());
+    }
+
+    #[test]
+    fn it_supports_initialization_from_asymmetric_distance_matrix() {
+        let inf = f32::INFINITY;
+        let graph = Graph::from_distance_matrix(&[
+            vec![0.0, 1.0, 9.0],
+            vec![5.0, 0.0, 1.0],
+            vec![1.0, inf, 0.0],
+        ]);
+
+        let forward = graph.get_edge(EdgeMode::Directed.generate_key(0, 1));
+        let backward = graph.get_edge(EdgeMode::Directed.generate_key(1, 0));
+
+        assert!(graph.is_directed());
+        assert_eq!(graph.get_amount_of_edges(), 5);
+        assert_eq!(graph.get_edge_index().len(), 5);
+        assert_eq!(forward.map(|edge| edge.distance), Some(1.0));
+        assert_eq!(backward.map(|edge| edge.distance), Some(5.0));
+        assert!(graph.get_adjacent_edges(&2).iter().all(|edge| edge.to != 1));
+        assert_eq!(
+            Graph::from_distance_matrix_csv("0,1,9\n5,0,1\n1,,0\n"),
+            Ok(graph.clone())
+        );
+        assert_eq!(
+            Graph::from_distance_matrix_csv("0, 1, 9\r\n5, 0, 1\r\n1, inf, 0\r\n"),
+            Ok(graph)
+        );
+    }
+
+    #[test]
+    fn it_rejects_malformed_distance_matrix() {
+        let errors = [
+            "0,1,9\n5,0,1\n1,x,0\n",
+            "0,1,9\n5,0,-1\n1,2,0\n",
+            "0,1,9\n5,0,NaN\n1,2,0\n",
+            "0,1\n5,0\n1,2\n",
+            "0,1,9\n5,0\n1,2,0\n",
+        ]
+        .iter()
+        .map(|csv| Graph::from_distance_matrix_csv(csv).unwrap_err())
+        .collect::<Vec<_>>();
+
+        assert_eq!(errors[0], "invalid distance 'x' in row 3, column 2");
+        assert_eq!(errors[1], "invalid distance '-1' in row 2, column 3");
+        assert_eq!(errors[2], "invalid distance 'NaN' in row 2, column 3");
+        assert!(errors[3].contains("not square"));
+        assert!(errors[4].contains("row 2 has 2 cells"));
+    }
+
+    #[test]
+    fn it_keeps_directed_keys_when_inverting_distances() {
+        let graph = Graph::from_directed_tuples(vec![(0, 1, 2.0), (1, 0, 4.0)]);
+
+        let inverted = graph.clone().invert_distances();
+
+        assert_eq!(inverted.get_edge_index(), graph.get_edge_index());
+        assert_eq!(
+            inverted
+                .get_edge(EdgeMode::Directed.generate_key(1, 0))
+                .map(|edge| edge.distance),
+            Some(0.25)
+        );
+    }
 }
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod graph_routes_tests {
-    use super::super::super::{AdjacencyListEntry, Route};
+    use super::super::super::{AdjacencyListEntry, Route, RouteCollection};
 
     fn get_mock_route() -> Route {
         Route::default()
@@ -10,6 +10,7 @@ mod graph_routes_tests {
                 to: 1,
                 distance: 1.0,
                 visibility: 1.0,
+                idx: Option::None,
             })
             .add_step(AdjacencyListEntry {
                 key: 1,
@@ -17,6 +18,7 @@ mod graph_routes_tests {
                 to: 2,
                 distance: 2.0,
                 visibility: 0.5,
+                idx: Option::None,
             })
             .add_step(AdjacencyListEntry {
                 key: 2,
@@ -24,6 +26,7 @@ mod graph_routes_tests {
                 to: 3,
                 distance: 3.0,
                 visibility: 0.3333,
+                idx: Option::None,
             })
     }
 
@@ -41,4 +44,62 @@ mod graph_routes_tests {
 
         assert_eq!(route.get_nodes(), vec![0, 1, 2, 3]);
     }
+
+    #[test]
+    fn it_returns_n_best_routes_in_order() {
+        let edge = |key, distance| AdjacencyListEntry {
+            key,
+            from: 0,
+            to: 1,
+            distance,
+            visibility: 1.0 / distance,
+            idx: Option::None,
+        };
+
+        let routes = RouteCollection::new(3, 1).add_steps(&vec![
+            Option::Some(edge(0, 3.0)),
+            Option::Some(edge(1, 1.0)),
+            Option::Some(edge(2, 2.0)),
+        ]);
+
+        let result = routes
+            .get_n_best_routes(2, 1)
+            .iter()
+            .map(|(route, _)| route.get_distance())
+            .collect::<Vec<_>>();
+
+        assert_eq!(result, vec![1.0, 2.0]);
+    }
+
+    #[test]
+    fn it_skips_infeasible_routes_when_selecting_best_ones() {
+        let edge = |key, distance| AdjacencyListEntry {
+            key,
+            from: 0,
+            to: 1,
+            distance,
+            visibility: 1.0 / distance,
+            idx: Option::None,
+        };
+
+        let routes = RouteCollection::new(3, 1)
+            .add_steps(&vec![
+                Option::Some(edge(0, 3.0)),
+                Option::Some(edge(1, 1.0)),
+                Option::Some(edge(2, 2.0)),
+            ])
+            .with_costs(vec![3.0, f32::INFINITY, 2.0]);
+
+        let result = routes.get_n_best_routes(3, 1);
+
+        assert_eq!(
+            result.iter().map(|(_, cost)| *cost).collect::<Vec<_>>(),
+            vec![2.0, 3.0]
+        );
+        assert_eq!(
+            routes.get_best_route(1).map(|(_, cost)| cost),
+            Option::Some(2.0)
+        );
+        assert_eq!(routes.get_feasible_routes(1).len(), 2);
+    }
 }
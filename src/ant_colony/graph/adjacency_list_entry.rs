@@ -1,30 +1,86 @@
-use crate::common::utils::UniquePair;
-
-use super::NodeId;
+use super::{EdgeMode, NodeId};
 
 pub type EdgeKey = u64;
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Debug, Clone, Copy)]
 pub struct AdjacencyListEntry {
     pub key: EdgeKey,
     pub from: NodeId,
     pub to: NodeId,
     pub distance: f32,
     pub visibility: f32,
+    /// compact index assigned by the graph, lets pheromone skip hashing the key
+    pub idx: Option<u32>,
+}
+
+/// Edge given either by its key or by its entry, which may carry the compact index
+pub trait EdgeRef: Copy {
+    fn key(self) -> EdgeKey;
+
+    /// Only a hint, it is valid for the edge index of the graph the edge comes from
+    fn idx_hint(self) -> Option<usize> {
+        Option::None
+    }
 }
 
 impl AdjacencyListEntry {
     pub fn new(from: NodeId, to: NodeId, distance: f32) -> Self {
+        AdjacencyListEntry::with_mode(from, to, distance, EdgeMode::Undirected)
+    }
+
+    /// Edge with its own key, not shared with the opposite direction
+    pub fn new_directed(from: NodeId, to: NodeId, distance: f32) -> Self {
+        AdjacencyListEntry::with_mode(from, to, distance, EdgeMode::Directed)
+    }
+
+    pub fn with_mode(from: NodeId, to: NodeId, distance: f32, mode: EdgeMode) -> Self {
         AdjacencyListEntry {
-            key: Self::get_key(from, to),
+            key: mode.generate_key(from, to),
             from,
             to,
             distance,
             visibility: 1.0 / (distance + stability_factor!()),
+            idx: Option::None,
+        }
+    }
+
+    /// Same edge (and key) with a different distance
+    pub fn with_distance(&self, distance: f32) -> Self {
+        AdjacencyListEntry {
+            distance,
+            visibility: 1.0 / (distance + stability_factor!()),
+            ..*self
         }
     }
 
     pub fn get_key(from: NodeId, to: NodeId) -> EdgeKey {
-        UniquePair::generate_key(from, to)
+        EdgeMode::Undirected.generate_key(from, to)
+    }
+}
+
+/// Compact index is not a part of the edge's identity
+impl PartialEq for AdjacencyListEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+            && self.from == other.from
+            && self.to == other.to
+            && self.distance == other.distance
+            && self.visibility == other.visibility
+    }
+}
+
+impl EdgeRef for EdgeKey {
+    fn key(self) -> EdgeKey {
+        self
+    }
+}
+
+impl EdgeRef for &AdjacencyListEntry {
+    fn key(self) -> EdgeKey {
+        self.key
+    }
+
+    fn idx_hint(self) -> Option<usize> {
+        self.idx.map(|idx| idx as usize)
     }
 }
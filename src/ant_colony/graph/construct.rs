@@ -1,10 +1,12 @@
 use rand::{distributions::Uniform, Rng};
 use std::collections::BTreeMap;
+use std::iter::once;
+use std::sync::Arc;
 
 use crate::common::utils::split_once;
 use crate::common::utils::Euclidean;
 
-use super::{AdjacencyListEntry, Graph, Node, NodeId};
+use super::{AdjacencyListEntry, EdgeIndex, EdgeMode, Graph, Node, NodeId};
 
 impl Graph {
     pub fn from_node_vector(nodes_vec: Vec<Node>) -> Self {
@@ -15,7 +17,7 @@ impl Graph {
                 nodes
             });
 
-        Graph { nodes }
+        Graph::from_node_map(nodes)
     }
 
     pub fn from_neighbour_tuples(tuple_vec: Vec<(NodeId, NodeId, f32)>) -> Self {
@@ -38,7 +40,7 @@ impl Graph {
                 nodes
             });
 
-        Graph { nodes }
+        Graph::from_node_map(nodes)
     }
 
     pub fn random_tsp_graph<R: Rng>(rng: &mut R, nodes: usize) -> Self {
@@ -54,6 +56,94 @@ impl Graph {
         Graph::from_neighbour_tuples(tuples)
     }
 
+    /// Complete directed graph, distances of both directions are drawn independently
+    pub fn random_atsp_graph<R: Rng>(rng: &mut R, nodes: usize) -> Self {
+        let distances = rng.sample_iter(Uniform::from(0.1..9.9));
+
+        let nodes_32 = nodes as u32;
+        let tuples = (0..nodes_32)
+            .flat_map(|from| {
+                (0..nodes_32)
+                    .filter(move |to| *to != from)
+                    .map(move |to| (from, to))
+            })
+            .zip(distances)
+            .map(|((from, to), distance)| (from, to, distance))
+            .collect();
+
+        Graph::from_directed_tuples(tuples)
+    }
+
+    /// Each tuple is a single arc `from -> to`
+    pub fn from_directed_tuples(tuple_vec: Vec<(NodeId, NodeId, f32)>) -> Self {
+        let edges = tuple_vec
+            .into_iter()
+            .map(|(from, to, distance)| AdjacencyListEntry::new_directed(from, to, distance))
+            .collect();
+
+        Graph::from_directed_edges(edges)
+    }
+
+    /// Row is the source node, column the target one.
+    /// Diagonal and non-finite entries (missing arcs) are skipped,
+    /// the matrix does not need to be symmetric.
+    pub fn from_distance_matrix(matrix: &[Vec<f32>]) -> Self {
+        let tuples = (0u32..)
+            .zip(matrix.iter())
+            .flat_map(|(from, row)| {
+                (0u32..)
+                    .zip(row.iter())
+                    .filter(move |(to, distance)| *to != from && distance.is_finite())
+                    .map(move |(to, distance)| (from, to, *distance))
+            })
+            .collect();
+
+        Graph::from_directed_tuples(tuples)
+    }
+
+    /// Comma separated rows of a square distance matrix.
+    /// Empty cell or `inf` marks a missing arc, other cells must be non-negative numbers.
+    pub fn from_distance_matrix_csv(data: &str) -> Result<Self, String> {
+        let matrix = data
+            .split('\n')
+            .filter(|line| !line.trim().is_empty())
+            .zip(1..)
+            .map(|(line, row)| {
+                line.split(',')
+                    .zip(1..)
+                    .map(|(cell, column)| Self::parse_distance_cell(cell.trim(), row, column))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let n_rows = matrix.len();
+
+        if let Option::Some(row) = matrix.iter().position(|row| row.len() != n_rows) {
+            return Err(format!(
+                "distance matrix is not square, row {} has {} cells, expected {}",
+                row + 1,
+                matrix[row].len(),
+                n_rows
+            ));
+        }
+
+        Ok(Self::from_distance_matrix(&matrix))
+    }
+
+    fn parse_distance_cell(cell: &str, row: usize, column: usize) -> Result<f32, String> {
+        if cell.is_empty() || cell == "inf" {
+            return Ok(f32::INFINITY);
+        }
+
+        cell.parse::<f32>()
+            .ok()
+            .filter(|distance| distance.is_finite() && *distance >= 0.0)
+            .ok_or(format!(
+                "invalid distance '{}' in row {}, column {}",
+                cell, row, column
+            ))
+    }
+
     pub fn from_coordinates(points: &[(u16, u16)]) -> Self {
         let data = (0u32..).zip(points.iter()).collect::<Vec<_>>();
 
@@ -94,6 +184,100 @@ impl Graph {
         Self::from_coordinates(&coords)
     }
 
+    /// Builds graph directly from a flat list of directed edges,
+    /// without grouping them into nodes first.
+    /// Nodes are the endpoints of the edges, see `from_nodes_and_edges` to keep isolated ones.
+    pub fn from_edges(edges: Vec<AdjacencyListEntry>) -> Self {
+        Graph::from_edges_with_mode(Vec::new(), edges, EdgeMode::Undirected)
+    }
+
+    /// Same as `from_edges`, but nodes without any edges are kept as well
+    pub fn from_nodes_and_edges(node_ids: Vec<NodeId>, edges: Vec<AdjacencyListEntry>) -> Self {
+        Graph::from_edges_with_mode(node_ids, edges, EdgeMode::Undirected)
+    }
+
+    /// Same as `from_edges`, but edges are expected to have directed keys
+    pub fn from_directed_edges(edges: Vec<AdjacencyListEntry>) -> Self {
+        Graph::from_edges_with_mode(Vec::new(), edges, EdgeMode::Directed)
+    }
+
+    fn from_edges_with_mode(
+        mut node_ids: Vec<NodeId>,
+        mut edges: Vec<AdjacencyListEntry>,
+        mode: EdgeMode,
+    ) -> Self {
+        // stable, so order of edges leaving the same node is kept
+        edges.sort_by_key(|edge| edge.from);
+
+        node_ids.extend(
+            edges
+                .iter()
+                .flat_map(|edge| once(edge.from).chain(once(edge.to))),
+        );
+
+        node_ids.sort_unstable();
+        node_ids.dedup();
+
+        Graph::from_sorted_edges(mode, node_ids, edges)
+    }
+
+    pub(super) fn from_node_map(nodes: BTreeMap<NodeId, Node>) -> Self {
+        let node_ids = nodes.keys().cloned().collect::<Vec<_>>();
+        let edges = nodes
+            .into_iter()
+            .flat_map(|(_, node)| node.adjacency_list)
+            .collect::<Vec<_>>();
+
+        Graph::from_sorted_edges(EdgeMode::Undirected, node_ids, edges)
+    }
+
+    /// Flattens adjacency lists into CSR arrays and assigns the compact edge index.
+    /// Both `node_ids` and `edges` (by their source node) have to be sorted.
+    fn from_sorted_edges(
+        mode: EdgeMode,
+        node_ids: Vec<NodeId>,
+        edges: Vec<AdjacencyListEntry>,
+    ) -> Self {
+        let offsets = once(0)
+            .chain(node_ids.iter().scan(0, |offset, node_id| {
+                while *offset < edges.len() && edges[*offset].from == *node_id {
+                    *offset += 1;
+                }
+                Option::Some(*offset)
+            }))
+            .collect::<Vec<_>>();
+
+        let edge_index = EdgeIndex::from_keys(edges.iter().map(|edge| edge.key));
+        let edges = edges
+            .into_iter()
+            .map(|edge| AdjacencyListEntry {
+                idx: edge_index.get_idx(edge.key).map(|idx| idx as u32),
+                ..edge
+            })
+            .collect::<Vec<_>>();
+
+        // keys are indexed in order of their first occurrence,
+        // so a new index shows up exactly when it equals the count of seen ones
+        let edge_positions = edges.iter().enumerate().fold(
+            Vec::with_capacity(edge_index.len()),
+            |mut positions, (position, edge)| {
+                if edge.idx == Option::Some(positions.len() as u32) {
+                    positions.push(position);
+                }
+                positions
+            },
+        );
+
+        Graph {
+            mode,
+            node_ids,
+            offsets,
+            edges,
+            edge_index: Arc::new(edge_index),
+            edge_positions,
+        }
+    }
+
     fn parse_adjacency_list_from_tuple(
         node_id: NodeId,
         tuple_vec: &Vec<(NodeId, NodeId, f32)>,
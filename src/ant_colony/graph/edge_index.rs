@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use super::{EdgeKey, EdgeRef};
+
+/// Assigns compact, consecutive indices to edge keys.
+///
+/// Built once, together with the graph, and shared by all pheromone
+/// instances of that graph, so trails can be stored in a dense vector.
+/// In undirected graphs, both directions of an edge share the same key, hence the same index.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct EdgeIndex {
+    keys: Vec<EdgeKey>,
+    positions: HashMap<EdgeKey, usize>,
+}
+
+impl EdgeIndex {
+    pub fn new() -> Self {
+        EdgeIndex {
+            keys: Vec::new(),
+            positions: HashMap::new(),
+        }
+    }
+
+    /// Keys are indexed in order of their first occurrence
+    pub fn from_keys<I: IntoIterator<Item = EdgeKey>>(keys: I) -> Self {
+        keys.into_iter().fold(EdgeIndex::new(), |mut index, key| {
+            index.insert(key);
+            index
+        })
+    }
+
+    /// Returns index of the key, assigns the next free one if key was not indexed yet
+    pub fn insert(&mut self, key: EdgeKey) -> usize {
+        match self.positions.get(&key) {
+            Option::Some(idx) => *idx,
+            Option::None => {
+                let idx = self.keys.len();
+
+                self.keys.push(key);
+                self.positions.insert(key, idx);
+
+                idx
+            }
+        }
+    }
+
+    pub fn get_idx(&self, key: EdgeKey) -> Option<usize> {
+        self.positions.get(&key).cloned()
+    }
+
+    /// Uses index carried by the edge if it points to the same key, hashes the key otherwise
+    pub fn resolve<E: EdgeRef>(&self, edge: E) -> Option<usize> {
+        let key = edge.key();
+
+        match edge.idx_hint() {
+            Option::Some(idx) if self.keys.get(idx) == Option::Some(&key) => Option::Some(idx),
+            _ => self.get_idx(key),
+        }
+    }
+
+    pub fn get_key(&self, idx: usize) -> Option<EdgeKey> {
+        self.keys.get(idx).cloned()
+    }
+
+    pub fn get_keys(&self) -> &[EdgeKey] {
+        &self.keys
+    }
+
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
@@ -0,0 +1,46 @@
+use std::fmt::Display;
+
+use crate::common::utils::UniquePair;
+
+use super::{EdgeKey, NodeId};
+
+/// Determines whether both directions of an edge share the key (and the pheromone trail)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EdgeMode {
+    /// A->B and B->A is the same edge
+    Undirected,
+    /// A->B and B->A are separate edges, keys are ordered pairs
+    Directed,
+}
+
+impl Default for EdgeMode {
+    fn default() -> Self {
+        Self::Undirected
+    }
+}
+
+impl EdgeMode {
+    pub fn generate_key(&self, from: NodeId, to: NodeId) -> EdgeKey {
+        match self {
+            EdgeMode::Undirected => UniquePair::generate_key(from, to),
+            EdgeMode::Directed => UniquePair::generate_ordered_key(from, to),
+        }
+    }
+
+    /// In undirected mode, smaller id is returned first
+    pub fn decode_key(&self, key: EdgeKey) -> (NodeId, NodeId) {
+        match self {
+            EdgeMode::Undirected => UniquePair::decode_key(key),
+            EdgeMode::Directed => UniquePair::decode_ordered_key(key),
+        }
+    }
+}
+
+impl Display for EdgeMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EdgeMode::Undirected => write!(f, "undirected"),
+            EdgeMode::Directed => write!(f, "directed"),
+        }
+    }
+}
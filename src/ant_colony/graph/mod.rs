@@ -1,16 +1,21 @@
 mod _tests;
 mod adjacency_list_entry;
 mod construct;
+mod edge_index;
+mod edge_mode;
 mod node;
 mod route;
 mod route_batch;
 mod route_collection;
 
-use std::collections::BTreeMap;
+use itertools::Itertools;
 use std::fmt::Display;
+use std::sync::Arc;
 
 pub use _tests::{mock_graph_tuple, mock_graph_vector};
-pub use adjacency_list_entry::{AdjacencyListEntry, EdgeKey};
+pub use adjacency_list_entry::{AdjacencyListEntry, EdgeKey, EdgeRef};
+pub use edge_index::EdgeIndex;
+pub use edge_mode::EdgeMode;
 pub use node::{Node, NodeId};
 pub use route::Route;
 pub use route_batch::{RouteBatch, RouteBatchWithHoles};
@@ -18,50 +23,78 @@ pub use route_collection::RouteCollection;
 
 use crate::common::utils::compare_float;
 
+/// Graph stored in compressed sparse row (CSR) format.
+///
+/// Adjacency lists of all nodes are kept in a single contiguous array,
+/// `offsets[row]..offsets[row + 1]` being the range of edges leaving the node in given row.
+/// Nodes are sorted by their ids, so the iteration order is stable.
+///
+/// In directed mode, edges A->B and B->A have separate keys, hence separate trails.
 #[derive(Debug, PartialEq, Clone)]
 pub struct Graph {
-    /// using BTreeMap instead of HashMap for stable iteration order
-    /// TODO: compare performance against indexmap (https://github.com/bluss/indexmap)
-    nodes: BTreeMap<NodeId, Node>,
+    mode: EdgeMode,
+    node_ids: Vec<NodeId>,
+    offsets: Vec<usize>,
+    edges: Vec<AdjacencyListEntry>,
+    /// assigned once the graph is built, shared with pheromone
+    edge_index: Arc<EdgeIndex>,
+    /// compact edge index -> position of the edge in `edges`
+    edge_positions: Vec<usize>,
 }
 
 impl Graph {
     #[cfg_attr(feature = "profiler", flame)]
-    pub fn get_adjacent_edges(&self, node_id: &NodeId) -> Vec<AdjacencyListEntry> {
-        self.nodes
-            .get(node_id)
-            .map_or_else(Vec::new, |n| n.adjacency_list.to_owned())
+    pub fn get_adjacent_edges(&self, node_id: &NodeId) -> &[AdjacencyListEntry] {
+        self.get_row(*node_id).map_or(&[], |row| {
+            &self.edges[self.offsets[row]..self.offsets[row + 1]]
+        })
     }
 
-    pub fn get_all_edges(&self) -> Vec<AdjacencyListEntry> {
-        self.edges_iter().collect()
+    pub fn get_mode(&self) -> EdgeMode {
+        self.mode
     }
 
-    pub fn get_edge(&self, key: EdgeKey) -> Option<AdjacencyListEntry> {
-        self.edges_iter()
-            .find(|edge| edge.key == key)
-            .map(|edge| edge.to_owned())
+    pub fn is_directed(&self) -> bool {
+        self.mode == EdgeMode::Directed
     }
 
+    pub fn get_edge_index(&self) -> Arc<EdgeIndex> {
+        Arc::clone(&self.edge_index)
+    }
+
+    /// Returns both directions of each undirected edge
+    pub fn get_all_edges(&self) -> &[AdjacencyListEntry] {
+        &self.edges
+    }
+
+    /// Returns single direction of the edge, the one which occurs first
+    pub fn get_edge<E: EdgeRef>(&self, edge: E) -> Option<AdjacencyListEntry> {
+        self.edge_index
+            .resolve(edge)
+            .map(|idx| self.edges[self.edge_positions[idx]])
+    }
+
+    /// Returns both directions of each undirected edge, in order of the graph
     pub fn get_edges(&self, keys: &[EdgeKey]) -> Vec<AdjacencyListEntry> {
-        self.edges_iter()
-            .filter(|edge| keys.contains(&edge.key))
+        keys.iter()
+            .filter_map(|key| self.edge_index.get_idx(*key))
+            .flat_map(|idx| self.get_positions_of_directions(self.edge_positions[idx]))
+            .sorted()
+            .dedup()
+            .map(|position| self.edges[position])
             .collect()
     }
 
-    pub fn get_node_ids(&self) -> Vec<NodeId> {
-        self.nodes.keys().map(|k| k.clone()).collect()
+    pub fn get_node_ids(&self) -> &[NodeId] {
+        &self.node_ids
     }
 
     pub fn get_amount_of_nodes(&self) -> usize {
-        self.nodes.len()
+        self.node_ids.len()
     }
 
     pub fn get_amount_of_edges(&self) -> usize {
-        self.nodes
-            .values()
-            .map(|node| node.adjacency_list.len())
-            .sum()
+        self.edges.len()
     }
 
     pub fn get_max_cycle_edges(&self) -> usize {
@@ -88,47 +121,40 @@ impl Graph {
     }
 
     pub fn invert_distances(self) -> Self {
-        Self {
-            nodes: self
-                .nodes
-                .into_iter()
-                .map(|(id, node)| {
-                    let adjacency_list = node
-                        .adjacency_list
-                        .into_iter()
-                        .map(|edge| {
-                            AdjacencyListEntry::new(edge.from, edge.to, 1.0 / edge.distance)
-                        })
-                        .collect();
-
-                    (
-                        id,
-                        Node {
-                            id: node.id,
-                            adjacency_list,
-                        },
-                    )
-                })
-                .collect(),
-        }
+        self.map_distances(|edge| 1.0 / edge.distance)
+    }
+
+    pub fn map_distances<F: Fn(&AdjacencyListEntry) -> f32>(self, mapper: F) -> Self {
+        // keys and structure stay the same, so the CSR arrays can be reused
+        let edges = self
+            .edges
+            .into_iter()
+            .map(|edge| {
+                let distance = mapper(&edge);
+
+                edge.with_distance(distance)
+            })
+            .collect();
+
+        Graph { edges, ..self }
     }
 
     pub fn estimate_hamiltonian_cycle(&self) -> Option<f32> {
-        let cycle_length = self.nodes.len();
-        let starting_node = self.nodes.values().next()?;
-        let starting_edge = starting_node.adjacency_list.first()?;
+        let cycle_length = self.node_ids.len();
+        let starting_node_id = *self.node_ids.first()?;
+        let starting_edge = self.get_adjacent_edges(&starting_node_id).first()?;
 
         let route = (0..cycle_length - 1).fold(vec![starting_edge], |mut taken_edges, _| {
             let maybe_next_edge = taken_edges
                 .last()
-                .and_then(|edge| self.nodes.get(&edge.to))
-                .and_then(|node| {
-                    node.adjacency_list
+                .map(|edge| self.get_adjacent_edges(&edge.to))
+                .and_then(|adjacent_edges| {
+                    adjacent_edges
                         .iter()
                         .filter(|edge| {
                             taken_edges.iter().all(|taken_edge| {
                                 let was_not_taken = taken_edge.from != edge.to;
-                                let is_closing_cycle = edge.to == starting_node.id
+                                let is_closing_cycle = edge.to == starting_node_id
                                     && taken_edges.len() == cycle_length - 1;
 
                                 was_not_taken || is_closing_cycle
@@ -154,16 +180,37 @@ impl Graph {
         }
     }
 
-    fn edges_iter(&self) -> impl Iterator<Item = AdjacencyListEntry> + '_ {
-        self.nodes
-            .values()
-            .flat_map(|node| node.adjacency_list.to_owned())
+    fn edges_lengths_iter(&self) -> impl Iterator<Item = f32> + '_ {
+        self.edges.iter().map(|edge| edge.distance)
+    }
+
+    /// Node ids are usually consecutive, so row can be read directly,
+    /// otherwise it is looked up in the sorted ids
+    /// Positions of the edge and, in undirected mode, of its reverse direction,
+    /// which is found among edges leaving the target node
+    fn get_positions_of_directions(&self, position: usize) -> Vec<usize> {
+        let edge = &self.edges[position];
+        let reverse_position = self
+            .get_row(edge.to)
+            .filter(|_| !self.is_directed())
+            .and_then(|row| {
+                (self.offsets[row]..self.offsets[row + 1]).find(|reverse_position| {
+                    let reverse = &self.edges[*reverse_position];
+
+                    reverse.key == edge.key && reverse.to == edge.from
+                })
+            });
+
+        std::iter::once(position).chain(reverse_position).collect()
     }
 
-    fn edges_lengths_iter(&self) -> impl Iterator<Item = f32> + '_ {
-        self.nodes
-            .values()
-            .flat_map(|node| node.adjacency_list.iter().map(|edge| edge.distance))
+    fn get_row(&self, node_id: NodeId) -> Option<usize> {
+        let direct_row = node_id as usize;
+
+        match self.node_ids.get(direct_row) {
+            Option::Some(id) if *id == node_id => Option::Some(direct_row),
+            _ => self.node_ids.binary_search(&node_id).ok(),
+        }
     }
 }
 
@@ -174,10 +221,10 @@ impl Display for Graph {
 
         write!(
             f,
-            "Graph\n\t\
+            "Graph ({})\n\t\
             nodes: {:>10}\n\t\
             edges: {:>10}",
-            node_count, edge_count
+            self.mode, node_count, edge_count
         )
     }
 }
@@ -5,7 +5,7 @@ use super::AdjacencyListEntry;
 ///
 /// It is mostly used when edges from different routes are
 /// processed at the same time
-pub type RouteBatch = Vec<AdjacencyListEntry>;
+pub type RouteBatch = [AdjacencyListEntry];
 
 /// Similar to above in function, but with the difference that some
 /// routes may have finished before other ones.
@@ -5,28 +5,88 @@ use super::{Route, RouteBatchWithHoles};
 use crate::common::utils::compare_float;
 
 /// Represents multiple unrelated Routes
-pub struct RouteCollection(Vec<Route>);
+///
+/// Colonies assign costs of the solved problem to the routes after each cycle,
+/// infinite cost marks an infeasible (e.g. incomplete) route.
+/// Without costs, routes cost their distance adjusted to the target number of steps.
+pub struct RouteCollection {
+    routes: Vec<Route>,
+    costs: Option<Vec<f32>>,
+}
 
 impl RouteCollection {
     pub fn new(ant_count: usize, route_length: usize) -> Self {
-        RouteCollection((0..ant_count).map(|_| Route::new(route_length)).collect())
+        Self::from_routes((0..ant_count).map(|_| Route::new(route_length)).collect())
+    }
+
+    pub fn from_routes(routes: Vec<Route>) -> Self {
+        RouteCollection {
+            routes,
+            costs: Option::None,
+        }
+    }
+
+    /// Expects a cost for every route, in the same order
+    pub fn with_costs(self, costs: Vec<f32>) -> Self {
+        assert_eq!(costs.len(), self.routes.len(), "Every route needs its cost");
+
+        RouteCollection {
+            costs: Option::Some(costs),
+            ..self
+        }
+    }
+
+    /// Assigned costs of the routes, or their distances adjusted to the target number of steps
+    pub fn get_costs(&self, target_num_of_steps: usize) -> Vec<f32> {
+        match &self.costs {
+            Option::Some(costs) => costs.clone(),
+            Option::None => self
+                .routes
+                .iter()
+                .map(|route| route.get_adjusted_distance(target_num_of_steps))
+                .collect(),
+        }
     }
 
+    /// Routes of finite cost along with the cost
+    pub fn get_feasible_routes(&self, target_num_of_steps: usize) -> Vec<(&Route, f32)> {
+        self.routes
+            .iter()
+            .zip(self.get_costs(target_num_of_steps))
+            .filter(|(_, cost)| cost.is_finite())
+            .collect()
+    }
+
+    /// Returns up to n feasible routes of the lowest cost, ordered from the cheapest one
+    pub fn get_n_best_routes(&self, n: usize, target_num_of_steps: usize) -> Vec<(Route, f32)> {
+        self.get_feasible_routes(target_num_of_steps)
+            .into_iter()
+            .sorted_by(|(_, cost_a), (_, cost_b)| compare_float(cost_a, cost_b))
+            .take(n)
+            .map(|(route, cost)| (route.clone(), cost))
+            .collect()
+    }
+
+    pub fn get_best_route(&self, target_num_of_steps: usize) -> Option<(Route, f32)> {
+        self.get_n_best_routes(1, target_num_of_steps).pop()
+    }
+
+    /// Routes change, so assigned costs are dropped
     pub fn add_steps(self, taken_edges: &RouteBatchWithHoles) -> Self {
-        let values = taken_edges
+        let routes = taken_edges
             .iter()
-            .zip_eq(self.0)
+            .zip_eq(self.routes)
             .map(|(maybe_edge, route)| match maybe_edge {
                 Option::Some(edge) => route.add_step(edge.to_owned()),
                 Option::None => route,
             })
             .collect();
 
-        RouteCollection(values)
+        Self::from_routes(routes)
     }
 
     pub fn get_shortest_route(&self) -> Option<Route> {
-        self.0
+        self.routes
             .iter()
             .min_by(|a, b| {
                 let dist_a = a.get_distance();
@@ -37,27 +97,35 @@ impl RouteCollection {
             .map(|route| route.clone())
     }
 
+    pub fn get_max_route_length(&self) -> usize {
+        self.routes
+            .iter()
+            .map(|route| route.get_length())
+            .max()
+            .unwrap_or_default()
+    }
+
     pub fn get_shortest_route_distance(&self) -> Option<f32> {
         self.get_shortest_route().map(|route| route.get_distance())
     }
 
     pub fn get_routes(&self) -> &[Route] {
-        &self.0
+        &self.routes
     }
 
     pub fn get_average_route_distance(&self) -> f32 {
         let sum = self
-            .0
+            .routes
             .iter()
             .fold(0.0, |acc, route| acc + route.get_distance());
 
-        sum / self.0.len() as f32
+        sum / self.routes.len() as f32
     }
 
     /// calculates how many routes are shorter than other ones
     /// this is be caused by the fact that `add_steps` accepts RouteBatchWithHoles
     pub fn get_ratio_of_incomplete_routes(&self) -> f32 {
-        let lengths = self.0.iter().map(|route| route.get_length());
+        let lengths = self.routes.iter().map(|route| route.get_length());
         let maybe_max_length = lengths.clone().max();
 
         match maybe_max_length {
@@ -73,6 +141,6 @@ impl RouteCollection {
 
 impl Default for RouteCollection {
     fn default() -> Self {
-        RouteCollection(Vec::new())
+        Self::from_routes(Vec::new())
     }
 }
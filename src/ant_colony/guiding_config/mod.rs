@@ -8,6 +8,7 @@ use crate::ant_colony::pheromone_updater::UpdaterStringConfig;
 ///
 /// Used for _smart_ estimation of hyperparameters
 /// based on some heuristics
+#[derive(Clone)]
 pub struct GuidingConfig {
     pub ant_count: usize,
     pub num_of_steps_per_cycle: usize,
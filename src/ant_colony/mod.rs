@@ -6,4 +6,5 @@ pub mod guiding_config;
 pub mod pheromone;
 pub mod pheromone_reader;
 pub mod pheromone_updater;
+pub mod problem;
 pub mod runner;
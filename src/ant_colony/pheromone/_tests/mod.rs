@@ -2,7 +2,8 @@
 mod pheromone_tests {
     use std::collections::HashMap;
 
-    use crate::ant_colony::pheromone::Pheromone;
+    use crate::ant_colony::graph::{mock_graph_tuple, Graph};
+    use crate::ant_colony::pheromone::{Pheromone, PheromoneRead, WeightedPheromone};
 
     #[test]
     fn it_allows_for_initializing_pheromone_trace() {
@@ -101,4 +102,88 @@ mod pheromone_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn it_allows_for_updating_trail_of_specific_edge() {
+        let pheromone = Pheromone::new()
+            .initialize_pheromone_for_edge(0, 0.5)
+            .initialize_pheromone_for_edge(1, 0.2)
+            .update_pheromone_value(1, |value| value * 2.0)
+            .update_pheromone_value(7, |value| value * 2.0);
+
+        assert_eq!(pheromone.get_pheromone_for_edge(0), 0.5);
+        assert_eq!(pheromone.get_pheromone_for_edge(1), 0.4);
+        assert_eq!(pheromone.get_values().len(), 2);
+    }
+
+    #[test]
+    fn it_shares_edge_index_with_graph() {
+        let graph = Graph::from_neighbour_tuples(mock_graph_tuple());
+        let edges = graph.get_all_edges();
+
+        let pheromone = edges.iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| pheromone.initialize_pheromone_for_edge(edge.key, 1.0),
+        );
+
+        assert_eq!(pheromone.get_edge_index(), graph.get_edge_index());
+        assert_eq!(pheromone.get_values().len(), mock_graph_tuple().len());
+        assert!(edges
+            .iter()
+            .all(|edge| pheromone.get_pheromone_for_edge(edge.key) == 1.0));
+    }
+
+    #[test]
+    fn it_compares_trails_regardless_of_index_order() {
+        let pheromone_a = Pheromone::new()
+            .initialize_pheromone_for_edge(0, 0.5)
+            .initialize_pheromone_for_edge(1, 0.2);
+        let pheromone_b = Pheromone::new()
+            .initialize_pheromone_for_edge(1, 0.2)
+            .initialize_pheromone_for_edge(0, 0.5);
+
+        assert_eq!(pheromone_a, pheromone_b);
+    }
+
+    #[test]
+    fn it_aggregates_trails_of_objectives_with_weights() {
+        let pheromone_a = Pheromone::new()
+            .initialize_pheromone_for_edge(0, 4.0)
+            .initialize_pheromone_for_edge(1, 1.0);
+        let pheromone_b = Pheromone::new()
+            .initialize_pheromone_for_edge(0, 1.0)
+            .initialize_pheromone_for_edge(1, 9.0);
+        let pheromones = vec![pheromone_a, pheromone_b];
+
+        let weighted = WeightedPheromone::new(&pheromones, &[0.5, 0.5]);
+        assert_delta!(weighted.get_pheromone_for_edge(0), 2.0);
+        assert_delta!(weighted.get_pheromone_for_edge(1), 3.0);
+
+        let first_only = WeightedPheromone::new(&pheromones, &[1.0, 0.0]).to_pheromone();
+        assert_delta!(first_only.get_pheromone_for_edge(0), 1.0);
+        assert_delta!(first_only.get_pheromone_for_edge(1), 0.25);
+    }
+
+    #[test]
+    fn it_looks_up_edges_by_their_compact_index_if_it_matches() {
+        let graph = Graph::from_neighbour_tuples(mock_graph_tuple());
+        let edge = graph.get_all_edges()[1];
+
+        let graph_pheromone =
+            Pheromone::with_edge_index(graph.get_edge_index()).increase_pheromone_value(&edge, 2.0);
+        // indexed in a different order, so the index carried by the edge is stale
+        let other_pheromone = graph
+            .get_all_edges()
+            .iter()
+            .rev()
+            .fold(Pheromone::new(), |pheromone, edge| {
+                pheromone.initialize_pheromone_for_edge(edge.key, 0.0)
+            })
+            .increase_pheromone_value(&edge, 2.0);
+
+        assert!(edge.idx.is_some());
+        assert_eq!(graph_pheromone.get_pheromone_for_edge(&edge), 2.0);
+        assert_eq!(other_pheromone.get_pheromone_for_edge(&edge), 2.0);
+        assert_eq!(other_pheromone.get_pheromone_for_edge(edge.key), 2.0);
+    }
 }
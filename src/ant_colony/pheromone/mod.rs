@@ -1,37 +1,73 @@
 mod _tests;
+mod weighted_pheromone;
 
+use itertools::Itertools;
 use std::collections::HashMap;
 use std::fmt::Display;
+use std::sync::Arc;
 
-use crate::ant_colony::graph::EdgeKey;
+use crate::ant_colony::graph::{EdgeIndex, EdgeKey, EdgeRef};
 use crate::common::utils::compare_float;
 
+pub use weighted_pheromone::WeightedPheromone;
+
 pub type PheromoneLevel = f32;
 
-#[derive(Debug, PartialEq)]
+/// Read access to pheromone trails, common for pheromone
+/// and trails aggregated from multiple pheromones
+pub trait PheromoneRead: Sync {
+    fn get_pheromone_for_edge<E: EdgeRef>(&self, edge: E) -> PheromoneLevel;
+}
+
+/// Dense pheromone storage, trails are kept in a vector
+/// indexed by the compact edge index of the graph.
+///
+/// Updates are made in place, so none of the per-step operations allocate.
+#[derive(Debug, Clone)]
 pub struct Pheromone {
-    values: HashMap<EdgeKey, PheromoneLevel>,
+    index: Arc<EdgeIndex>,
+    values: Vec<PheromoneLevel>,
 }
 
 impl Pheromone {
     pub fn new() -> Self {
-        Pheromone {
-            values: HashMap::new(),
-        }
+        Pheromone::with_edge_index(Arc::new(EdgeIndex::new()))
+    }
+
+    /// Creates empty trails (set to 0.0) for each edge of the index
+    pub fn with_edge_index(index: Arc<EdgeIndex>) -> Self {
+        let values = vec![0.0; index.len()];
+
+        Pheromone { index, values }
     }
 
     pub fn from_values(values: HashMap<EdgeKey, PheromoneLevel>) -> Self {
-        Pheromone { values }
+        values
+            .into_iter()
+            .sorted_by_key(|(key, _)| *key)
+            .fold(Pheromone::new(), |pheromone, (key, value)| {
+                pheromone.initialize_pheromone_for_edge(key, value)
+            })
     }
 
+    /// Edges missing from the index are appended to it,
+    /// which clones the index if it is shared with the graph
     pub fn initialize_pheromone_for_edge(mut self, edge_key: EdgeKey, value: f32) -> Self {
-        self.values.insert(edge_key, value);
+        match self.index.get_idx(edge_key) {
+            Option::Some(idx) => self.values[idx] = value,
+            Option::None => {
+                Arc::make_mut(&mut self.index).insert(edge_key);
+                self.values.push(value);
+            }
+        }
 
         self
     }
 
-    pub fn get_pheromone_for_edge(&self, edge_key: EdgeKey) -> PheromoneLevel {
-        let value = self.values.get(&edge_key);
+    /// Edges of the graph the pheromone was built for are looked up by their compact index,
+    /// keys are hashed only as a fallback
+    pub fn get_pheromone_for_edge<E: EdgeRef>(&self, edge: E) -> PheromoneLevel {
+        let value = self.index.resolve(edge).map(|idx| self.values[idx]);
 
         debug_assert_ne!(
             value,
@@ -39,76 +75,128 @@ impl Pheromone {
             "Failed to find pheromone value for edge"
         );
 
-        value.unwrap_or(&0.0).clone()
+        value.unwrap_or(0.0)
+    }
+
+    pub fn increase_pheromone_value<E: EdgeRef>(mut self, edge: E, increment: f32) -> Self {
+        if let Some(idx) = self.index.resolve(edge) {
+            self.values[idx] += increment;
+        }
+
+        self
     }
 
-    pub fn increase_pheromone_value(mut self, edge_key: EdgeKey, increment: f32) -> Self {
-        if let Some(val) = self.values.get_mut(&edge_key) {
-            *val += increment;
+    /// Replaces trail of given edge with the result of `updater`
+    pub fn update_pheromone_value<E, F>(mut self, edge: E, updater: F) -> Self
+    where
+        E: EdgeRef,
+        F: Fn(PheromoneLevel) -> PheromoneLevel,
+    {
+        if let Some(idx) = self.index.resolve(edge) {
+            self.values[idx] = updater(self.values[idx]);
         }
 
         self
     }
 
     pub fn scale_all_pheromone_values(mut self, scaler: f32) -> Self {
-        for val in self.values.values_mut() {
+        for val in self.values.iter_mut() {
             *val *= scaler;
         }
 
         self
     }
 
-    pub fn get_values(&self) -> &HashMap<EdgeKey, PheromoneLevel> {
-        &self.values
+    pub fn get_edge_index(&self) -> Arc<EdgeIndex> {
+        Arc::clone(&self.index)
+    }
+
+    /// Iterates over (edge key, trail) pairs in order of the edge index
+    pub fn iter(&self) -> impl Iterator<Item = (EdgeKey, PheromoneLevel)> + '_ {
+        self.index
+            .get_keys()
+            .iter()
+            .cloned()
+            .zip(self.values.iter().cloned())
+    }
+
+    /// Collects trails into a map, allocates - meant for inspecting the pheromone
+    pub fn get_values(&self) -> HashMap<EdgeKey, PheromoneLevel> {
+        self.iter().collect()
+    }
+
+    pub fn get_max_value(&self) -> PheromoneLevel {
+        self.values
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .unwrap_or(1.0)
     }
 
     /// Each pheromone trail is scaled to [0.0, 1.0)
     /// where 1.0 is maximum value
     pub fn get_values_normalized(&self) -> HashMap<EdgeKey, PheromoneLevel> {
-        let max: f32 = *self.values.values().max_by(compare_float).unwrap_or(&1.0);
+        let max = self.get_max_value();
 
-        self.values
-            .iter()
-            .map(|(key, val)| (key.clone(), val / max))
-            .collect()
+        self.iter().map(|(key, val)| (key, val / max)).collect()
     }
 
     /// Each pheromone trail is scaled in such a way, that sum of all is 1.0
     pub fn get_values_normalized_to_sum(&self) -> HashMap<EdgeKey, PheromoneLevel> {
-        let sum: f32 = self.values.values().sum();
+        let sum: f32 = self.values.iter().sum();
 
-        self.values
-            .iter()
-            .map(|(key, val)| (key.clone(), val / sum))
-            .collect()
+        self.iter().map(|(key, val)| (key, val / sum)).collect()
     }
 
     pub fn normalize(&self) -> Self {
-        Pheromone {
-            values: self.get_values_normalized(),
-        }
+        let max = self.get_max_value();
+
+        self.clone().scale_all_pheromone_values(1.0 / max)
     }
 
     pub fn normalize_sum(&self) -> Self {
-        Pheromone {
-            values: self.get_values_normalized_to_sum(),
-        }
+        let sum: f32 = self.values.iter().sum();
+
+        self.clone().scale_all_pheromone_values(1.0 / sum)
     }
 
     pub fn calc_variance(&self) -> f32 {
-        let values_normalized = self.get_values_normalized();
-        let count = values_normalized.len() as f32;
-        let avg = values_normalized.values().cloned().sum::<f32>() / count;
+        let max = self.get_max_value();
+        let count = self.values.len() as f32;
+        let avg = self.values.iter().map(|val| val / max).sum::<f32>() / count;
 
-        values_normalized
-            .values()
-            .map(|val| (avg - *val).powi(2))
+        self.values
+            .iter()
+            .map(|val| (avg - val / max).powi(2))
             .sum::<f32>()
             / count
     }
 
+    /// Moves trails towards the other pheromone
+    ///         \tau = (1 - w) \tau + w \tau_{other}
+    /// edges missing from the other pheromone are only scaled
+    pub fn blend(mut self, other: &Pheromone, weight: f32) -> Self {
+        let decay = 1.0 - weight;
+
+        if Arc::ptr_eq(&self.index, &other.index) {
+            for (val, other_val) in self.values.iter_mut().zip(other.values.iter()) {
+                *val = decay * *val + weight * other_val;
+            }
+
+            return self;
+        }
+
+        let scaled_pheromone = self.scale_all_pheromone_values(decay);
+
+        other
+            .iter()
+            .fold(scaled_pheromone, |pheromone, (key, other_val)| {
+                pheromone.increase_pheromone_value(key, weight * other_val)
+            })
+    }
+
     pub fn clamp(mut self, min: f32, max: f32) -> Pheromone {
-        for val in self.values.values_mut() {
+        for val in self.values.iter_mut() {
             *val = val.min(max).max(min);
         }
 
@@ -116,21 +204,35 @@ impl Pheromone {
     }
 }
 
+impl PartialEq for Pheromone {
+    fn eq(&self, other: &Self) -> bool {
+        // pheromone built for the same graph shares the index,
+        // otherwise the same trails could have been indexed in different order
+        if self.index == other.index {
+            self.values == other.values
+        } else {
+            self.get_values() == other.get_values()
+        }
+    }
+}
+
+impl PheromoneRead for Pheromone {
+    fn get_pheromone_for_edge<E: EdgeRef>(&self, edge: E) -> PheromoneLevel {
+        Pheromone::get_pheromone_for_edge(self, edge)
+    }
+}
+
 impl Display for Pheromone {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let non_zero_edges = self
-            .values
-            .iter()
-            .filter(|(_, value)| **value >= 1e-3)
-            .collect::<Vec<_>>();
+        let non_zero_edges = self.values.iter().filter(|value| **value >= 1e-3).count();
 
-        let non_empty_edge_ratio = non_zero_edges.len() as f32 / self.values.len() as f32;
+        let non_empty_edge_ratio = non_zero_edges as f32 / self.values.len() as f32;
 
         write!(
             f,
             "Pheromone\n\t\
             edges with trail >= 0.001: {} / {} ({:>4.2}%)",
-            non_zero_edges.len(),
+            non_zero_edges,
             self.values.len(),
             100.0 * non_empty_edge_ratio,
         )
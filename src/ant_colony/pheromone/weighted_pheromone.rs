@@ -0,0 +1,65 @@
+use crate::ant_colony::graph::EdgeRef;
+
+use super::{Pheromone, PheromoneLevel, PheromoneRead};
+
+/// Read-only view over trails kept separately for each objective,
+/// aggregated with weights (`Doerner2004PACO`)
+///         \tau = \prod_k \tau_k^{w_k}
+///
+/// Scaling trails of any objective does not change the proportions seen by an ant,
+/// so objectives with different distance scales can be mixed.
+pub struct WeightedPheromone<'a> {
+    pheromones: &'a [Pheromone],
+    weights: &'a [f32],
+}
+
+impl<'a> WeightedPheromone<'a> {
+    pub fn new(pheromones: &'a [Pheromone], weights: &'a [f32]) -> Self {
+        debug_assert_eq!(
+            pheromones.len(),
+            weights.len(),
+            "Each objective needs its weight"
+        );
+
+        WeightedPheromone {
+            pheromones,
+            weights,
+        }
+    }
+
+    /// Materializes aggregated trails, each objective is normalized to its maximum first,
+    /// so the result does not depend on scales of objectives
+    pub fn to_pheromone(&self) -> Pheromone {
+        let normalized = self
+            .pheromones
+            .iter()
+            .map(Pheromone::normalize)
+            .collect::<Vec<_>>();
+        let view = WeightedPheromone::new(&normalized, self.weights);
+
+        match normalized.first() {
+            Option::Some(first) => {
+                let values = first
+                    .iter()
+                    .map(|(key, _)| (key, view.get_pheromone_for_edge(key)))
+                    .collect::<Vec<_>>();
+
+                values.into_iter().fold(
+                    Pheromone::with_edge_index(first.get_edge_index()),
+                    |pheromone, (key, value)| pheromone.initialize_pheromone_for_edge(key, value),
+                )
+            }
+            Option::None => Pheromone::new(),
+        }
+    }
+}
+
+impl<'a> PheromoneRead for WeightedPheromone<'a> {
+    fn get_pheromone_for_edge<E: EdgeRef>(&self, edge: E) -> PheromoneLevel {
+        self.pheromones
+            .iter()
+            .zip(self.weights.iter())
+            .map(|(pheromone, weight)| pheromone.get_pheromone_for_edge(edge).powf(*weight))
+            .product()
+    }
+}
@@ -2,6 +2,7 @@
 mod pheromone_reader_tests {
     use itertools::Itertools;
 
+    use crate::ant_colony::graph::{mock_graph_tuple, AdjacencyListEntry, Graph};
     use crate::ant_colony::pheromone::Pheromone;
 
     use super::super::PheromoneReader;
@@ -63,4 +64,62 @@ mod pheromone_reader_tests {
 
         assert_eq!(num, 3);
     }
+
+    #[test]
+    fn it_calculates_branching_factor() {
+        let graph = Graph::from_neighbour_tuples(mock_graph_tuple());
+        let get_uniform_pheromone = || {
+            graph
+                .get_all_edges()
+                .iter()
+                .fold(Pheromone::new(), |pheromone, edge| {
+                    pheromone.initialize_pheromone_for_edge(edge.key, 1.0)
+                })
+        };
+
+        let edge_0_1 = graph
+            .get_adjacent_edges(&0)
+            .iter()
+            .find(|edge| edge.to == 1)
+            .cloned()
+            .unwrap();
+        let uniform_pheromone = get_uniform_pheromone();
+        let converged_pheromone =
+            get_uniform_pheromone().increase_pheromone_value(edge_0_1.key, 1.0);
+
+        let uniform_factor =
+            PheromoneReader::calc_branching_factor(&uniform_pheromone, &graph, 0.05);
+        let converged_factor =
+            PheromoneReader::calc_branching_factor(&converged_pheromone, &graph, 0.05);
+
+        // every node has 3 neighbours, only nodes 0 and 1 prefer a single edge
+        assert_delta!(uniform_factor, 3.0);
+        assert_delta!(converged_factor, (1.0 + 1.0 + 3.0 + 3.0) / 4.0);
+    }
+
+    #[test]
+    fn it_returns_both_directions_of_edges_from_graph() {
+        let graph = Graph::from_neighbour_tuples(mock_graph_tuple());
+        let pheromone = graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| {
+                let value = iif!(edge.key == AdjacencyListEntry::get_key(0, 1), 1.0, 0.1);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, value)
+            },
+        );
+        let expected = vec![
+            AdjacencyListEntry::new(0, 1, 3.0),
+            AdjacencyListEntry::new(1, 0, 3.0),
+        ];
+
+        assert_eq!(
+            PheromoneReader::get_edges_with_pheromone_above(&pheromone, &graph, 0.5),
+            expected
+        );
+        assert_eq!(
+            PheromoneReader::get_top_n_edges(&pheromone, &graph, 1),
+            expected
+        );
+    }
 }
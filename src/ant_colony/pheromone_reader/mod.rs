@@ -9,10 +9,11 @@ pub struct PheromoneReader;
 
 impl PheromoneReader {
     pub fn count_edges_with_pheromone_above(pheromone: &Pheromone, level: f32) -> usize {
+        let max = pheromone.get_max_value();
+
         pheromone
-            .get_values_normalized()
             .iter()
-            .filter(|(_, value)| **value > level)
+            .filter(|(_, value)| *value / max > level)
             .count()
     }
 
@@ -36,6 +37,36 @@ impl PheromoneReader {
         graph.get_edges(&keys)
     }
 
+    /// Lambda-branching factor (`Gambardella1995AntQ`), averaged over all nodes.
+    ///
+    /// For each node counts adjacent edges with trail not lower than
+    ///         \tau_{min} + \lambda (\tau_{max} - \tau_{min})
+    /// where min and max are taken over the node's adjacent edges.
+    /// Values close to 1 mean ants are almost always choosing the same edges.
+    pub fn calc_branching_factor(pheromone: &Pheromone, graph: &Graph, lambda: f32) -> f32 {
+        let node_ids = graph.get_node_ids();
+
+        if node_ids.is_empty() {
+            return 0.0;
+        }
+
+        let total_branches = node_ids.iter().fold(0, |acc, node_id| {
+            let levels = graph
+                .get_adjacent_edges(node_id)
+                .iter()
+                .map(|edge| pheromone.get_pheromone_for_edge(edge))
+                .collect::<Vec<_>>();
+
+            let min = levels.iter().cloned().fold(f32::INFINITY, f32::min);
+            let max = levels.iter().cloned().fold(f32::NEG_INFINITY, f32::max);
+            let cutoff = min + lambda * (max - min);
+
+            acc + levels.iter().filter(|level| **level >= cutoff).count()
+        });
+
+        total_branches as f32 / node_ids.len() as f32
+    }
+
     fn get_edge_keys_with_pheromone_above(pheromone: &Pheromone, level: f32) -> Vec<EdgeKey> {
         pheromone
             .get_values_normalized()
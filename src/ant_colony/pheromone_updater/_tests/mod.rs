@@ -2,13 +2,18 @@
 mod pheromone_updater_tests {
     use std::collections::HashMap;
 
-    use crate::ant_colony::graph::{AdjacencyListEntry, EdgeKey, RouteCollection};
+    use crate::ant_colony::graph::{AdjacencyListEntry, EdgeKey, Graph, RouteCollection};
+    use crate::ant_colony::guiding_config::GuidingConfig;
     use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
     use crate::ant_colony::pheromone_updater::{
         AveragePheromoneUpdater, ColonyPheromoneUpdater, ConstantPheromoneUpdater,
-        CyclicalPheromoneUpdater, PheromoneUpdater,
+        CyclicalPheromoneUpdater, ElitistPheromoneUpdater, HyperCubePheromoneUpdater,
+        LocalColonyPheromoneUpdater, MaxMinPheromoneUpdater, PheromoneUpdater,
+        RankPheromoneUpdater, UpdaterStringConfig,
     };
 
+    use super::super::maxmin_pheromone_updater::DepositedRoute;
+
     fn get_edges() -> Vec<AdjacencyListEntry> {
         vec![
             AdjacencyListEntry {
@@ -17,6 +22,7 @@ mod pheromone_updater_tests {
                 to: 0,
                 distance: 1.0,
                 visibility: 1.0,
+                idx: Option::None,
             },
             AdjacencyListEntry {
                 key: 1,
@@ -24,6 +30,7 @@ mod pheromone_updater_tests {
                 to: 0,
                 distance: 2.0,
                 visibility: 0.5,
+                idx: Option::None,
             },
             AdjacencyListEntry {
                 key: 2,
@@ -31,6 +38,7 @@ mod pheromone_updater_tests {
                 to: 0,
                 distance: 3.0,
                 visibility: 0.333,
+                idx: Option::None,
             },
         ]
     }
@@ -90,7 +98,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -107,7 +115,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -121,7 +129,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -135,7 +143,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -152,7 +160,7 @@ mod pheromone_updater_tests {
             2 => 0.8 + 0.2 / 3.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -166,7 +174,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -180,7 +188,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -194,7 +202,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -211,7 +219,7 @@ mod pheromone_updater_tests {
             2 => 0.8 + 0.2 / 5.0 + 0.2 / 4.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -225,7 +233,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -242,7 +250,7 @@ mod pheromone_updater_tests {
             2 => 1.0
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
     }
 
     #[test]
@@ -260,6 +268,404 @@ mod pheromone_updater_tests {
             2 => 0.8
         );
 
-        assert_eq!(*pheromone.get_values(), expected);
+        assert_eq!(pheromone.get_values(), expected);
+    }
+
+    #[test]
+    fn local_system_updater_moves_trails_towards_initial_value_after_ant_move() {
+        let updater = LocalColonyPheromoneUpdater::new(1.0, 0.5, 0.2, 2);
+        let edges = get_edges();
+        let init_pheromone = Pheromone::new()
+            .initialize_pheromone_for_edge(0, 3.0)
+            .initialize_pheromone_for_edge(1, 3.0)
+            .initialize_pheromone_for_edge(2, 3.0);
+
+        let pheromone = [edges[0], edges[0], edges[2]]
+            .iter()
+            .fold(init_pheromone, |pheromone, edge| {
+                updater.on_after_ant_move(pheromone, edge)
+            });
+
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            // (3 * 0.5 + 0.5 * 1) * 0.5 + 0.5 * 1
+            0 => 1.5,
+            1 => 3.0,
+            // 3 * 0.5 + 0.5 * 1
+            2 => 2.0
+        );
+
+        assert_eq!(pheromone.get_values(), expected);
+    }
+
+    #[test]
+    fn local_system_updater_does_not_update_the_pheromone_after_step() {
+        let updater = LocalColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 2);
+        let pheromone = test_step_update(updater);
+
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            0 => 1.0,
+            1 => 1.0,
+            2 => 1.0
+        );
+
+        assert_eq!(pheromone.get_values(), expected);
+    }
+
+    #[test]
+    fn local_system_updater_correctly_updates_the_pheromone_after_cycle() {
+        let pheromone = test_cycle_update(LocalColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 2));
+        let expected = test_cycle_update(ColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 2));
+
+        assert_eq!(pheromone, expected);
+    }
+
+    #[test]
+    fn rank_updater_correctly_updates_the_pheromone_after_cycle() {
+        let updater = RankPheromoneUpdater::new(1.0, 3, 0.2);
+        let pheromone = test_cycle_update(updater);
+
+        // ranked routes are (0, 1) with length (1 + 2) and (0, 2) with length (1 + 3)
+        // (0, 1) is also the best-so-far route
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            // (1 * 0.8) + 2 * (1 / (1 + 2)) + 1 * (1 / (1 + 3)) + 3 * (1 / (1 + 2))
+            0 => 0.8 + 2.0 / 3.0 + 1.0 / 4.0 + 3.0 / 3.0,
+            // (1 * 0.8) + 2 * (1 / (1 + 2)) + 3 * (1 / (1 + 2))
+            1 => 0.8 + 2.0 / 3.0 + 3.0 / 3.0,
+            // (1 * 0.8) + 1 * (1 / (1 + 3))
+            2 => 0.8 + 1.0 / 4.0
+        );
+
+        let result = pheromone.get_values();
+
+        assert_delta!(result[&0], expected[&0]);
+        assert_delta!(result[&1], expected[&1]);
+        assert_delta!(result[&2], expected[&2]);
+    }
+
+    #[test]
+    fn rank_updater_keeps_depositing_on_best_so_far_route() {
+        let updater = RankPheromoneUpdater::new(1.0, 2, 0.2);
+        let edges = get_edges();
+        let init_edges = vec![edges[0], edges[1], edges[2]];
+        let init_pheromone = updater.initialize(Pheromone::new(), &init_edges);
+
+        let good_cycle = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(edges[0])]);
+        let bad_cycle = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(edges[2])]);
+
+        let pheromone = updater.on_after_cycle(init_pheromone, &good_cycle);
+        let pheromone = updater.on_after_cycle(pheromone, &bad_cycle);
+
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            // ((1 * 0.8) + 1 / 1 + 2 / 1) * 0.8 + 2 / 1
+            0 => (0.8 + 1.0 + 2.0) * 0.8 + 2.0,
+            1 => 0.8 * 0.8,
+            // (1 * 0.8) * 0.8 + 1 / 3
+            2 => 0.8 * 0.8 + 1.0 / 3.0
+        );
+
+        let result = pheromone.get_values();
+
+        assert_delta!(result[&0], expected[&0]);
+        assert_delta!(result[&1], expected[&1]);
+        assert_delta!(result[&2], expected[&2]);
+    }
+
+    #[test]
+    fn rank_updater_remembers_cost_of_best_so_far_route() {
+        let updater = RankPheromoneUpdater::new(1.0, 2, 0.2);
+        let edges = get_edges();
+        let init_edges = vec![edges[0], edges[1], edges[2]];
+        let init_pheromone = updater.initialize(Pheromone::new(), &init_edges);
+
+        // second ant takes no edge in the second step, its cost is given by the colony
+        let taken_routes = RouteCollection::new(2, 2)
+            .add_steps(&vec![Option::Some(edges[1]), Option::Some(edges[0])])
+            .add_steps(&vec![Option::Some(edges[1]), Option::None])
+            .with_costs(vec![4.0, 1.5]);
+
+        updater.on_after_cycle(init_pheromone, &taken_routes);
+
+        assert_delta!(updater.get_best_so_far().get_best().unwrap().1, 1.5);
+    }
+
+    /// The cheaper route (0) did not complete the solution, the other one (2) did
+    fn test_cycle_update_with_infeasible_route<U: PheromoneUpdater>(
+        updater: &U,
+    ) -> HashMap<EdgeKey, PheromoneLevel> {
+        let edges = get_edges();
+        let init_edges = vec![edges[0], edges[1], edges[2]];
+        let taken_routes = RouteCollection::new(2, 1)
+            .add_steps(&vec![Option::Some(edges[0]), Option::Some(edges[2])])
+            .with_costs(vec![f32::INFINITY, 3.0]);
+
+        let init_pheromone = updater.initialize(Pheromone::new(), &init_edges);
+
+        updater
+            .on_after_cycle(init_pheromone, &taken_routes)
+            .get_values()
+    }
+
+    #[test]
+    fn updaters_do_not_reinforce_infeasible_routes() {
+        let cyclical = CyclicalPheromoneUpdater::new(1.0, 0.2, 0.2, 1);
+        let system = ColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 1);
+        let elitist = ElitistPheromoneUpdater::new(1.0, 0.2, 0.2, 2, 1);
+        let rank = RankPheromoneUpdater::new(1.0, 2, 0.2);
+        let maxmin = MaxMinPheromoneUpdater::new(8.0, 0.5, 0.05, 8, DepositedRoute::GlobalBest);
+        let hyper_cube = HyperCubePheromoneUpdater::new(0.2, 2);
+
+        let results = [
+            test_cycle_update_with_infeasible_route(&cyclical),
+            test_cycle_update_with_infeasible_route(&system),
+            test_cycle_update_with_infeasible_route(&elitist),
+            test_cycle_update_with_infeasible_route(&rank),
+            test_cycle_update_with_infeasible_route(&maxmin),
+            test_cycle_update_with_infeasible_route(&hyper_cube),
+        ];
+
+        assert!(results.iter().all(|result| result[&2] > result[&0]));
+        assert_delta!(elitist.get_best_so_far().get_best().unwrap().1, 3.0);
+        assert_delta!(maxmin.get_best_so_far().get_best().unwrap().1, 3.0);
+        assert_delta!(rank.get_best_so_far().get_best().unwrap().1, 3.0);
+    }
+
+    #[test]
+    fn updaters_only_evaporate_trails_without_feasible_routes() {
+        let updater = RankPheromoneUpdater::new(1.0, 2, 0.2);
+        let taken_routes = RouteCollection::new(1, 1)
+            .add_steps(&vec![Option::Some(get_edges()[0])])
+            .with_costs(vec![f32::INFINITY]);
+        let init_pheromone = Pheromone::new().initialize_pheromone_for_edge(0, 1.0);
+
+        let pheromone = updater.on_after_cycle(init_pheromone, &taken_routes);
+
+        assert_delta!(pheromone.get_values()[&0], 0.8);
+        assert!(updater.get_best_so_far().get_best().is_none());
+    }
+
+    #[test]
+    fn rank_updater_derives_initial_value_from_route_estimate() {
+        let updater = "6,0.1,100".parse::<RankPheromoneUpdater>().unwrap();
+
+        // 0.5 * 6 * 5 / (0.1 * 100)
+        assert_delta!(updater.get_initial_value(), 1.5);
+        assert!("6,0.1,x".parse::<RankPheromoneUpdater>().is_err());
+        assert!("6".parse::<RankPheromoneUpdater>().is_err());
+    }
+
+    #[test]
+    fn rank_updater_takes_route_estimate_from_guide_if_omitted() {
+        let graph = Graph::from_directed_tuples(vec![(0, 1, 1.0), (1, 0, 1.0)]);
+        let guide = GuidingConfig::from_graph(4, 2, UpdaterStringConfig::Rank("".into()), &graph);
+        let route_estimate = guide
+            .graph_cycle_estimate
+            .unwrap_or(guide.graph_avg_distance * 2.0);
+
+        let updater =
+            RankPheromoneUpdater::from_str_and_guide("4,0.5", Option::Some(&guide)).unwrap();
+
+        // 0.5 * 4 * 3 / (0.5 * L)
+        assert_delta!(updater.get_initial_value(), 12.0 / route_estimate);
+        assert!("4,0.5".parse::<RankPheromoneUpdater>().is_err());
+    }
+
+    #[test]
+    fn elitist_updater_correctly_updates_the_pheromone_after_cycle() {
+        let updater = ElitistPheromoneUpdater::new(1.0, 0.2, 0.2, 2, 2);
+        let pheromone = test_cycle_update(updater);
+
+        // best-so-far route is (0, 1) with length (1 + 2), deposited by 2 elitist ants
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            // (1 * 0.8) + 0.2 * (1 / (1 + 2)) + 0.2 * (1 / (1 + 3)) + 2 * 0.2 * (1 / (1 + 2))
+            0 => 0.8 + 0.2 / 3.0 + 0.2 / 4.0 + 0.4 / 3.0,
+            // (1 * 0.8) + 0.2 * (1 / (1 + 2)) + 0.2 * (1 / (2 + 3)) + 2 * 0.2 * (1 / (1 + 2))
+            1 => 0.8 + 0.2 / 3.0 + 0.2 / 5.0 + 0.4 / 3.0,
+            // (1 * 0.8) + 0.2 * (1 / (2 + 3)) + 0.2 * (1 / (1 + 3))
+            2 => 0.8 + 0.2 / 5.0 + 0.2 / 4.0
+        );
+
+        let result = pheromone.get_values();
+
+        assert_delta!(result[&0], expected[&0]);
+        assert_delta!(result[&1], expected[&1]);
+        assert_delta!(result[&2], expected[&2]);
+    }
+
+    fn test_maxmin_good_then_bad_cycle(updater: &MaxMinPheromoneUpdater) -> Pheromone {
+        let edges = get_edges();
+        let init_edges = vec![edges[0], edges[1], edges[2]];
+        let init_pheromone = updater.initialize(Pheromone::new(), &init_edges);
+
+        let good_cycle = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(edges[0])]);
+        let bad_cycle = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(edges[2])]);
+
+        let pheromone = updater.on_after_cycle(init_pheromone, &good_cycle);
+
+        updater.on_after_cycle(pheromone, &bad_cycle)
+    }
+
+    #[test]
+    fn maxmin_updater_deposits_on_global_best_route() {
+        let updater = MaxMinPheromoneUpdater::new(8.0, 0.5, 0.05, 8, DepositedRoute::GlobalBest);
+        let pheromone = test_maxmin_good_then_bad_cycle(&updater);
+        let result = pheromone.get_values();
+
+        assert!(result[&0] > result[&2]);
+        assert_delta!(updater.get_best_so_far().get_best().unwrap().1, 8.0);
+    }
+
+    #[test]
+    fn maxmin_updater_deposits_on_iteration_best_route() {
+        let updater = MaxMinPheromoneUpdater::new(8.0, 0.5, 0.05, 8, DepositedRoute::IterationBest);
+        let pheromone = test_maxmin_good_then_bad_cycle(&updater);
+        let result = pheromone.get_values();
+
+        assert!(result[&0] <= result[&2]);
+        assert_delta!(updater.get_best_so_far().get_best().unwrap().1, 8.0);
+    }
+
+    /// Colonies reset updaters only when pheromone is reset between epochs,
+    /// trails reinitialized on stagnation keep the best-so-far route
+    #[test]
+    fn updaters_forget_best_so_far_route_on_epoch_reset() {
+        let maxmin = MaxMinPheromoneUpdater::new(8.0, 0.5, 0.05, 8, DepositedRoute::GlobalBest);
+        let rank = RankPheromoneUpdater::new(1.0, 2, 0.2);
+        let elitist = ElitistPheromoneUpdater::new(1.0, 0.2, 0.2, 2, 1);
+        let taken_routes =
+            RouteCollection::new(1, 1).add_steps(&vec![Option::Some(get_edges()[0])]);
+
+        test_maxmin_good_then_bad_cycle(&maxmin);
+        rank.on_after_cycle(Pheromone::new(), &taken_routes);
+        elitist.on_after_cycle(Pheromone::new(), &taken_routes);
+        assert!(rank.get_best_so_far().get_best().is_some());
+
+        maxmin.reset();
+        rank.reset();
+        elitist.reset();
+
+        assert!(maxmin.get_best_so_far().get_best().is_none());
+        assert!(rank.get_best_so_far().get_best().is_none());
+        assert!(elitist.get_best_so_far().get_best().is_none());
+    }
+
+    #[test]
+    fn maxmin_updater_parses_deposited_route() {
+        assert!("4,0.5,0.05,4".parse::<MaxMinPheromoneUpdater>().is_ok());
+        assert!("4,0.5,0.05,4,global"
+            .parse::<MaxMinPheromoneUpdater>()
+            .is_ok());
+        assert!("4,0.5,0.05,4,iteration"
+            .parse::<MaxMinPheromoneUpdater>()
+            .is_ok());
+        assert!("4,0.5,0.05,4,other"
+            .parse::<MaxMinPheromoneUpdater>()
+            .is_err());
+    }
+
+    #[test]
+    fn hyper_cube_updater_correctly_updates_the_pheromone_after_cycle() {
+        let updater = HyperCubePheromoneUpdater::new(0.2, 2);
+        let pheromone = test_cycle_update(updater);
+
+        // deposited routes are (0, 1) with length (1 + 2) and (0, 2) with length (1 + 3),
+        // their qualities normalized to sum are (4 / 7) and (3 / 7)
+        let expected: HashMap<EdgeKey, PheromoneLevel> = map!(
+            // (0.5 * 0.8) + 0.2 * (4 / 7 + 3 / 7)
+            0 => 0.4 + 0.2,
+            // (0.5 * 0.8) + 0.2 * (4 / 7)
+            1 => 0.4 + 0.2 * 4.0 / 7.0,
+            // (0.5 * 0.8) + 0.2 * (3 / 7)
+            2 => 0.4 + 0.2 * 3.0 / 7.0
+        );
+
+        let result = pheromone.get_values();
+
+        assert_delta!(result[&0], expected[&0]);
+        assert_delta!(result[&1], expected[&1]);
+        assert_delta!(result[&2], expected[&2]);
+    }
+
+    #[test]
+    fn hyper_cube_updater_keeps_trails_in_unit_interval() {
+        let updater = HyperCubePheromoneUpdater::new(0.5, 3);
+        let edges = get_edges();
+        let init_edges = vec![edges[0], edges[1], edges[2]];
+        let init_pheromone = updater.initialize(Pheromone::new(), &init_edges);
+
+        let cycle = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(edges[0])]);
+        let pheromone = (0..50).fold(init_pheromone, |pheromone, _| {
+            updater.on_after_cycle(pheromone, &cycle)
+        });
+
+        assert!(pheromone
+            .get_values()
+            .values()
+            .all(|value| (0.0..=1.0).contains(value)));
+        assert_delta!(pheromone.get_values()[&0], 1.0);
+    }
+
+    #[test]
+    fn updaters_deposit_only_on_taken_direction_of_directed_edge() {
+        let updater = ConstantPheromoneUpdater::new(1.0, 0.2, 0.2);
+        let graph = Graph::from_directed_tuples(vec![(0, 1, 1.0), (1, 0, 1.0)]);
+        let forward = AdjacencyListEntry::new_directed(0, 1, 1.0);
+        let backward = AdjacencyListEntry::new_directed(1, 0, 1.0);
+
+        let init_pheromone = updater.initialize(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            graph.get_all_edges(),
+        );
+        let pheromone = updater.on_after_step(init_pheromone, &vec![Option::Some(forward)]);
+
+        // (1 * 0.8) + 0.2
+        assert_delta!(pheromone.get_pheromone_for_edge(forward.key), 1.0);
+        // (1 * 0.8)
+        assert_delta!(pheromone.get_pheromone_for_edge(backward.key), 0.8);
+    }
+
+    /// Single route taking the forward arc of a 2-cycle, returns trails of (forward, backward)
+    fn test_directed_cycle_update<U: PheromoneUpdater>(updater: &U) -> (f32, f32) {
+        let graph = Graph::from_directed_tuples(vec![(0, 1, 1.0), (1, 0, 1.0)]);
+        let forward = AdjacencyListEntry::new_directed(0, 1, 1.0);
+        let backward = AdjacencyListEntry::new_directed(1, 0, 1.0);
+        let taken_routes = RouteCollection::new(1, 1).add_steps(&vec![Option::Some(forward)]);
+
+        let init_pheromone = updater.initialize(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            graph.get_all_edges(),
+        );
+        let pheromone = updater.on_after_cycle(init_pheromone, &taken_routes);
+
+        (
+            pheromone.get_pheromone_for_edge(forward.key),
+            pheromone.get_pheromone_for_edge(backward.key),
+        )
+    }
+
+    #[test]
+    fn cycle_updaters_deposit_only_on_taken_direction_of_directed_edge() {
+        let results = [
+            test_directed_cycle_update(&CyclicalPheromoneUpdater::new(1.0, 0.2, 0.2, 1)),
+            test_directed_cycle_update(&ColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 1)),
+            test_directed_cycle_update(&LocalColonyPheromoneUpdater::new(1.0, 0.2, 0.2, 1)),
+            test_directed_cycle_update(&ElitistPheromoneUpdater::new(1.0, 0.2, 0.2, 2, 1)),
+            test_directed_cycle_update(&RankPheromoneUpdater::new(1.0, 2, 0.2)),
+            test_directed_cycle_update(&HyperCubePheromoneUpdater::new(0.2, 1)),
+            test_directed_cycle_update(&MaxMinPheromoneUpdater::new(
+                8.0,
+                0.5,
+                0.05,
+                8,
+                DepositedRoute::GlobalBest,
+            )),
+        ];
+
+        for (forward, backward) in results.iter() {
+            assert!(forward > backward);
+        }
+
+        // cyclical: (1 * 0.8) + 0.2 / 1 and (1 * 0.8)
+        assert_delta!(results[0].0, 1.0);
+        assert_delta!(results[0].1, 0.8);
     }
 }
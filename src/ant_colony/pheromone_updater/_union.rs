@@ -1,12 +1,14 @@
 use std::{fmt::Display, str::FromStr};
 
-use crate::ant_colony::graph::{RouteBatchWithHoles, RouteCollection};
+use crate::ant_colony::graph::{AdjacencyListEntry, RouteBatchWithHoles, RouteCollection};
 use crate::ant_colony::guiding_config::WithGuidingConfig;
 use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
 
 use super::{
     AveragePheromoneUpdater, ColonyPheromoneUpdater, ConstantPheromoneUpdater,
-    CyclicalPheromoneUpdater, MaxMinPheromoneUpdater, PheromoneUpdater, UpdaterStringConfig,
+    CyclicalPheromoneUpdater, ElitistPheromoneUpdater, HyperCubePheromoneUpdater,
+    LocalColonyPheromoneUpdater, MaxMinPheromoneUpdater, PheromoneUpdater, RankPheromoneUpdater,
+    UpdaterStringConfig,
 };
 use crate::ant_colony::guiding_config::GuidingConfig;
 
@@ -17,7 +19,11 @@ pub enum Updaters {
     Const(ConstantPheromoneUpdater),
     Cyclical(CyclicalPheromoneUpdater),
     Colony(ColonyPheromoneUpdater),
+    LocalColony(LocalColonyPheromoneUpdater),
     MaxMin(MaxMinPheromoneUpdater),
+    Rank(RankPheromoneUpdater),
+    Elitist(ElitistPheromoneUpdater),
+    HyperCube(HyperCubePheromoneUpdater),
 }
 
 impl PheromoneUpdater for Updaters {
@@ -27,7 +33,57 @@ impl PheromoneUpdater for Updaters {
             Updaters::Const(updater) => updater.get_initial_value(),
             Updaters::Cyclical(updater) => updater.get_initial_value(),
             Updaters::Colony(updater) => updater.get_initial_value(),
+            Updaters::LocalColony(updater) => updater.get_initial_value(),
             Updaters::MaxMin(updater) => updater.get_initial_value(),
+            Updaters::Rank(updater) => updater.get_initial_value(),
+            Updaters::Elitist(updater) => updater.get_initial_value(),
+            Updaters::HyperCube(updater) => updater.get_initial_value(),
+        }
+    }
+
+    fn reset(&self) {
+        match self {
+            Updaters::Average(updater) => updater.reset(),
+            Updaters::Const(updater) => updater.reset(),
+            Updaters::Cyclical(updater) => updater.reset(),
+            Updaters::Colony(updater) => updater.reset(),
+            Updaters::LocalColony(updater) => updater.reset(),
+            Updaters::MaxMin(updater) => updater.reset(),
+            Updaters::Rank(updater) => updater.reset(),
+            Updaters::Elitist(updater) => updater.reset(),
+            Updaters::HyperCube(updater) => updater.reset(),
+        }
+    }
+
+    fn has_local_update(&self) -> bool {
+        match self {
+            Updaters::Average(updater) => updater.has_local_update(),
+            Updaters::Const(updater) => updater.has_local_update(),
+            Updaters::Cyclical(updater) => updater.has_local_update(),
+            Updaters::Colony(updater) => updater.has_local_update(),
+            Updaters::LocalColony(updater) => updater.has_local_update(),
+            Updaters::MaxMin(updater) => updater.has_local_update(),
+            Updaters::Rank(updater) => updater.has_local_update(),
+            Updaters::Elitist(updater) => updater.has_local_update(),
+            Updaters::HyperCube(updater) => updater.has_local_update(),
+        }
+    }
+
+    fn on_after_ant_move(
+        &self,
+        pheromone: Pheromone,
+        taken_edge: &AdjacencyListEntry,
+    ) -> Pheromone {
+        match self {
+            Updaters::Average(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::Const(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::Cyclical(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::Colony(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::LocalColony(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::MaxMin(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::Rank(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::Elitist(updater) => updater.on_after_ant_move(pheromone, taken_edge),
+            Updaters::HyperCube(updater) => updater.on_after_ant_move(pheromone, taken_edge),
         }
     }
 
@@ -37,7 +93,11 @@ impl PheromoneUpdater for Updaters {
             Updaters::Const(updater) => updater.on_after_step(pheromone, taken_edges),
             Updaters::Cyclical(updater) => updater.on_after_step(pheromone, taken_edges),
             Updaters::Colony(updater) => updater.on_after_step(pheromone, taken_edges),
+            Updaters::LocalColony(updater) => updater.on_after_step(pheromone, taken_edges),
             Updaters::MaxMin(updater) => updater.on_after_step(pheromone, taken_edges),
+            Updaters::Rank(updater) => updater.on_after_step(pheromone, taken_edges),
+            Updaters::Elitist(updater) => updater.on_after_step(pheromone, taken_edges),
+            Updaters::HyperCube(updater) => updater.on_after_step(pheromone, taken_edges),
         }
     }
 
@@ -47,7 +107,11 @@ impl PheromoneUpdater for Updaters {
             Updaters::Const(updater) => updater.on_after_cycle(pheromone, taken_routes),
             Updaters::Cyclical(updater) => updater.on_after_cycle(pheromone, taken_routes),
             Updaters::Colony(updater) => updater.on_after_cycle(pheromone, taken_routes),
+            Updaters::LocalColony(updater) => updater.on_after_cycle(pheromone, taken_routes),
             Updaters::MaxMin(updater) => updater.on_after_cycle(pheromone, taken_routes),
+            Updaters::Rank(updater) => updater.on_after_cycle(pheromone, taken_routes),
+            Updaters::Elitist(updater) => updater.on_after_cycle(pheromone, taken_routes),
+            Updaters::HyperCube(updater) => updater.on_after_cycle(pheromone, taken_routes),
         }
     }
 }
@@ -71,7 +135,11 @@ impl Display for Updaters {
             Updaters::Const(updater) => updater.fmt(f),
             Updaters::Cyclical(updater) => updater.fmt(f),
             Updaters::Colony(updater) => updater.fmt(f),
+            Updaters::LocalColony(updater) => updater.fmt(f),
             Updaters::MaxMin(updater) => updater.fmt(f),
+            Updaters::Rank(updater) => updater.fmt(f),
+            Updaters::Elitist(updater) => updater.fmt(f),
+            Updaters::HyperCube(updater) => updater.fmt(f),
         }
     }
 }
@@ -102,10 +170,32 @@ impl Updaters {
                 .or_else(|| maybe_guide.and_then(ColonyPheromoneUpdater::guided))
                 .map(Self::Colony),
 
+            UpdaterStringConfig::LocalColony(opts) => LocalColonyPheromoneUpdater::from_str(opts)
+                .ok()
+                .or_else(|| maybe_guide.and_then(LocalColonyPheromoneUpdater::guided))
+                .map(Self::LocalColony),
+
             UpdaterStringConfig::MaxMin(opts) => MaxMinPheromoneUpdater::from_str(opts)
                 .ok()
                 .or_else(|| maybe_guide.and_then(MaxMinPheromoneUpdater::guided))
                 .map(Self::MaxMin),
+
+            UpdaterStringConfig::Rank(opts) => {
+                RankPheromoneUpdater::from_str_and_guide(opts, maybe_guide)
+                    .ok()
+                    .or_else(|| maybe_guide.and_then(RankPheromoneUpdater::guided))
+                    .map(Self::Rank)
+            }
+
+            UpdaterStringConfig::Elitist(opts) => ElitistPheromoneUpdater::from_str(opts)
+                .ok()
+                .or_else(|| maybe_guide.and_then(ElitistPheromoneUpdater::guided))
+                .map(Self::Elitist),
+
+            UpdaterStringConfig::HyperCube(opts) => HyperCubePheromoneUpdater::from_str(opts)
+                .ok()
+                .or_else(|| maybe_guide.and_then(HyperCubePheromoneUpdater::guided))
+                .map(Self::HyperCube),
         }
     }
 }
@@ -44,7 +44,7 @@ impl PheromoneUpdater for AveragePheromoneUpdater {
             |updated_pheromone, taken_edge| {
                 let increment = self.increment / taken_edge.distance;
 
-                updated_pheromone.increase_pheromone_value(taken_edge.key, increment)
+                updated_pheromone.increase_pheromone_value(taken_edge, increment)
             },
         )
     }
@@ -0,0 +1,45 @@
+use std::sync::Mutex;
+
+use crate::ant_colony::graph::Route;
+
+/// Remembers the cheapest route seen across all cycles.
+///
+/// Updaters are only given shared references, hence the interior mutability.
+pub struct BestRouteMemory {
+    best: Mutex<Option<(Route, f32)>>,
+}
+
+impl BestRouteMemory {
+    pub fn new() -> Self {
+        BestRouteMemory {
+            best: Mutex::new(Option::None),
+        }
+    }
+
+    /// Compares candidate against the remembered route and
+    /// returns the better one (with its cost)
+    pub fn remember(&self, candidate: &Route, candidate_cost: f32) -> (Route, f32) {
+        let mut best = self.best.lock().unwrap();
+
+        match best.as_ref() {
+            Option::Some((_, best_cost)) if *best_cost <= candidate_cost => {}
+            _ => *best = Option::Some((candidate.clone(), candidate_cost)),
+        }
+
+        best.clone().unwrap_or((candidate.clone(), candidate_cost))
+    }
+
+    pub fn forget(&self) {
+        *self.best.lock().unwrap() = Option::None;
+    }
+
+    pub fn get_best(&self) -> Option<(Route, f32)> {
+        self.best.lock().unwrap().clone()
+    }
+}
+
+impl Default for BestRouteMemory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
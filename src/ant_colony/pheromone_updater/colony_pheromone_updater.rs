@@ -11,7 +11,7 @@ use super::PheromoneUpdater;
 /// and increases pheromone value of taken edges by a contant value.
 ///
 /// After whole cycle is finished, increases pheromone levels of edges
-/// that belong to the shortest route.
+/// that belong to the cheapest feasible route.
 pub struct ColonyPheromoneUpdater {
     // advised to be (n / tour length), n - number of cities
     initial_and_step_increment: f32,
@@ -50,7 +50,7 @@ impl PheromoneUpdater for ColonyPheromoneUpdater {
         taken_edges.iter().filter_map(|a| a.as_ref()).fold(
             decayed_pheromone,
             |updated_pheromone, taken_edge| {
-                updated_pheromone.increase_pheromone_value(taken_edge.key, increment)
+                updated_pheromone.increase_pheromone_value(taken_edge, increment)
             },
         )
     }
@@ -60,20 +60,16 @@ impl PheromoneUpdater for ColonyPheromoneUpdater {
 
         let decayed_pheromone = pheromone.scale_all_pheromone_values(decay);
 
-        match taken_routes.get_shortest_route() {
+        match taken_routes.get_best_route(self.target_num_of_steps) {
             Option::None => decayed_pheromone,
-            Option::Some(route) => {
-                let route_dist = route.get_adjusted_distance(self.target_num_of_steps);
-
-                route.get_edges().iter().fold(
-                    decayed_pheromone,
-                    |edge_updated_route, taken_edge| {
-                        let increment = self.cycle_evaporation_rate / route_dist;
-
-                        edge_updated_route.increase_pheromone_value(taken_edge.key, increment)
-                    },
-                )
-            }
+            Option::Some((route, route_cost)) => route.get_edges().iter().fold(
+                decayed_pheromone,
+                |edge_updated_route, taken_edge| {
+                    let increment = self.cycle_evaporation_rate / route_cost;
+
+                    edge_updated_route.increase_pheromone_value(taken_edge, increment)
+                },
+            ),
         }
     }
 }
@@ -42,7 +42,7 @@ impl PheromoneUpdater for ConstantPheromoneUpdater {
         taken_edges.iter().filter_map(|a| a.as_ref()).fold(
             decayed_pheromone,
             |updated_pheromone, taken_edge| {
-                updated_pheromone.increase_pheromone_value(taken_edge.key, self.increment)
+                updated_pheromone.increase_pheromone_value(taken_edge, self.increment)
             },
         )
     }
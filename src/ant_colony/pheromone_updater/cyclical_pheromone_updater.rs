@@ -8,7 +8,8 @@ use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
 use super::PheromoneUpdater;
 
 /// After all ants finish whole cycle, decays the pheromone and updates pheromone levels
-/// of specific edges by value inversly proportional to length of route they belong to.
+/// of specific edges by value inversly proportional to cost of route they belong to.
+/// Infeasible routes do not deposit.
 ///
 /// If edge was taken by multiple ants it will be increased multiple times.
 ///
@@ -45,21 +46,22 @@ impl PheromoneUpdater for CyclicalPheromoneUpdater {
         let decay = 1.0 - self.evaporation_rate;
         let decayed_pheromone = pheromone.scale_all_pheromone_values(decay);
 
-        taken_routes.get_routes().iter().fold(
-            decayed_pheromone,
-            |route_updated_pheromone, taken_route| {
-                let route_dist = taken_route.get_adjusted_distance(self.target_num_of_steps);
+        taken_routes
+            .get_feasible_routes(self.target_num_of_steps)
+            .into_iter()
+            .fold(
+                decayed_pheromone,
+                |route_updated_pheromone, (taken_route, route_cost)| {
+                    taken_route.get_edges().iter().fold(
+                        route_updated_pheromone,
+                        |edge_updated_route, taken_edge| {
+                            let increment = self.increment / route_cost;
 
-                taken_route.get_edges().iter().fold(
-                    route_updated_pheromone,
-                    |edge_updated_route, taken_edge| {
-                        let increment = self.increment / route_dist;
-
-                        edge_updated_route.increase_pheromone_value(taken_edge.key, increment)
-                    },
-                )
-            },
-        )
+                            edge_updated_route.increase_pheromone_value(taken_edge, increment)
+                        },
+                    )
+                },
+            )
     }
 }
 
@@ -0,0 +1,150 @@
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::RouteCollection;
+use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
+use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
+
+use super::{BestRouteMemory, CyclicalPheromoneUpdater, PheromoneUpdater};
+
+/// Elitist Ant System (`Dorigo1996AntST`).
+///
+/// After all ants finish whole cycle, updates the pheromone in the same way as `CyclicalPheromoneUpdater`.
+/// Additionally, best route found in all cycles so far is reinforced
+/// as if it was taken by a given number of elitist ants.
+///
+/// Does not perform updates after each step.
+pub struct ElitistPheromoneUpdater {
+    initial_value: f32,
+    evaporation_rate: f32,
+    increment: f32,
+    n_elitist_ants: usize,
+    target_num_of_steps: usize,
+    cyclical_updater: CyclicalPheromoneUpdater,
+    best_so_far: BestRouteMemory,
+}
+
+impl ElitistPheromoneUpdater {
+    pub fn new(
+        initial_value: f32,
+        evaporation_rate: f32,
+        increment: f32,
+        n_elitist_ants: usize,
+        target_num_of_steps: usize,
+    ) -> Self {
+        ElitistPheromoneUpdater {
+            initial_value,
+            evaporation_rate,
+            increment,
+            n_elitist_ants,
+            target_num_of_steps,
+            cyclical_updater: CyclicalPheromoneUpdater::new(
+                initial_value,
+                evaporation_rate,
+                increment,
+                target_num_of_steps,
+            ),
+            best_so_far: BestRouteMemory::new(),
+        }
+    }
+
+    pub fn get_best_so_far(&self) -> &BestRouteMemory {
+        &self.best_so_far
+    }
+}
+
+impl PheromoneUpdater for ElitistPheromoneUpdater {
+    fn get_initial_value(&self) -> PheromoneLevel {
+        self.initial_value
+    }
+
+    fn reset(&self) {
+        self.best_so_far.forget();
+    }
+
+    fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
+        let updated_pheromone = self
+            .cyclical_updater
+            .on_after_cycle(pheromone, taken_routes);
+
+        let maybe_best_so_far = match taken_routes.get_best_route(self.target_num_of_steps) {
+            Option::Some((route, route_cost)) => {
+                Option::Some(self.best_so_far.remember(&route, route_cost))
+            }
+            Option::None => self.best_so_far.get_best(),
+        };
+
+        match maybe_best_so_far {
+            Option::Some((route, route_cost)) => {
+                let increment = self.n_elitist_ants as f32 * self.increment / route_cost;
+
+                route
+                    .get_edges()
+                    .iter()
+                    .fold(updated_pheromone, |elitist_pheromone, edge| {
+                        elitist_pheromone.increase_pheromone_value(edge, increment)
+                    })
+            }
+            Option::None => updated_pheromone,
+        }
+    }
+}
+
+impl FromStr for ElitistPheromoneUpdater {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse opts of ElitistPheromoneUpdater";
+
+        let (initial_value, evaporation_rate, increment, n_elitist_ants, target_len): (
+            f32,
+            f32,
+            f32,
+            f32,
+            f32,
+        ) = opts
+            .splitn(5, ',')
+            .map(str::parse)
+            .filter_map(Result::ok)
+            .collect_tuple()
+            .ok_or(error)?;
+
+        Ok(ElitistPheromoneUpdater::new(
+            initial_value,
+            evaporation_rate,
+            increment,
+            n_elitist_ants as usize,
+            target_len as usize,
+        ))
+    }
+}
+
+impl WithGuidingConfig for ElitistPheromoneUpdater {
+    fn guided(guide: &GuidingConfig) -> Option<Self> {
+        // `Dorigo1996AntST` advises number of elitist ants equal to number of nodes,
+        // but with thousands of ants that would only lead to premature convergence
+        let n_elitist_ants = guide.graph_node_count.min(guide.ant_count / 10).max(1);
+
+        Some(ElitistPheromoneUpdater::new(
+            1.0,
+            0.3,
+            1.0,
+            n_elitist_ants,
+            guide.num_of_steps_per_cycle,
+        ))
+    }
+}
+
+impl Display for ElitistPheromoneUpdater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pheromone updater (Elitist)\n\t\
+            initial_value: {:>5}\n\t\
+            evaporation:   {:>5.3}\n\t\
+            increment:     {:>5.3}\n\t\
+            elitist ants:  {:>5}",
+            self.initial_value, self.evaporation_rate, self.increment, self.n_elitist_ants
+        )
+    }
+}
@@ -0,0 +1,110 @@
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::RouteCollection;
+use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
+use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
+
+use super::PheromoneUpdater;
+
+/// Hyper-cube framework (`Blum2004HyperCube`).
+///
+/// After all ants finish whole cycle, the n cheapest feasible routes deposit
+///         \tau_{ij} = (1 - \rho) \tau_{ij} + \rho \sum_s F(s) / \sum_{s'} F(s')
+/// where F(s) = 1 / L(s) and L(s) is the cost of the route. Deposits of a single cycle sum up to \rho,
+/// so trails stay in [0, 1] regardless of the scale of graph distances.
+///
+/// Does not perform updates after each step.
+pub struct HyperCubePheromoneUpdater {
+    evaporation_rate: f32,
+    n_best: usize,
+}
+
+impl HyperCubePheromoneUpdater {
+    pub fn new(evaporation_rate: f32, n_best: usize) -> Self {
+        HyperCubePheromoneUpdater {
+            evaporation_rate,
+            n_best: n_best.max(1),
+        }
+    }
+}
+
+impl PheromoneUpdater for HyperCubePheromoneUpdater {
+    fn get_initial_value(&self) -> PheromoneLevel {
+        0.5
+    }
+
+    fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
+        let target_num_of_steps = taken_routes.get_max_route_length();
+        let best_routes = taken_routes.get_n_best_routes(self.n_best, target_num_of_steps);
+
+        let qualities = best_routes
+            .iter()
+            .map(|(_, route_cost)| 1.0 / route_cost)
+            .collect::<Vec<_>>();
+        let total_quality: f32 = qualities.iter().sum();
+
+        if best_routes.is_empty() || !total_quality.is_normal() {
+            return pheromone;
+        }
+
+        let decay = 1.0 - self.evaporation_rate;
+        let decayed_pheromone = pheromone.scale_all_pheromone_values(decay);
+
+        let updated_pheromone = best_routes.iter().zip(qualities).fold(
+            decayed_pheromone,
+            |route_updated_pheromone, ((route, _), quality)| {
+                let increment = self.evaporation_rate * quality / total_quality;
+
+                route.get_edges().iter().fold(
+                    route_updated_pheromone,
+                    |edge_updated_pheromone, edge| {
+                        edge_updated_pheromone.increase_pheromone_value(edge, increment)
+                    },
+                )
+            },
+        );
+
+        // routes that take the same edge more than once could push it above 1
+        updated_pheromone.clamp(0.0, 1.0)
+    }
+}
+
+impl FromStr for HyperCubePheromoneUpdater {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse opts of HyperCubePheromoneUpdater";
+
+        let (evaporation_rate, n_best): (f32, f32) = opts
+            .splitn(2, ',')
+            .map(str::parse)
+            .filter_map(Result::ok)
+            .collect_tuple()
+            .ok_or(error)?;
+
+        Ok(HyperCubePheromoneUpdater::new(
+            evaporation_rate,
+            n_best as usize,
+        ))
+    }
+}
+
+impl WithGuidingConfig for HyperCubePheromoneUpdater {
+    fn guided(_guide: &GuidingConfig) -> Option<Self> {
+        // independent of the graph, that is the whole point
+        Some(HyperCubePheromoneUpdater::new(0.1, 1))
+    }
+}
+
+impl Display for HyperCubePheromoneUpdater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pheromone updater (Hyper-cube)\n\t\
+            evaporation:   {:>5.3}\n\t\
+            best routes:   {:>5}",
+            self.evaporation_rate, self.n_best
+        )
+    }
+}
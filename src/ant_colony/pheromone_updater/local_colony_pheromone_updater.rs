@@ -0,0 +1,127 @@
+use itertools::Itertools;
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::{AdjacencyListEntry, RouteCollection};
+use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
+use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
+
+use super::{ColonyPheromoneUpdater, PheromoneUpdater};
+
+/// Ant Colony System updater with a true local update.
+///
+/// Right after an ant takes an edge, the edge's trail is moved towards the initial value
+///         \tau_{ij} = (1 - \xi) \tau_{ij} + \xi \tau_0
+/// so ants that select their edges later in the same step are less likely to follow it.
+/// Ants observe updates of ants with lower indices, as if they moved one after another,
+/// hence trails are deterministic regardless of threads. Ants still select edges in parallel,
+/// only ants next to an edge taken earlier in the same step select again.
+///
+/// After whole cycle is finished, behaves exactly like `ColonyPheromoneUpdater`.
+pub struct LocalColonyPheromoneUpdater {
+    initial_value: f32,
+    local_evaporation_rate: f32,
+    global_evaporation_rate: f32,
+    global_updater: ColonyPheromoneUpdater,
+}
+
+impl LocalColonyPheromoneUpdater {
+    pub fn new(
+        initial_value: f32,
+        local_evaporation_rate: f32,
+        global_evaporation_rate: f32,
+        target_num_of_steps: usize,
+    ) -> Self {
+        LocalColonyPheromoneUpdater {
+            initial_value,
+            local_evaporation_rate,
+            global_evaporation_rate,
+            global_updater: ColonyPheromoneUpdater::new(
+                initial_value,
+                local_evaporation_rate,
+                global_evaporation_rate,
+                target_num_of_steps,
+            ),
+        }
+    }
+}
+
+impl PheromoneUpdater for LocalColonyPheromoneUpdater {
+    fn get_initial_value(&self) -> PheromoneLevel {
+        self.initial_value
+    }
+
+    fn has_local_update(&self) -> bool {
+        true
+    }
+
+    fn on_after_ant_move(
+        &self,
+        pheromone: Pheromone,
+        taken_edge: &AdjacencyListEntry,
+    ) -> Pheromone {
+        let decay = 1.0 - self.local_evaporation_rate;
+        let increment = self.local_evaporation_rate * self.initial_value;
+
+        pheromone.update_pheromone_value(taken_edge, |value| value * decay + increment)
+    }
+
+    fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
+        self.global_updater.on_after_cycle(pheromone, taken_routes)
+    }
+}
+
+impl FromStr for LocalColonyPheromoneUpdater {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse opts of LocalColonyPheromoneUpdater";
+
+        let (initial_value, local_evaporation_rate, global_evaporation_rate, target_len): (
+            f32,
+            f32,
+            f32,
+            f32,
+        ) = opts
+            .splitn(4, ',')
+            .map(str::parse)
+            .filter_map(Result::ok)
+            .collect_tuple()
+            .ok_or(error)?;
+
+        Ok(LocalColonyPheromoneUpdater::new(
+            initial_value,
+            local_evaporation_rate,
+            global_evaporation_rate,
+            target_len as usize,
+        ))
+    }
+}
+
+impl WithGuidingConfig for LocalColonyPheromoneUpdater {
+    fn guided(guide: &GuidingConfig) -> Option<Self> {
+        // `Dorigo1997AntCS` advises tau_0 = 1 / (n * L_nn)
+        let route_estimate = guide
+            .graph_cycle_estimate
+            .unwrap_or(guide.graph_avg_distance * guide.num_of_steps_per_cycle as f32);
+
+        Some(LocalColonyPheromoneUpdater::new(
+            1.0 / (guide.graph_node_count as f32 * route_estimate),
+            0.1,
+            0.1,
+            guide.num_of_steps_per_cycle,
+        ))
+    }
+}
+
+impl Display for LocalColonyPheromoneUpdater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pheromone updater (System, local)\n\t\
+            initial value:      {:>5}\n\t\
+            local evaporation:  {:>5.3}\n\t\
+            global evaporation: {:>5.3}",
+            self.initial_value, self.local_evaporation_rate, self.global_evaporation_rate,
+        )
+    }
+}
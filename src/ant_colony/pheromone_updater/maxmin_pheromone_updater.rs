@@ -2,17 +2,40 @@ use itertools::Itertools;
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::ant_colony::graph::RouteCollection;
+use crate::ant_colony::graph::{Route, RouteCollection};
 use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
 use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
 
-use super::PheromoneUpdater;
+use super::{BestRouteMemory, PheromoneUpdater};
+
+/// Determines which route is allowed to deposit pheromone after the cycle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DepositedRoute {
+    /// cheapest feasible route from the last cycle
+    IterationBest,
+    /// cheapest feasible route from all cycles so far
+    GlobalBest,
+}
+
+impl FromStr for DepositedRoute {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "iteration" => Ok(Self::IterationBest),
+            "global" => Ok(Self::GlobalBest),
+            _ => Err("Failed to parse deposited route type"),
+        }
+    }
+}
 
 pub struct MaxMinPheromoneUpdater {
     route_estimate: f32,
     evaporation_rate: f32,
     best_route_p: f32,
     target_num_of_steps: usize,
+    deposited_route: DepositedRoute,
+    best_so_far: BestRouteMemory,
 }
 
 impl MaxMinPheromoneUpdater {
@@ -21,12 +44,35 @@ impl MaxMinPheromoneUpdater {
         evaporation_rate: f32,
         best_route_p: f32,
         target_num_of_steps: usize,
+        deposited_route: DepositedRoute,
     ) -> Self {
         MaxMinPheromoneUpdater {
             route_estimate,
             evaporation_rate,
             best_route_p,
             target_num_of_steps,
+            deposited_route,
+            best_so_far: BestRouteMemory::new(),
+        }
+    }
+
+    pub fn get_best_so_far(&self) -> &BestRouteMemory {
+        &self.best_so_far
+    }
+
+    /// Returns the route (with its cost) which should deposit pheromone.
+    /// Best-so-far memory is updated regardless of the mode.
+    fn select_deposited_route(&self, taken_routes: &RouteCollection) -> Option<(Route, f32)> {
+        let iteration_best = taken_routes.get_best_route(self.target_num_of_steps);
+
+        let global_best = iteration_best
+            .as_ref()
+            .map(|(route, route_cost)| self.best_so_far.remember(route, *route_cost))
+            .or_else(|| self.best_so_far.get_best());
+
+        match self.deposited_route {
+            DepositedRoute::IterationBest => iteration_best,
+            DepositedRoute::GlobalBest => global_best,
         }
     }
 
@@ -50,12 +96,15 @@ impl PheromoneUpdater for MaxMinPheromoneUpdater {
         self.pher_max(self.route_estimate)
     }
 
+    fn reset(&self) {
+        self.best_so_far.forget();
+    }
+
     fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
-        if let Some(route) = taken_routes.get_shortest_route() {
+        if let Some((route, route_dist)) = self.select_deposited_route(taken_routes) {
             let decay = 1.0 - self.evaporation_rate;
             let decayed_pheromone = pheromone.scale_all_pheromone_values(decay);
 
-            let route_dist = route.get_adjusted_distance(self.target_num_of_steps);
             let increment = 1.0 / route_dist;
 
             let updated_pheromone =
@@ -63,7 +112,7 @@ impl PheromoneUpdater for MaxMinPheromoneUpdater {
                     .get_edges()
                     .iter()
                     .fold(decayed_pheromone, |updated_pheromone, edge| {
-                        updated_pheromone.increase_pheromone_value(edge.key, increment)
+                        updated_pheromone.increase_pheromone_value(edge, increment)
                     });
 
             let max = self.pher_max(route_dist);
@@ -82,23 +131,32 @@ impl FromStr for MaxMinPheromoneUpdater {
     fn from_str(opts: &str) -> Result<Self, Self::Err> {
         let error = "Failed to parse opts of MaxMinPheromoneUpdater";
 
+        let mut opts_iter = opts.splitn(5, ',');
+
         let (route_estimate, evaporation_rate, best_route_p, target_num_of_steps): (
             f32,
             f32,
             f32,
             f32,
-        ) = opts
-            .splitn(4, ',')
+        ) = opts_iter
+            .by_ref()
+            .take(4)
             .map(str::parse)
             .filter_map(Result::ok)
             .collect_tuple()
             .ok_or(error)?;
 
+        // deposited route is optional, by default iteration best is used
+        let deposited_route = opts_iter
+            .next()
+            .map_or(Ok(DepositedRoute::IterationBest), DepositedRoute::from_str)?;
+
         Ok(MaxMinPheromoneUpdater::new(
             route_estimate,
             evaporation_rate,
             best_route_p,
             target_num_of_steps as usize,
+            deposited_route,
         ))
     }
 }
@@ -110,6 +168,7 @@ impl WithGuidingConfig for MaxMinPheromoneUpdater {
             0.2,
             0.1,
             guide.num_of_steps_per_cycle,
+            DepositedRoute::IterationBest,
         ))
     }
 }
@@ -121,8 +180,12 @@ impl Display for MaxMinPheromoneUpdater {
             "MaxMin updater\n\t\
             route estimate:          {:>5}\n\t\
             evaporation rate:   {:>5.3}\n\t\
-            target steps:       {:>5.3}",
-            self.route_estimate, self.evaporation_rate, self.target_num_of_steps
+            target steps:       {:>5.3}\n\t\
+            deposited route:    {:?}",
+            self.route_estimate,
+            self.evaporation_rate,
+            self.target_num_of_steps,
+            self.deposited_route
         )
     }
 }
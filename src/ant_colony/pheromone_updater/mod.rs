@@ -1,26 +1,38 @@
 mod _tests;
 mod _union;
 mod average_pheromone_updater;
+mod best_route_memory;
 mod colony_pheromone_updater;
 mod constant_pheromone_updater;
 mod cyclical_pheromone_updater;
+mod elitist_pheromone_updater;
+mod hyper_cube_pheromone_updater;
+mod local_colony_pheromone_updater;
 mod maxmin_pheromone_updater;
+mod rank_pheromone_updater;
 
 use std::fmt::Display;
 use std::str::FromStr;
 
-use crate::ant_colony::graph::{RouteBatch, RouteBatchWithHoles, RouteCollection};
+use crate::ant_colony::graph::{
+    AdjacencyListEntry, RouteBatch, RouteBatchWithHoles, RouteCollection,
+};
 use crate::ant_colony::guiding_config::WithGuidingConfig;
 use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
 
 pub use _union::Updaters;
 pub use average_pheromone_updater::AveragePheromoneUpdater;
+pub use best_route_memory::BestRouteMemory;
 pub use colony_pheromone_updater::ColonyPheromoneUpdater;
 pub use constant_pheromone_updater::ConstantPheromoneUpdater;
 pub use cyclical_pheromone_updater::CyclicalPheromoneUpdater;
+pub use elitist_pheromone_updater::ElitistPheromoneUpdater;
+pub use hyper_cube_pheromone_updater::HyperCubePheromoneUpdater;
+pub use local_colony_pheromone_updater::LocalColonyPheromoneUpdater;
 pub use maxmin_pheromone_updater::MaxMinPheromoneUpdater;
+pub use rank_pheromone_updater::RankPheromoneUpdater;
 
-pub trait PheromoneUpdater: WithGuidingConfig + Display + Sized + FromStr {
+pub trait PheromoneUpdater: WithGuidingConfig + Display + Send + Sync + Sized + FromStr {
     fn get_initial_value(&self) -> PheromoneLevel;
 
     fn initialize(&self, init_pheromone: Pheromone, edges: &RouteBatch) -> Pheromone {
@@ -31,6 +43,28 @@ pub trait PheromoneUpdater: WithGuidingConfig + Display + Sized + FromStr {
         })
     }
 
+    /// Forgets what was learned in previous cycles, e.g. the best-so-far route.
+    /// Called by colonies when pheromone is reset between epochs,
+    /// reinitialization after stagnation keeps the best-so-far route
+    fn reset(&self) {}
+
+    /// Determines whether colony should call `on_after_ant_move`,
+    /// which makes ants of the step observe updates of ants with lower indices
+    fn has_local_update(&self) -> bool {
+        false
+    }
+
+    /// Called right after an ant takes an edge, in the order of ants' indices,
+    /// so the result does not depend on how many threads are used.
+    /// Should change the trail of the taken edge only
+    fn on_after_ant_move(
+        &self,
+        pheromone: Pheromone,
+        _taken_edge: &AdjacencyListEntry,
+    ) -> Pheromone {
+        pheromone
+    }
+
     fn on_after_step(&self, pheromone: Pheromone, _taken_edges: &RouteBatchWithHoles) -> Pheromone {
         pheromone
     }
@@ -46,7 +80,11 @@ pub enum UpdaterStringConfig {
     Average(String),
     Cyclical(String),
     Colony(String),
+    LocalColony(String),
     MaxMin(String),
+    Rank(String),
+    Elitist(String),
+    HyperCube(String),
 }
 
 impl FromStr for UpdaterStringConfig {
@@ -62,7 +100,11 @@ impl FromStr for UpdaterStringConfig {
             "avg" => Some(Self::Average(opts)),
             "cycle" => Some(Self::Cyclical(opts)),
             "colony" => Some(Self::Colony(opts)),
+            "acs" => Some(Self::LocalColony(opts)),
             "maxmin" => Some(Self::MaxMin(opts)),
+            "rank" => Some(Self::Rank(opts)),
+            "elitist" => Some(Self::Elitist(opts)),
+            "hcf" => Some(Self::HyperCube(opts)),
             _ => None,
         }
         .ok_or("Failed to parse pheromone updater type")
@@ -76,7 +118,11 @@ impl ToString for UpdaterStringConfig {
             Self::Average(opts) => format!("avg:{}", opts),
             Self::Cyclical(opts) => format!("cycle:{}", opts),
             Self::Colony(opts) => format!("colony:{}", opts),
+            Self::LocalColony(opts) => format!("acs:{}", opts),
             Self::MaxMin(opts) => format!("maxmin:{}", opts),
+            Self::Rank(opts) => format!("rank:{}", opts),
+            Self::Elitist(opts) => format!("elitist:{}", opts),
+            Self::HyperCube(opts) => format!("hcf:{}", opts),
         }
     }
 }
@@ -0,0 +1,172 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::ant_colony::graph::{Route, RouteCollection};
+use crate::ant_colony::guiding_config::{GuidingConfig, WithGuidingConfig};
+use crate::ant_colony::pheromone::{Pheromone, PheromoneLevel};
+
+use super::{BestRouteMemory, PheromoneUpdater};
+
+/// Rank-based Ant System (`Bullnheimer1997ANV`).
+///
+/// After all ants finish whole cycle, decays the pheromone and lets only
+/// the w-1 cheapest feasible routes of the cycle deposit, each weighted by its rank (w - r).
+/// Additionally, the best-so-far route deposits with the highest weight w.
+///
+/// Does not perform updates after each step.
+pub struct RankPheromoneUpdater {
+    initial_value: f32,
+    n_ranked: usize,
+    evaporation_rate: f32,
+    best_so_far: BestRouteMemory,
+}
+
+impl RankPheromoneUpdater {
+    pub fn new(initial_value: f32, n_ranked: usize, evaporation_rate: f32) -> Self {
+        RankPheromoneUpdater {
+            initial_value,
+            n_ranked: n_ranked.max(1),
+            evaporation_rate,
+            best_so_far: BestRouteMemory::new(),
+        }
+    }
+
+    /// Initial value follows `Bullnheimer1997ANV`, tau_0 = 0.5 * w * (w - 1) / (rho * L)
+    pub fn with_route_estimate(
+        n_ranked: usize,
+        evaporation_rate: f32,
+        route_estimate: f32,
+    ) -> Self {
+        let initial_value = 0.5 * (n_ranked * n_ranked.saturating_sub(1)) as f32
+            / (evaporation_rate * route_estimate);
+
+        RankPheromoneUpdater::new(initial_value, n_ranked, evaporation_rate)
+    }
+
+    /// Options in format `<w>,<evaporation>[,<route_estimate>]`,
+    /// the route estimate is derived from the guide when omitted
+    pub fn from_str_and_guide(
+        opts: &str,
+        maybe_guide: Option<&GuidingConfig>,
+    ) -> Result<Self, &'static str> {
+        let error = "Failed to parse opts of RankPheromoneUpdater";
+
+        let values = opts
+            .split(',')
+            .map(str::parse::<f32>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error)?;
+
+        let (n_ranked, evaporation_rate, route_estimate) = match values.as_slice() {
+            [n_ranked, evaporation_rate] => maybe_guide
+                .map(|guide| (*n_ranked, *evaporation_rate, Self::estimate_route(guide)))
+                .ok_or("RankPheromoneUpdater needs route estimate without guiding config")?,
+            [n_ranked, evaporation_rate, route_estimate] => {
+                (*n_ranked, *evaporation_rate, *route_estimate)
+            }
+            _ => return Err(error),
+        };
+
+        Ok(RankPheromoneUpdater::with_route_estimate(
+            n_ranked as usize,
+            evaporation_rate,
+            route_estimate,
+        ))
+    }
+
+    fn estimate_route(guide: &GuidingConfig) -> f32 {
+        guide
+            .graph_cycle_estimate
+            .unwrap_or(guide.graph_avg_distance * guide.num_of_steps_per_cycle as f32)
+    }
+
+    pub fn get_best_so_far(&self) -> &BestRouteMemory {
+        &self.best_so_far
+    }
+
+    fn deposit(pheromone: Pheromone, route: &Route, increment: f32) -> Pheromone {
+        route
+            .get_edges()
+            .iter()
+            .fold(pheromone, |updated_pheromone, edge| {
+                updated_pheromone.increase_pheromone_value(edge, increment)
+            })
+    }
+}
+
+impl PheromoneUpdater for RankPheromoneUpdater {
+    fn get_initial_value(&self) -> PheromoneLevel {
+        self.initial_value
+    }
+
+    fn reset(&self) {
+        self.best_so_far.forget();
+    }
+
+    fn on_after_cycle(&self, pheromone: Pheromone, taken_routes: &RouteCollection) -> Pheromone {
+        let decay = 1.0 - self.evaporation_rate;
+        let decayed_pheromone = pheromone.scale_all_pheromone_values(decay);
+
+        let target_num_of_steps = taken_routes.get_max_route_length();
+        let best_routes =
+            taken_routes.get_n_best_routes(self.n_ranked.max(2) - 1, target_num_of_steps);
+
+        let best_so_far = best_routes
+            .first()
+            .map(|(best_route, route_cost)| self.best_so_far.remember(best_route, *route_cost));
+
+        let ranked_routes = best_routes.iter().take(self.n_ranked - 1);
+
+        let rank_updated_pheromone = ranked_routes.zip(1..).fold(
+            decayed_pheromone,
+            |updated_pheromone, ((route, route_cost), rank)| {
+                let increment = (self.n_ranked - rank) as f32 / route_cost;
+
+                Self::deposit(updated_pheromone, route, increment)
+            },
+        );
+
+        match best_so_far.or_else(|| self.best_so_far.get_best()) {
+            Option::Some((route, route_cost)) => {
+                let increment = self.n_ranked as f32 / route_cost;
+
+                Self::deposit(rank_updated_pheromone, &route, increment)
+            }
+            Option::None => rank_updated_pheromone,
+        }
+    }
+}
+
+impl FromStr for RankPheromoneUpdater {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        RankPheromoneUpdater::from_str_and_guide(opts, Option::None)
+    }
+}
+
+impl WithGuidingConfig for RankPheromoneUpdater {
+    fn guided(guide: &GuidingConfig) -> Option<Self> {
+        // values based on `Bullnheimer1997ANV`
+        let n_ranked = 6;
+        let evaporation_rate = 0.1;
+
+        Some(RankPheromoneUpdater::with_route_estimate(
+            n_ranked,
+            evaporation_rate,
+            Self::estimate_route(guide),
+        ))
+    }
+}
+
+impl Display for RankPheromoneUpdater {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Pheromone updater (Rank)\n\t\
+            initial_value: {:>5}\n\t\
+            ranked routes: {:>5}\n\t\
+            evaporation:   {:>5.3}",
+            self.initial_value, self.n_ranked, self.evaporation_rate
+        )
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(test)]
+mod problem_tests {
+    use std::collections::HashMap;
+
+    use crate::ant_colony::ant::Ant;
+    use crate::ant_colony::graph::{mock_graph_vector, AdjacencyListEntry, Graph, Route};
+
+    use super::super::{
+        Problem, Problems, SequentialOrdering, ShortestPath, TravellingSalesman, VehicleRouting,
+    };
+
+    fn get_graph() -> Graph {
+        Graph::from_node_vector(mock_graph_vector())
+    }
+
+    fn get_destinations(edges: Vec<AdjacencyListEntry>) -> Vec<u32> {
+        edges.iter().map(|edge| edge.to).collect()
+    }
+
+    #[test]
+    fn tsp_closes_the_cycle_once_all_nodes_were_visited() {
+        let graph = get_graph();
+        let problem = TravellingSalesman;
+
+        let ant = Ant::new(0).move_to_node(1);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![2, 3]
+        );
+
+        let ant = ant.move_to_node(2).move_to_node(3);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![0]
+        );
+        assert!(!problem.is_complete(&ant, &graph));
+
+        let ant = ant.move_to_node(0);
+        assert!(problem.is_complete(&ant, &graph));
+    }
+
+    #[test]
+    fn shortest_path_stops_at_the_target() {
+        let graph = get_graph();
+        let problem = ShortestPath::new(0, 3);
+
+        let ant = Ant::new(0).move_to_node(1);
+        let complete_route = Route::new(2)
+            .add_step(AdjacencyListEntry::new(0, 1, 3.0))
+            .add_step(AdjacencyListEntry::new(1, 3, 4.0));
+        let incomplete_route = Route::new(1).add_step(AdjacencyListEntry::new(0, 1, 3.0));
+
+        assert_eq!(problem.get_start_nodes(&graph), vec![0]);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![2, 3]
+        );
+        assert!(problem
+            .get_feasible_edges(&ant.move_to_node(3), &graph)
+            .is_empty());
+        assert_eq!(problem.calc_route_cost(&complete_route, 3), 7.0);
+        assert_eq!(problem.calc_route_cost(&incomplete_route, 3), f32::INFINITY);
+    }
+
+    #[test]
+    fn sequential_ordering_respects_precedences() {
+        let graph = get_graph();
+        let problem = SequentialOrdering::new(&[(2, 1)]);
+
+        let ant = Ant::new(0);
+        assert_eq!(problem.get_start_nodes(&graph), vec![0, 2, 3]);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![2, 3]
+        );
+
+        let ant = ant.move_to_node(2);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![1, 3]
+        );
+
+        let ant = ant.move_to_node(1).move_to_node(3);
+        assert!(problem.get_feasible_edges(&ant, &graph).is_empty());
+        assert!(problem.is_complete(&ant, &graph));
+    }
+
+    #[test]
+    fn vehicle_routing_returns_to_the_depot_when_capacity_is_exceeded() {
+        let graph = get_graph();
+        let problem = VehicleRouting::new(0, 2.0, HashMap::new());
+
+        let ant = Ant::new(0).move_to_node(1).move_to_node(2);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![0]
+        );
+
+        let ant = ant.move_to_node(0);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![3]
+        );
+        assert!(!problem.is_complete(&ant, &graph));
+
+        let ant = ant.move_to_node(3);
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![0]
+        );
+
+        let ant = ant.move_to_node(0);
+        assert!(problem.get_feasible_edges(&ant, &graph).is_empty());
+        assert!(problem.is_complete(&ant, &graph));
+    }
+
+    #[test]
+    fn vehicle_routing_skips_customers_with_too_large_demand() {
+        let graph = get_graph();
+        let demands = map!(1 => 2.0, 2 => 1.0, 3 => 3.0);
+        let problem = VehicleRouting::new(0, 3.0, demands);
+
+        let ant = Ant::new(0).move_to_node(1);
+
+        assert_eq!(
+            get_destinations(problem.get_feasible_edges(&ant, &graph)),
+            vec![2]
+        );
+    }
+
+    #[test]
+    fn it_parses_problems() {
+        assert_eq!("tsp".parse(), Ok(Problems::default()));
+        assert_eq!(
+            "path:0,3".parse(),
+            Ok(Problems::ShortestPath(ShortestPath::new(0, 3)))
+        );
+        assert_eq!(
+            "sop:2<1,0<3".parse(),
+            Ok(Problems::SequentialOrdering(SequentialOrdering::new(&[
+                (2, 1),
+                (0, 3)
+            ])))
+        );
+        assert_eq!(
+            "cvrp:0,10".parse(),
+            Ok(Problems::VehicleRouting(VehicleRouting::new(
+                0,
+                10.0,
+                HashMap::new()
+            )))
+        );
+        assert!("path:0".parse::<Problems>().is_err());
+        assert!("sop:2-1".parse::<Problems>().is_err());
+        assert!("cvrp:0.5,10".parse::<Problems>().is_err());
+        assert!("cvrp:-1,10".parse::<Problems>().is_err());
+    }
+
+    #[test]
+    fn incomplete_solutions_cost_infinitely_much() {
+        let graph = get_graph();
+        let route = Route::new(1).add_step(AdjacencyListEntry::new(0, 1, 3.0));
+        let ant = Ant::new(0).move_to_node(1);
+
+        let sop = SequentialOrdering::new(&[]);
+        let cvrp = VehicleRouting::new(0, 2.0, HashMap::new());
+
+        assert_eq!(
+            sop.calc_solution_cost(&ant, &route, &graph, 1),
+            f32::INFINITY
+        );
+        assert_eq!(
+            cvrp.calc_solution_cost(&ant, &route, &graph, 1),
+            f32::INFINITY
+        );
+
+        // partial tours limited by the number of steps are valid solutions of TSP
+        assert_eq!(
+            TravellingSalesman.calc_solution_cost(&ant, &route, &graph, 2),
+            6.0
+        );
+    }
+}
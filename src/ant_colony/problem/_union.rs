@@ -0,0 +1,120 @@
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId, Route};
+
+use super::{Problem, SequentialOrdering, ShortestPath, TravellingSalesman, VehicleRouting};
+
+/// using an enum instead of run-time
+/// polymorhism to avoid cost of dynamic dispatch
+#[derive(Debug, Clone, PartialEq)]
+pub enum Problems {
+    TravellingSalesman(TravellingSalesman),
+    ShortestPath(ShortestPath),
+    SequentialOrdering(SequentialOrdering),
+    VehicleRouting(VehicleRouting),
+}
+
+impl Default for Problems {
+    fn default() -> Self {
+        Problems::TravellingSalesman(TravellingSalesman)
+    }
+}
+
+impl Problem for Problems {
+    fn get_start_nodes<'a>(&self, graph: &'a Graph) -> Cow<'a, [NodeId]> {
+        match self {
+            Problems::TravellingSalesman(problem) => problem.get_start_nodes(graph),
+            Problems::ShortestPath(problem) => problem.get_start_nodes(graph),
+            Problems::SequentialOrdering(problem) => problem.get_start_nodes(graph),
+            Problems::VehicleRouting(problem) => problem.get_start_nodes(graph),
+        }
+    }
+
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
+        match self {
+            Problems::TravellingSalesman(problem) => problem.get_feasible_edges(ant, graph),
+            Problems::ShortestPath(problem) => problem.get_feasible_edges(ant, graph),
+            Problems::SequentialOrdering(problem) => problem.get_feasible_edges(ant, graph),
+            Problems::VehicleRouting(problem) => problem.get_feasible_edges(ant, graph),
+        }
+    }
+
+    fn is_complete(&self, ant: &Ant, graph: &Graph) -> bool {
+        match self {
+            Problems::TravellingSalesman(problem) => problem.is_complete(ant, graph),
+            Problems::ShortestPath(problem) => problem.is_complete(ant, graph),
+            Problems::SequentialOrdering(problem) => problem.is_complete(ant, graph),
+            Problems::VehicleRouting(problem) => problem.is_complete(ant, graph),
+        }
+    }
+
+    fn calc_route_cost(&self, route: &Route, target_num_of_steps: usize) -> f32 {
+        match self {
+            Problems::TravellingSalesman(problem) => {
+                problem.calc_route_cost(route, target_num_of_steps)
+            }
+            Problems::ShortestPath(problem) => problem.calc_route_cost(route, target_num_of_steps),
+            Problems::SequentialOrdering(problem) => {
+                problem.calc_route_cost(route, target_num_of_steps)
+            }
+            Problems::VehicleRouting(problem) => {
+                problem.calc_route_cost(route, target_num_of_steps)
+            }
+        }
+    }
+
+    fn calc_solution_cost(
+        &self,
+        ant: &Ant,
+        route: &Route,
+        graph: &Graph,
+        target_num_of_steps: usize,
+    ) -> f32 {
+        match self {
+            Problems::TravellingSalesman(problem) => {
+                problem.calc_solution_cost(ant, route, graph, target_num_of_steps)
+            }
+            Problems::ShortestPath(problem) => {
+                problem.calc_solution_cost(ant, route, graph, target_num_of_steps)
+            }
+            Problems::SequentialOrdering(problem) => {
+                problem.calc_solution_cost(ant, route, graph, target_num_of_steps)
+            }
+            Problems::VehicleRouting(problem) => {
+                problem.calc_solution_cost(ant, route, graph, target_num_of_steps)
+            }
+        }
+    }
+}
+
+impl FromStr for Problems {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut config_iter = s.splitn(2, ':');
+        let name = config_iter.next().unwrap_or_default();
+        let opts = config_iter.next().unwrap_or_default();
+
+        match name {
+            "tsp" => Ok(Problems::TravellingSalesman(TravellingSalesman)),
+            "path" => ShortestPath::from_str(opts).map(Problems::ShortestPath),
+            "sop" => SequentialOrdering::from_str(opts).map(Problems::SequentialOrdering),
+            "cvrp" => VehicleRouting::from_str(opts).map(Problems::VehicleRouting),
+            _ => Err("Failed to parse problem type"),
+        }
+    }
+}
+
+impl Display for Problems {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problems::TravellingSalesman(problem) => problem.fmt(f),
+            Problems::ShortestPath(problem) => problem.fmt(f),
+            Problems::SequentialOrdering(problem) => problem.fmt(f),
+            Problems::VehicleRouting(problem) => problem.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+mod _tests;
+mod _union;
+mod sequential_ordering;
+mod shortest_path;
+mod travelling_salesman;
+mod vehicle_routing;
+
+use std::borrow::Cow;
+use std::fmt::Display;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId, Route};
+
+pub use _union::Problems;
+pub use sequential_ordering::SequentialOrdering;
+pub use shortest_path::ShortestPath;
+pub use travelling_salesman::TravellingSalesman;
+pub use vehicle_routing::VehicleRouting;
+
+/// Construction rules of the solved problem.
+///
+/// Defines where ants start, which moves are feasible for an ant
+/// and how good is the route it constructed.
+/// Dispatchers only choose among feasible edges, so the same colony
+/// can solve any problem implementing the trait.
+pub trait Problem: Display + Send + Sync {
+    /// Nodes ants are placed on at the beginning of each cycle
+    fn get_start_nodes<'a>(&self, graph: &'a Graph) -> Cow<'a, [NodeId]> {
+        Cow::Borrowed(graph.get_node_ids())
+    }
+
+    /// Edges the ant may take next, empty once the ant has nowhere (or no need) to go
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry>;
+
+    /// Whether the ant constructed a complete solution
+    fn is_complete(&self, ant: &Ant, graph: &Graph) -> bool;
+
+    /// Cost of the route, lower is better.
+    /// By default, distances are adjusted, so incomplete routes are not favoured.
+    fn calc_route_cost(&self, route: &Route, target_num_of_steps: usize) -> f32 {
+        route.get_adjusted_distance(target_num_of_steps)
+    }
+
+    /// Cost of the route constructed by the ant.
+    /// Incomplete solutions are infeasible, so they cost infinitely much
+    /// and are neither reinforced by updaters nor kept as the best route.
+    fn calc_solution_cost(
+        &self,
+        ant: &Ant,
+        route: &Route,
+        graph: &Graph,
+        target_num_of_steps: usize,
+    ) -> f32 {
+        iif!(
+            self.is_complete(ant, graph),
+            self.calc_route_cost(route, target_num_of_steps),
+            f32::INFINITY
+        )
+    }
+}
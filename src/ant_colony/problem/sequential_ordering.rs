@@ -0,0 +1,93 @@
+use itertools::Itertools;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
+
+use super::Problem;
+
+/// Sequential Ordering Problem, Hamiltonian path respecting precedence constraints.
+///
+/// A node can be visited only after all of its predecessors were visited,
+/// ants start at nodes that have no predecessors.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SequentialOrdering {
+    predecessors: HashMap<NodeId, Vec<NodeId>>,
+}
+
+impl SequentialOrdering {
+    /// Each pair `(a, b)` requires `a` to be visited before `b`
+    pub fn new(precedences: &[(NodeId, NodeId)]) -> Self {
+        let predecessors = precedences
+            .iter()
+            .map(|(before, after)| (*after, *before))
+            .into_group_map();
+
+        SequentialOrdering { predecessors }
+    }
+
+    fn is_allowed(&self, ant: &Ant, node_id: &NodeId) -> bool {
+        self.predecessors.get(node_id).map_or(true, |predecessors| {
+            predecessors.iter().all(|before| ant.has_visited(before))
+        })
+    }
+}
+
+impl Problem for SequentialOrdering {
+    fn get_start_nodes<'a>(&self, graph: &'a Graph) -> Cow<'a, [NodeId]> {
+        graph
+            .get_node_ids()
+            .iter()
+            .filter(|node_id| !self.predecessors.contains_key(node_id))
+            .cloned()
+            .collect()
+    }
+
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
+        graph
+            .get_adjacent_edges(&ant.current_node)
+            .iter()
+            .filter(|edge| !ant.has_visited(&edge.to) && self.is_allowed(ant, &edge.to))
+            .cloned()
+            .collect()
+    }
+
+    fn is_complete(&self, ant: &Ant, graph: &Graph) -> bool {
+        ant.get_path().len() == graph.get_amount_of_nodes()
+    }
+}
+
+/// Precedences in format `<a><<b>,<c><<d>`, e.g. `0<3,2<5`
+impl FromStr for SequentialOrdering {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse opts of SequentialOrdering";
+
+        let precedences = opts
+            .split(',')
+            .filter(|precedence| !precedence.is_empty())
+            .map(|precedence| {
+                precedence
+                    .splitn(2, '<')
+                    .map(str::parse::<NodeId>)
+                    .filter_map(Result::ok)
+                    .collect_tuple()
+                    .ok_or(error)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SequentialOrdering::new(&precedences))
+    }
+}
+
+impl Display for SequentialOrdering {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let n_precedences: usize = self.predecessors.values().map(Vec::len).sum();
+
+        write!(f, "sequential ordering ({} precedences)", n_precedences)
+    }
+}
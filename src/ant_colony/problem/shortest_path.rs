@@ -0,0 +1,78 @@
+use itertools::Itertools;
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId, Route};
+
+use super::Problem;
+
+/// Open path from the source to the target node.
+///
+/// All ants start at the source and stop once they reach the target,
+/// routes that did not reach it are infinitely long.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShortestPath {
+    source: NodeId,
+    target: NodeId,
+}
+
+impl ShortestPath {
+    pub fn new(source: NodeId, target: NodeId) -> Self {
+        ShortestPath { source, target }
+    }
+}
+
+impl Problem for ShortestPath {
+    fn get_start_nodes<'a>(&self, _graph: &'a Graph) -> Cow<'a, [NodeId]> {
+        Cow::Owned(vec![self.source])
+    }
+
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
+        if ant.current_node == self.target {
+            return Vec::new();
+        }
+
+        graph
+            .get_adjacent_edges(&ant.current_node)
+            .iter()
+            .filter(|edge| !ant.has_visited(&edge.to))
+            .cloned()
+            .collect()
+    }
+
+    fn is_complete(&self, ant: &Ant, _graph: &Graph) -> bool {
+        ant.current_node == self.target
+    }
+
+    fn calc_route_cost(&self, route: &Route, _target_num_of_steps: usize) -> f32 {
+        let reached_target = route
+            .get_edges()
+            .last()
+            .map_or(false, |edge| edge.to == self.target);
+
+        iif!(reached_target, route.get_distance(), f32::INFINITY)
+    }
+}
+
+impl FromStr for ShortestPath {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let (source, target): (NodeId, NodeId) = opts
+            .splitn(2, ',')
+            .map(str::parse)
+            .filter_map(Result::ok)
+            .collect_tuple()
+            .ok_or("Failed to parse opts of ShortestPath")?;
+
+        Ok(ShortestPath::new(source, target))
+    }
+}
+
+impl Display for ShortestPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "shortest path from {} to {}", self.source, self.target)
+    }
+}
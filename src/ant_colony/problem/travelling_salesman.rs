@@ -0,0 +1,59 @@
+use std::fmt::Display;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, Route};
+
+use super::Problem;
+
+/// Hamiltonian cycle, ants visit every node once and go back to the initial one
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct TravellingSalesman;
+
+impl Problem for TravellingSalesman {
+    #[cfg_attr(feature = "profiler", flame)]
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
+        let adjacent_edges = graph.get_adjacent_edges(&ant.current_node);
+
+        let possible_next_edges = adjacent_edges
+            .iter()
+            .filter(|edge| !ant.has_visited(&edge.to))
+            .map(|edge| edge.to_owned())
+            .collect::<Vec<_>>();
+
+        if !possible_next_edges.is_empty() {
+            return possible_next_edges;
+        }
+
+        // if there are no nodes that have not been visited,
+        // it might be because ant has travelled all possible nodes,
+        // here, we allow the ant to go back to the initial node to close the cycle
+        let edge_leading_to_inital_node = adjacent_edges
+            .iter()
+            .find(|edge| edge.to == ant.inital_node)
+            .cloned();
+
+        edge_leading_to_inital_node.map_or(possible_next_edges, |edge| vec![edge])
+    }
+
+    fn is_complete(&self, ant: &Ant, graph: &Graph) -> bool {
+        ant.current_node == ant.inital_node && ant.get_path().len() > graph.get_amount_of_nodes()
+    }
+
+    /// Cycles are usually limited by the number of steps (e.g. on image graphs),
+    /// such partial tours are valid solutions, their adjusted distances keep them comparable
+    fn calc_solution_cost(
+        &self,
+        _ant: &Ant,
+        route: &Route,
+        _graph: &Graph,
+        target_num_of_steps: usize,
+    ) -> f32 {
+        self.calc_route_cost(route, target_num_of_steps)
+    }
+}
+
+impl Display for TravellingSalesman {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "travelling salesman")
+    }
+}
@@ -0,0 +1,108 @@
+use itertools::Itertools;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::ant::Ant;
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
+
+use super::Problem;
+
+/// Capacitated Vehicle Routing Problem.
+///
+/// Ants start at the depot and visit customers until the load would exceed the capacity,
+/// then they go back to the depot and start a new tour.
+/// The solution is complete once all customers were served and the ant is back at the depot.
+/// Customers without a given demand require a single unit.
+#[derive(Debug, Clone, PartialEq)]
+pub struct VehicleRouting {
+    depot: NodeId,
+    capacity: f32,
+    demands: HashMap<NodeId, f32>,
+}
+
+impl VehicleRouting {
+    pub fn new(depot: NodeId, capacity: f32, demands: HashMap<NodeId, f32>) -> Self {
+        VehicleRouting {
+            depot,
+            capacity,
+            demands,
+        }
+    }
+
+    fn get_demand(&self, node_id: &NodeId) -> f32 {
+        self.demands.get(node_id).cloned().unwrap_or(1.0)
+    }
+
+    /// Load delivered since the last visit in the depot
+    fn calc_current_load(&self, ant: &Ant) -> f32 {
+        ant.get_path()
+            .iter()
+            .rev()
+            .take_while(|node_id| **node_id != self.depot)
+            .map(|node_id| self.get_demand(node_id))
+            .sum()
+    }
+}
+
+impl Problem for VehicleRouting {
+    fn get_start_nodes<'a>(&self, _graph: &'a Graph) -> Cow<'a, [NodeId]> {
+        Cow::Owned(vec![self.depot])
+    }
+
+    fn get_feasible_edges(&self, ant: &Ant, graph: &Graph) -> Vec<AdjacencyListEntry> {
+        let adjacent_edges = graph.get_adjacent_edges(&ant.current_node);
+        let remaining_capacity = self.capacity - self.calc_current_load(ant);
+
+        let customer_edges = adjacent_edges
+            .iter()
+            .filter(|edge| edge.to != self.depot && !ant.has_visited(&edge.to))
+            .filter(|edge| self.get_demand(&edge.to) <= remaining_capacity)
+            .cloned()
+            .collect::<Vec<_>>();
+
+        if !customer_edges.is_empty() || ant.current_node == self.depot {
+            return customer_edges;
+        }
+
+        adjacent_edges
+            .iter()
+            .find(|edge| edge.to == self.depot)
+            .cloned()
+            .map_or(customer_edges, |edge| vec![edge])
+    }
+
+    fn is_complete(&self, ant: &Ant, graph: &Graph) -> bool {
+        ant.current_node == self.depot
+            && graph
+                .get_node_ids()
+                .iter()
+                .all(|node_id| ant.has_visited(node_id))
+    }
+}
+
+/// Unit demands, in format `<depot>,<capacity>`
+impl FromStr for VehicleRouting {
+    type Err = &'static str;
+
+    fn from_str(opts: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse opts of VehicleRouting";
+
+        let (depot, capacity) = opts.splitn(2, ',').collect_tuple().ok_or(error)?;
+        let depot = depot.parse::<NodeId>().map_err(|_| error)?;
+        let capacity = capacity.parse::<f32>().map_err(|_| error)?;
+
+        Ok(VehicleRouting::new(depot, capacity, HashMap::new()))
+    }
+}
+
+impl Display for VehicleRouting {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "vehicle routing (depot {}, capacity {})",
+            self.depot, self.capacity
+        )
+    }
+}
@@ -0,0 +1,166 @@
+#[cfg(test)]
+mod stopping_criterion_tests {
+    use std::time::Duration;
+
+    use crate::ant_colony::graph::{AdjacencyListEntry, Route};
+    use crate::common::utils::TimeLimit;
+
+    use super::super::{CycleSummary, StoppingCriterion};
+
+    fn mock_cycle(
+        cycle_idx: usize,
+        shortest_dist: f32,
+        best_route_cost: Option<f32>,
+        pheromone_variance: f32,
+    ) -> CycleSummary {
+        let best_route = best_route_cost.map(|cost| {
+            let route = Route::new(1).add_step(AdjacencyListEntry::new(0, 1, cost));
+
+            (route, cost)
+        });
+
+        CycleSummary {
+            cycle_idx,
+            exec_time_ms: 0,
+            shortest_dist: Option::Some(shortest_dist),
+            shortest_path_length: Option::Some(1),
+            avg_dist: shortest_dist,
+            n_non_empty_edges: 0,
+            ratio_of_incomplete_routes: 0.0,
+            pheromone_variance,
+            shortest_route: Option::None,
+            best_route,
+            reinitialized: false,
+        }
+    }
+
+    fn mock_history() -> Vec<CycleSummary> {
+        vec![
+            mock_cycle(0, 10.0, Option::Some(10.0), 0.5),
+            mock_cycle(1, 8.0, Option::Some(8.0), 0.3),
+            mock_cycle(2, 8.0, Option::Some(8.0), 0.2),
+            mock_cycle(3, 9.0, Option::Some(9.0), 0.1),
+        ]
+    }
+
+    #[test]
+    fn it_checks_each_criterion() {
+        let history = mock_history();
+        let elapsed = Duration::from_millis(100);
+
+        let limit = |millis| TimeLimit(Duration::from_millis(millis));
+
+        assert!(StoppingCriterion::Cycles(4).is_met(&history, elapsed));
+        assert!(!StoppingCriterion::Cycles(5).is_met(&history, elapsed));
+        assert!(StoppingCriterion::NoImprovement(2).is_met(&history, elapsed));
+        assert!(!StoppingCriterion::NoImprovement(3).is_met(&history, elapsed));
+        assert!(StoppingCriterion::TimeLimit(limit(100)).is_met(&history, elapsed));
+        assert!(!StoppingCriterion::TimeLimit(limit(101)).is_met(&history, elapsed));
+        assert!(StoppingCriterion::TargetDistance(8.0).is_met(&history, elapsed));
+        assert!(!StoppingCriterion::TargetDistance(7.5).is_met(&history, elapsed));
+        assert!(StoppingCriterion::MinPheromoneVariance(0.15).is_met(&history, elapsed));
+        assert!(!StoppingCriterion::MinPheromoneVariance(0.1).is_met(&history, elapsed));
+    }
+
+    #[test]
+    fn it_ignores_short_routes_without_cost_for_target_distance() {
+        // short, but incomplete route, so the cycle has no feasible route
+        let history = vec![mock_cycle(0, 1.0, Option::None, 0.5)];
+
+        assert!(!StoppingCriterion::TargetDistance(5.0).is_met(&history, Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn it_compares_target_distance_with_routes_of_the_epoch_only() {
+        // history of an epoch starts empty, routes of previous epochs do not count
+        let history = mock_history();
+
+        assert!(StoppingCriterion::TargetDistance(8.0).is_met(&history, Duration::from_secs(0)));
+        assert!(
+            !StoppingCriterion::TargetDistance(8.0).is_met(&history[..1], Duration::from_secs(0))
+        );
+        assert!(!StoppingCriterion::TargetDistance(8.0).is_met(&[], Duration::from_secs(0)));
+    }
+
+    #[test]
+    fn it_reports_the_first_criterion_met() {
+        let history = mock_history();
+        let criteria = [
+            StoppingCriterion::Cycles(10),
+            StoppingCriterion::TargetDistance(9.5),
+            StoppingCriterion::NoImprovement(2),
+        ];
+
+        let result = StoppingCriterion::find_met(&criteria, &history, Duration::from_secs(0));
+
+        assert_eq!(
+            result,
+            Option::Some(&StoppingCriterion::TargetDistance(9.5))
+        );
+        assert_eq!(
+            StoppingCriterion::find_met(&criteria[..1], &history, Duration::from_secs(0)),
+            Option::None
+        );
+    }
+}
+
+#[cfg(test)]
+mod run_summary_tests {
+    use crate::ant_colony::graph::{AdjacencyListEntry, Route};
+
+    use super::super::{EpochSummary, EpochTransition, RunSummary};
+
+    fn mock_epoch(epoch_idx: usize, distance_and_cost: Option<(f32, f32)>) -> EpochSummary {
+        let best_route = distance_and_cost.map(|(distance, cost)| {
+            let route = Route::new(1).add_step(AdjacencyListEntry::new(0, 1, distance));
+
+            (route, cost)
+        });
+
+        EpochSummary {
+            epoch_idx,
+            exec_time_ms: 0,
+            best_route,
+            best_route_cycle_idx: Option::Some(0),
+            stopped_by: Option::None,
+        }
+    }
+
+    #[test]
+    fn it_selects_the_best_epoch() {
+        let summary = RunSummary {
+            epochs: vec![
+                mock_epoch(1, Option::Some((5.0, 5.0))),
+                mock_epoch(2, Option::None),
+                mock_epoch(3, Option::Some((3.0, 3.0))),
+                mock_epoch(4, Option::Some((4.0, 4.0))),
+            ],
+        };
+
+        let result = summary.get_best_epoch().map(|epoch| epoch.epoch_idx);
+
+        assert_eq!(result, Option::Some(3));
+    }
+
+    #[test]
+    fn it_selects_the_best_epoch_by_cost_of_route() {
+        // shorter route of the first epoch is penalized by the problem
+        let summary = RunSummary {
+            epochs: vec![
+                mock_epoch(1, Option::Some((2.0, 12.0))),
+                mock_epoch(2, Option::Some((4.0, 4.0))),
+            ],
+        };
+
+        let result = summary.get_best_epoch().map(|epoch| epoch.epoch_idx);
+
+        assert_eq!(result, Option::Some(2));
+    }
+
+    #[test]
+    fn it_parses_epoch_transition() {
+        assert_eq!("keep".parse(), Ok(EpochTransition::Keep));
+        assert_eq!("reset".parse(), Ok(EpochTransition::Reset));
+        assert!("restart".parse::<EpochTransition>().is_err());
+    }
+}
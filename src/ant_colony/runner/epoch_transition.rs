@@ -0,0 +1,38 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Determines what happens with trails when the next epoch starts
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum EpochTransition {
+    /// next epoch continues with trails of the previous one
+    Keep,
+    /// trails are reset to their initial values, i.e. the search is restarted
+    Reset,
+}
+
+impl Default for EpochTransition {
+    fn default() -> Self {
+        Self::Keep
+    }
+}
+
+impl FromStr for EpochTransition {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "keep" => Ok(Self::Keep),
+            "reset" => Ok(Self::Reset),
+            _ => Err("Failed to parse epoch transition"),
+        }
+    }
+}
+
+impl Display for EpochTransition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Keep => write!(f, "keep"),
+            Self::Reset => write!(f, "reset"),
+        }
+    }
+}
@@ -1,6 +1,11 @@
+mod _tests;
+mod epoch_transition;
+mod stopping_criterion;
 mod summary;
 
 use std::rc::Rc;
+use std::sync::Arc;
+use std::time::Instant;
 
 use crate::ant_colony::colony::Colony;
 use crate::ant_colony::graph::{Graph, Route};
@@ -8,7 +13,9 @@ use crate::ant_colony::pheromone_reader::PheromoneReader;
 use crate::common::cli_output::CliOutput;
 use crate::common::utils::{compare_float, measure, produce_until};
 
-pub use summary::{CycleSummary, EpochSummary};
+pub use epoch_transition::EpochTransition;
+pub use stopping_criterion::StoppingCriterion;
+pub use summary::{CycleSummary, EpochSummary, RunSummary};
 
 pub struct ColonyRunner<C, IO>
 where
@@ -16,7 +23,7 @@ where
     IO: CliOutput,
 {
     colony: C,
-    graph: Rc<Graph>,
+    graph: Arc<Graph>,
     io: Rc<IO>,
     cycle_history: Vec<CycleSummary>,
     epoch_history: Vec<EpochSummary>,
@@ -27,7 +34,7 @@ where
     C: Colony,
     IO: CliOutput,
 {
-    pub fn new(colony: C, graph: Rc<Graph>, io: Rc<IO>) -> Self {
+    pub fn new(colony: C, graph: Arc<Graph>, io: Rc<IO>) -> Self {
         ColonyRunner {
             colony,
             graph,
@@ -57,7 +64,38 @@ where
         self.last_cycle_summary().zip(self.last_epoch_summary())
     }
 
+    pub fn run_summary(&self) -> RunSummary {
+        RunSummary {
+            epochs: self.epoch_history.clone(),
+        }
+    }
+
+    /// Trains multiple epochs, each one until any of the criteria is met
+    pub fn train_epochs_until(
+        self,
+        n_epochs: usize,
+        criteria: &[StoppingCriterion],
+        transition: EpochTransition,
+    ) -> Self {
+        let runner = (0..n_epochs).fold(self, |runner, epoch_idx| {
+            let should_reset = epoch_idx > 0 && transition == EpochTransition::Reset;
+
+            iif!(should_reset, runner.reset_pheromone(), runner).train_until(criteria)
+        });
+
+        if n_epochs > 1 {
+            runner.io.print(&runner.run_summary());
+        }
+
+        runner
+    }
+
     pub fn train_n_until_no_improvement(self, n_until: usize) -> Self {
+        self.train_until(&[StoppingCriterion::NoImprovement(n_until)])
+    }
+
+    /// Trains single epoch, until any of the criteria is met
+    pub fn train_until(self, criteria: &[StoppingCriterion]) -> Self {
         let ColonyRunner {
             colony: init_colony,
             graph,
@@ -66,22 +104,27 @@ where
             mut epoch_history,
         } = self;
 
+        let start = Instant::now();
         let (colony, next_cycle_history) = produce_until(
             (init_colony, Vec::new()),
             |(colony, history), idx| Self::train_cycle(colony, io.as_ref(), history, idx),
-            |(_, history), _| Self::had_no_improvement_in_n_last_steps(history, n_until),
+            |(_, history), _| {
+                StoppingCriterion::find_met(criteria, history, start.elapsed()).is_some()
+            },
         );
 
-        let (shortest_route, shortest_route_cycle_idx) =
-            Self::shortest_route_from_cycle_history(&next_cycle_history);
+        let (best_route, best_route_cycle_idx) =
+            Self::best_route_from_cycle_history(&next_cycle_history);
 
         let epoch_summary = EpochSummary {
-            shortest_route,
-            shortest_route_cycle_idx,
+            best_route,
+            best_route_cycle_idx,
             epoch_idx: epoch_history.len() + 1,
             exec_time_ms: next_cycle_history
                 .iter()
                 .fold(0, |acc, cycle| acc + cycle.exec_time_ms),
+            stopped_by: StoppingCriterion::find_met(criteria, &next_cycle_history, start.elapsed())
+                .cloned(),
         };
 
         io.print(&epoch_summary);
@@ -98,43 +141,13 @@ where
     }
 
     fn train_epoch(self, n_cycles: usize) -> Self {
-        let ColonyRunner {
-            colony: init_colony,
-            graph,
-            io,
-            mut cycle_history,
-            mut epoch_history,
-        } = self;
-
-        let (colony, next_cycle_history) = (0..n_cycles).fold(
-            (init_colony, Vec::with_capacity(n_cycles)),
-            |(colony, summaries), cycle_idx| {
-                ColonyRunner::train_cycle(colony, io.as_ref(), summaries, cycle_idx)
-            },
-        );
-
-        let (shortest_route, shortest_route_cycle_idx) =
-            Self::shortest_route_from_cycle_history(&next_cycle_history);
-
-        let epoch_summary = EpochSummary {
-            shortest_route,
-            shortest_route_cycle_idx,
-            epoch_idx: epoch_history.len() + 1,
-            exec_time_ms: next_cycle_history
-                .iter()
-                .fold(0, |acc, cycle| acc + cycle.exec_time_ms),
-        };
-
-        io.print(&epoch_summary);
-        cycle_history.extend(next_cycle_history);
-        epoch_history.push(epoch_summary);
+        self.train_until(&[StoppingCriterion::Cycles(n_cycles)])
+    }
 
+    fn reset_pheromone(self) -> Self {
         ColonyRunner {
-            colony,
-            graph,
-            io,
-            cycle_history,
-            epoch_history,
+            colony: self.colony.reset_pheromone(),
+            ..self
         }
     }
 
@@ -149,6 +162,7 @@ where
         let pheromone = new_colony.get_pheromone();
         let routes = new_colony.get_routes();
         let shortest_route = routes.get_shortest_route();
+        let best_route = routes.get_best_route(routes.get_max_route_length());
         let shortest_dist = shortest_route.clone().map(|r| r.get_distance());
         let shortest_path_length = shortest_route.clone().map(|r| r.get_length());
 
@@ -162,6 +176,8 @@ where
             n_non_empty_edges: PheromoneReader::count_edges_with_pheromone_above(pheromone, 0.1),
             pheromone_variance: pheromone.calc_variance(),
             shortest_route,
+            best_route,
+            reinitialized: new_colony.was_reinitialized(),
         };
 
         io.print(&summary);
@@ -170,59 +186,22 @@ where
         (new_colony, summaries)
     }
 
-    fn had_no_improvement_in_n_last_steps(
-        history: &[CycleSummary],
-        n_no_improvement: usize,
-    ) -> bool {
-        // n_no_improvement + 1, we need additional one to compare against
-        let tail_length = n_no_improvement + 1;
-
-        if history.len() < tail_length {
-            return false;
-        }
-
-        let history_tail = history
-            .iter()
-            .rev()
-            .take(tail_length)
-            .rev()
-            .collect::<Vec<_>>();
-
-        let should_stop =
-            history_tail
-                .split_first()
-                .map_or(false, |(reference, latest_n_entries)| {
-                    let none_improved = latest_n_entries
-                        .iter()
-                        .all(|latest| latest.shortest_dist >= reference.shortest_dist);
-
-                    none_improved
-                });
-
-        should_stop
-    }
-
-    fn shortest_route_from_cycle_history(
+    fn best_route_from_cycle_history(
         cycle_history: &[CycleSummary],
-    ) -> (Option<Route>, Option<usize>) {
-        let shortest_entry = cycle_history
+    ) -> (Option<(Route, f32)>, Option<usize>) {
+        let best_entry = cycle_history
             .iter()
             .filter_map(|cycle| {
                 cycle
-                    .shortest_route
+                    .best_route
                     .as_ref()
                     .map(|route| (route.clone(), cycle.cycle_idx))
             })
-            .min_by(|(route_a, _), (route_b, _)| {
-                let dist_a = route_a.get_distance();
-                let dist_b = route_b.get_distance();
-
-                compare_float(&dist_a, &dist_b)
-            });
+            .min_by(|((_, cost_a), _), ((_, cost_b), _)| compare_float(cost_a, cost_b));
 
         (
-            shortest_entry.clone().map(|(route, _)| route),
-            shortest_entry.map(|(_, cycle_idx)| cycle_idx),
+            best_entry.clone().map(|(route, _)| route),
+            best_entry.map(|(_, cycle_idx)| cycle_idx),
         )
     }
 }
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::time::Duration;
+
+use crate::common::utils::TimeLimit;
+
+use super::CycleSummary;
+
+/// Determines when the runner finishes an epoch,
+/// multiple criteria can be combined, the first one met stops the training
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StoppingCriterion {
+    /// fixed number of cycles
+    Cycles(usize),
+    /// shortest route did not improve for given number of cycles
+    NoImprovement(usize),
+    /// wall-clock time of the epoch exceeded the limit
+    TimeLimit(TimeLimit),
+    /// route costing at most this much was found in the epoch, cost is evaluated
+    /// by the problem, so incomplete routes do not count
+    TargetDistance(f32),
+    /// variance of pheromone dropped below the threshold
+    MinPheromoneVariance(f32),
+}
+
+impl StoppingCriterion {
+    pub fn is_met(&self, history: &[CycleSummary], elapsed: Duration) -> bool {
+        match *self {
+            Self::Cycles(n_cycles) => history.len() >= n_cycles,
+            Self::NoImprovement(n_cycles) => {
+                Self::had_no_improvement_in_n_last_steps(history, n_cycles)
+            }
+            Self::TimeLimit(limit) => elapsed >= limit.as_duration(),
+            Self::TargetDistance(target) => history
+                .iter()
+                .filter_map(|cycle| cycle.best_route.as_ref())
+                .any(|(_, cost)| *cost <= target),
+            Self::MinPheromoneVariance(threshold) => history
+                .last()
+                .map_or(false, |cycle| cycle.pheromone_variance < threshold),
+        }
+    }
+
+    /// Returns the first criterion that is met
+    pub fn find_met<'a>(
+        criteria: &'a [StoppingCriterion],
+        history: &[CycleSummary],
+        elapsed: Duration,
+    ) -> Option<&'a StoppingCriterion> {
+        criteria
+            .iter()
+            .find(|criterion| criterion.is_met(history, elapsed))
+    }
+
+    fn had_no_improvement_in_n_last_steps(
+        history: &[CycleSummary],
+        n_no_improvement: usize,
+    ) -> bool {
+        // n_no_improvement + 1, we need additional one to compare against
+        let tail_length = n_no_improvement + 1;
+
+        if history.len() < tail_length {
+            return false;
+        }
+
+        let history_tail = &history[history.len() - tail_length..];
+
+        history_tail
+            .split_first()
+            .map_or(false, |(reference, latest_n_entries)| {
+                latest_n_entries
+                    .iter()
+                    .all(|latest| latest.shortest_dist >= reference.shortest_dist)
+            })
+    }
+}
+
+impl Display for StoppingCriterion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Cycles(n_cycles) => write!(f, "number of cycles ({})", n_cycles),
+            Self::NoImprovement(n_cycles) => write!(f, "no improvement in {} cycles", n_cycles),
+            Self::TimeLimit(limit) => write!(f, "time limit ({})", limit),
+            Self::TargetDistance(target) => write!(f, "target distance ({})", target),
+            Self::MinPheromoneVariance(threshold) => {
+                write!(f, "pheromone variance below {}", threshold)
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 use std::fmt::Display;
 
 use crate::ant_colony::graph::Route;
+use crate::common::utils::compare_float;
+
+use super::StoppingCriterion;
 
 #[derive(Clone)]
 pub struct CycleSummary {
@@ -13,13 +16,18 @@ pub struct CycleSummary {
     pub ratio_of_incomplete_routes: f32,
     pub pheromone_variance: f32,
     pub shortest_route: Option<Route>,
+    /// cheapest feasible route of the cycle along with its cost, as evaluated by the problem
+    pub best_route: Option<(Route, f32)>,
+    pub reinitialized: bool,
 }
 
 impl Display for CycleSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reinit_appendix = iif!(self.reinitialized, "  |  trails reinitialized", "");
+
         write!(
             f,
-            "Cycle #{:<3} {:>5}ms  |  non empty edges: {:>10}  |  avg path length: {:>10.3}  ({:>4.1}%)  |  shortest length: {:>10.3}  ({:>3})  |  pheromone variance: {:>4.5}",
+            "Cycle #{:<3} {:>5}ms  |  non empty edges: {:>10}  |  avg path length: {:>10.3}  ({:>4.1}%)  |  shortest length: {:>10.3}  ({:>3})  |  pheromone variance: {:>4.5}{}",
             self.cycle_idx,
             self.exec_time_ms,
             self.n_non_empty_edges,
@@ -27,7 +35,8 @@ impl Display for CycleSummary {
             self.ratio_of_incomplete_routes,
             self.shortest_dist.unwrap_or(0.0),
             self.shortest_path_length.unwrap_or(0),
-            self.pheromone_variance
+            self.pheromone_variance,
+            reinit_appendix
         )
     }
 }
@@ -37,21 +46,78 @@ impl Display for CycleSummary {
 pub struct EpochSummary {
     pub epoch_idx: usize,
     pub exec_time_ms: u128,
-    pub shortest_route: Option<Route>,
-    pub shortest_route_cycle_idx: Option<usize>,
+    /// cheapest feasible route of the epoch along with its cost, as evaluated by the problem
+    pub best_route: Option<(Route, f32)>,
+    pub best_route_cycle_idx: Option<usize>,
+    pub stopped_by: Option<StoppingCriterion>,
 }
 
 impl Display for EpochSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let path_appendix = match self
-            .shortest_route
-            .as_ref()
-            .zip(self.shortest_route_cycle_idx)
-        {
-            Option::Some((route, cycle_idx)) => format!(
-                "\n\tshortest path #{} ({}): {:?}",
+        let path_appendix = match self.best_route.as_ref().zip(self.best_route_cycle_idx) {
+            Option::Some(((route, cost), cycle_idx)) => format!(
+                "\n\tbest path #{} ({}): {:?}",
                 cycle_idx,
-                route.get_distance(),
+                cost,
+                route.get_nodes()
+            ),
+            Option::None => String::from(""),
+        };
+
+        let stop_appendix = match &self.stopped_by {
+            Option::Some(criterion) => format!("\n\tstopped by: {}", criterion),
+            Option::None => String::from(""),
+        };
+
+        write!(
+            f,
+            "Epoch #{:<3} {:>5}ms{}{}",
+            self.epoch_idx, self.exec_time_ms, path_appendix, stop_appendix
+        )
+    }
+}
+
+/// Summary of all epochs of the run
+#[derive(Clone)]
+pub struct RunSummary {
+    pub epochs: Vec<EpochSummary>,
+}
+
+impl RunSummary {
+    /// Epoch that found the cheapest feasible route
+    pub fn get_best_epoch(&self) -> Option<&EpochSummary> {
+        self.epochs
+            .iter()
+            .filter_map(|epoch| epoch.best_route.as_ref().map(|(_, cost)| (epoch, cost)))
+            .min_by(|(_, cost_a), (_, cost_b)| compare_float(cost_a, cost_b))
+            .map(|(epoch, _)| epoch)
+    }
+}
+
+impl Display for RunSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let epoch_lines = self
+            .epochs
+            .iter()
+            .map(|epoch| {
+                format!(
+                    "\n\tepoch #{:<3} best cost: {:>10.3}  (cycle #{})",
+                    epoch.epoch_idx,
+                    epoch.best_route.as_ref().map_or(0.0, |(_, cost)| *cost),
+                    epoch.best_route_cycle_idx.unwrap_or(0)
+                )
+            })
+            .collect::<String>();
+
+        let best_appendix = match self
+            .get_best_epoch()
+            .and_then(|epoch| epoch.best_route.as_ref().map(|route| (epoch, route)))
+        {
+            Option::Some((epoch, (route, cost))) => format!(
+                "\n\toverall best path (epoch #{}, cycle #{}) ({}): {:?}",
+                epoch.epoch_idx,
+                epoch.best_route_cycle_idx.unwrap_or(0),
+                cost,
                 route.get_nodes()
             ),
             Option::None => String::from(""),
@@ -59,8 +125,10 @@ impl Display for EpochSummary {
 
         write!(
             f,
-            "Epoch #{:<3} {:>5}ms{}",
-            self.epoch_idx, self.exec_time_ms, path_appendix
+            "Run of {} epochs{}{}",
+            self.epochs.len(),
+            epoch_lines,
+            best_appendix
         )
     }
 }
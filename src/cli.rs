@@ -1,10 +1,13 @@
 use clap::Clap;
 
-use crate::common::utils::Capacity;
+use crate::common::utils::{Capacity, ObjectiveWeights, TimeLimit};
 
 use crate::ant_colony::ant_dispatcher::DispatcherStringConfig;
+use crate::ant_colony::colony::{IslandConfigs, Migration, StagnationCriterion};
 use crate::ant_colony::pheromone_updater::UpdaterStringConfig;
-use crate::images::image_graph_converter::ConverterStringConfig;
+use crate::ant_colony::problem::Problems;
+use crate::ant_colony::runner::EpochTransition;
+use crate::images::image_graph_converter::{ConverterStringConfig, ObjectivesStringConfig};
 
 #[derive(Clap, Debug, Clone)]
 #[clap(version = "1.0.0", author = "Grzegorz K. <kazana.grzegorz@gmail.com>")]
@@ -28,28 +31,133 @@ pub struct Opts {
     #[clap(short, long, about = "updater type in format <type>:<args>")]
     pub updater: UpdaterStringConfig,
 
+    #[clap(
+        long,
+        default_value = "tsp",
+        about = "solved problem, tsp | path:<source>,<target> | sop:<a><<b>,... | cvrp:<depot>,<capacity>"
+    )]
+    pub problem: Problems,
+
     #[clap(
         long,
         default_value = "i:spatial",
-        about = "converter type in format <type>:<args>"
+        about = "converter type in format <type>:<args>, weighted combination in format mix:<weight>*<converter>+..., e.g. mix:0.7*i:spatial+0.3*window:20"
     )]
     pub converter: ConverterStringConfig,
 
+    #[clap(
+        long,
+        about = "converters of multi-objective search separated with ';', e.g. 'spatial;i:spatial', replaces converter and islands"
+    )]
+    pub objectives: Option<ObjectivesStringConfig>,
+
+    #[clap(
+        long,
+        default_value = "10",
+        about = "objective weights are multiples of 1 / weight_divisions, determines the size of the pareto front"
+    )]
+    pub weight_divisions: usize,
+
+    #[clap(
+        long,
+        about = "point of the pareto front to embed with, e.g. 0.3,0.7, by default equal weights or the one closest to target capacity"
+    )]
+    pub objective_weights: Option<ObjectiveWeights>,
+
     #[clap(short, long, about = "number of traning cycles")]
     pub cycles: Option<usize>,
 
+    #[clap(long, default_value = "1", about = "number of training epochs")]
+    pub epochs: usize,
+
+    #[clap(
+        long,
+        default_value = "keep",
+        about = "trails between epochs, keep | reset"
+    )]
+    pub epoch_transition: EpochTransition,
+
     #[clap(
         long,
         about = "train until number of cycles does not provide improvement"
     )]
     pub stop_after: Option<usize>,
 
+    #[clap(
+        long,
+        about = "train until time limit is exceeded, e.g. 500ms, 30s, 2m"
+    )]
+    pub time_limit: Option<TimeLimit>,
+
+    #[clap(
+        long,
+        about = "train until a complete route at most this long (cost of the problem) is found"
+    )]
+    pub target_distance: Option<f32>,
+
+    #[clap(long, about = "train until pheromone variance drops below the value")]
+    pub min_pheromone_variance: Option<f32>,
+
+    #[clap(
+        long,
+        default_value = "none",
+        about = "reinitialize trails on stagnation, none | branching:<lambda>,<threshold> | unchanged:<cycles>"
+    )]
+    pub stagnation: StagnationCriterion,
+
+    #[clap(
+        long,
+        about = "number of colonies running in parallel (island model), by default 1"
+    )]
+    pub islands: Option<usize>,
+
+    #[clap(
+        long,
+        default_value = "10",
+        about = "number of cycles between migrations of the island model"
+    )]
+    pub migration_interval: usize,
+
+    #[clap(
+        long,
+        default_value = "best",
+        about = "migration between islands, best | blend:<weight>"
+    )]
+    pub migration: Migration,
+
+    #[clap(
+        long,
+        about = "updaters of consecutive islands separated with ';', repeated for further islands, by default the updater"
+    )]
+    pub island_updaters: Option<IslandConfigs<UpdaterStringConfig>>,
+
+    #[clap(
+        long,
+        about = "dispatchers of consecutive islands separated with ';', repeated for further islands, by default the dispatcher"
+    )]
+    pub island_dispatchers: Option<IslandConfigs<DispatcherStringConfig>>,
+
     #[clap(
         long,
         about = "dimension of the pheromone mask, directly affects graph size, height is calculated automatically"
     )]
     pub mask_width: Option<usize>,
 
+    #[clap(
+        long,
+        default_value = "1",
+        about = "number of coarse-to-fine levels, width is halved per level below mask width, pheromone of each level seeds the next one"
+    )]
+    pub pyramid_levels: usize,
+
+    #[clap(
+        long,
+        use_delimiter = true,
+        require_delimiter = true,
+        about = "cycles of each pyramid level from the coarsest one separated with ',', e.g. '40,20,10', by default cycles"
+    )]
+    pub pyramid_cycles: Vec<usize>,
+
     #[clap(long, about = "target capacity")]
     pub target_capacity: Option<Capacity>,
 
@@ -98,18 +206,30 @@ pub struct TspCommand {
 
     #[clap(short, long, about = "path to tsp graph csv")]
     pub graph: Option<String>,
+
+    #[clap(
+        short,
+        long,
+        about = "path to csv with (possibly asymmetric) distance matrix, solved as directed graph"
+    )]
+    pub matrix: Option<String>,
+
+    #[clap(long, about = "generate random asymmetric (directed) graph")]
+    pub directed: bool,
 }
 
 impl ToString for Opts {
     fn to_string(&self) -> String {
         format!(
-            "_a{}_s{}_D{}_U{}_C{}_c{}_m{}_t{}_",
+            "_a{}_s{}_D{}_U{}_C{}_c{}_e{}_E{}_m{}_t{}_",
             self.ants.unwrap_or_default(),
             self.steps.unwrap_or_default(),
             self.dispatcher.to_string(),
             self.updater.to_string(),
             self.converter.to_string(),
             self.cycles.unwrap_or_default(),
+            self.epochs,
+            self.epoch_transition,
             self.mask_width.unwrap_or_default(),
             self.target_capacity
                 .as_ref()
@@ -11,6 +11,8 @@ pub enum AppError {
 
     IoError(String),
 
+    ConfigError(String),
+
     ColonyExecutionFailed,
 }
 
@@ -21,6 +23,7 @@ impl Display for AppError {
             AppError::ImageSavingError(err) => write!(f, "ImageSavingError: {}", err),
             AppError::DataLoadingError(err) => write!(f, "DataLoadingError: {}", err),
             AppError::IoError(err) => write!(f, "IoError: {}", err),
+            AppError::ConfigError(err) => write!(f, "ConfigError: {}", err),
             AppError::ColonyExecutionFailed => write!(f, "Failed to execute the colony"),
         }
     }
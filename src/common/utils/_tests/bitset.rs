@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod bitset_tests {
+    use super::super::super::BitSet;
+
+    #[test]
+    fn it_contains_inserted_values_only() {
+        let mut set = BitSet::with_capacity(100);
+
+        set.insert(0);
+        set.insert(63);
+        set.insert(64);
+
+        assert!(set.contains(0));
+        assert!(set.contains(63));
+        assert!(set.contains(64));
+        assert!(!set.contains(1));
+        assert!(!set.contains(99));
+        assert_eq!(set.count(), 3);
+    }
+
+    #[test]
+    fn it_grows_above_capacity() {
+        let mut set = BitSet::with_capacity(10);
+
+        set.insert(1000);
+
+        assert!(set.contains(1000));
+        assert!(!set.contains(2000));
+    }
+
+    #[test]
+    fn it_clears_all_values() {
+        let mut set = BitSet::with_capacity(10);
+
+        set.insert(3);
+        set.clear();
+
+        assert!(!set.contains(3));
+        assert_eq!(set.count(), 0);
+    }
+}
@@ -0,0 +1,30 @@
+#[cfg(test)]
+mod disjoint_set_tests {
+    use super::super::super::DisjointSet;
+
+    #[test]
+    fn it_merges_subsets() {
+        let mut set = DisjointSet::new(5);
+
+        assert!(set.union(0, 1).is_some());
+        assert!(set.union(3, 4).is_some());
+        assert!(set.union(1, 0).is_none());
+
+        assert_eq!(set.find(0), set.find(1));
+        assert_ne!(set.find(1), set.find(3));
+        assert_eq!(set.size_of(4), 2);
+        assert_eq!(set.size_of(2), 1);
+        assert_eq!(set.n_subsets(), 3);
+    }
+
+    #[test]
+    fn it_labels_elements_in_order_of_appearance() {
+        let mut set = DisjointSet::new(6);
+
+        set.union(4, 1);
+        set.union(5, 0);
+        set.union(2, 3);
+
+        assert_eq!(set.labels(), vec![0, 1, 2, 2, 1, 0]);
+    }
+}
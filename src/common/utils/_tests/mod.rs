@@ -1,7 +1,13 @@
+mod bitset;
+mod disjoint_set;
 mod map_accum;
 mod measure_chunks;
+mod objective_weights;
 mod produce_until;
 mod select_top_n_items;
+mod simplex_lattice;
+mod split_seed;
+mod time_limit;
 mod unique_pair;
 mod vec_2d;
 mod weighted_sample;
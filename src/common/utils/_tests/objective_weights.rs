@@ -0,0 +1,21 @@
+#[cfg(test)]
+mod objective_weights_tests {
+    use std::str::FromStr;
+
+    use super::super::super::ObjectiveWeights;
+
+    #[test]
+    fn it_normalizes_weights() {
+        let weights = ObjectiveWeights::from_str("1,3").unwrap();
+
+        assert_eq!(weights.get_weights(), &[0.25, 0.75]);
+    }
+
+    #[test]
+    fn it_rejects_invalid_weights() {
+        assert!(ObjectiveWeights::from_str("0.5,x").is_err());
+        assert!(ObjectiveWeights::from_str("-1,2").is_err());
+        assert!(ObjectiveWeights::from_str("0,0").is_err());
+        assert!(ObjectiveWeights::from_str("").is_err());
+    }
+}
@@ -0,0 +1,37 @@
+#[cfg(test)]
+mod simplex_lattice_tests {
+    use super::super::super::simplex_lattice;
+
+    #[test]
+    fn it_spaces_two_weights_evenly() {
+        let lattice = simplex_lattice(2, 4);
+
+        assert_eq!(
+            lattice,
+            vec![
+                vec![1.0, 0.0],
+                vec![0.75, 0.25],
+                vec![0.5, 0.5],
+                vec![0.25, 0.75],
+                vec![0.0, 1.0]
+            ]
+        );
+    }
+
+    #[test]
+    fn it_generates_weights_summing_to_one() {
+        let lattice = simplex_lattice(3, 3);
+
+        assert_eq!(lattice.len(), 10);
+        lattice.iter().for_each(|weights| {
+            assert_eq!(weights.len(), 3);
+            assert_delta!(weights.iter().sum::<f32>(), 1.0);
+        });
+    }
+
+    #[test]
+    fn it_handles_degenerate_dimensions() {
+        assert_eq!(simplex_lattice(1, 5), vec![vec![1.0]]);
+        assert!(simplex_lattice(0, 5).is_empty());
+    }
+}
@@ -0,0 +1,43 @@
+#[cfg(test)]
+mod split_seed_tests {
+    use itertools::Itertools;
+
+    use super::super::super::SplitSeed;
+
+    #[test]
+    fn it_derives_the_same_stream_for_the_same_index() {
+        let seed = SplitSeed::new(42);
+
+        assert_eq!(seed.split(7).split(3), seed.split(7).split(3));
+        assert_eq!(seed.split(7).unit_pair(), seed.split(7).unit_pair());
+    }
+
+    #[test]
+    fn it_derives_distinct_streams() {
+        let seed = SplitSeed::new(42);
+
+        let n_unique = (0..1000)
+            .map(|idx| seed.split(idx).get_seed())
+            .unique()
+            .count();
+
+        assert_eq!(n_unique, 1000);
+        assert_ne!(seed.split(1).split(0), seed.split(0).split(1));
+    }
+
+    #[test]
+    fn it_samples_unit_interval() {
+        let seed = SplitSeed::new(0);
+
+        let samples = (0..1000)
+            .flat_map(|idx| {
+                let (a, b) = seed.split(idx).unit_pair();
+                vec![a, b]
+            })
+            .collect::<Vec<_>>();
+        let mean = samples.iter().sum::<f32>() / samples.len() as f32;
+
+        assert!(samples.iter().all(|value| (0.0..1.0).contains(value)));
+        assert!((mean - 0.5).abs() < 0.05);
+    }
+}
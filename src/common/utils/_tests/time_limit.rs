@@ -0,0 +1,15 @@
+#[cfg(test)]
+mod time_limit_tests {
+    use std::time::Duration;
+
+    use super::super::super::TimeLimit;
+
+    #[test]
+    fn it_parses_time_limit_with_units() {
+        assert_eq!("500ms".parse(), Ok(TimeLimit(Duration::from_millis(500))));
+        assert_eq!("30s".parse(), Ok(TimeLimit(Duration::from_secs(30))));
+        assert_eq!("2m".parse(), Ok(TimeLimit(Duration::from_secs(120))));
+        assert!("30".parse::<TimeLimit>().is_err());
+        assert!("s".parse::<TimeLimit>().is_err());
+    }
+}
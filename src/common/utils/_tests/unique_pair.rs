@@ -19,6 +19,16 @@ mod common_utils_unique_pair_tests {
         assert!((from, to) == result || (to, from) == result);
     }
 
+    #[test]
+    fn it_does_distinguish_node_order_in_ordered_key() {
+        let key_a = UniquePair::generate_ordered_key(2, 1);
+        let key_b = UniquePair::generate_ordered_key(1, 2);
+
+        assert_ne!(key_a, key_b);
+        assert_eq!(UniquePair::decode_ordered_key(key_a), (2, 1));
+        assert_eq!(UniquePair::decode_ordered_key(key_b), (1, 2));
+    }
+
     proptest! {
         #[test]
         fn it_does_not_care_for_node_order_when_generating_key(a: u32, b: u32) {
@@ -0,0 +1,53 @@
+use super::ceil_div;
+
+const WORD_BITS: usize = 64;
+
+/// Dense set of small unsigned integers, one bit per possible element.
+///
+/// Grows when an element above the capacity is inserted,
+/// `clear` keeps the allocated memory so the set can be reused.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct BitSet {
+    words: Vec<u64>,
+}
+
+impl BitSet {
+    pub fn with_capacity(capacity: usize) -> Self {
+        BitSet {
+            words: vec![0; ceil_div(capacity, WORD_BITS)],
+        }
+    }
+
+    pub fn insert(&mut self, value: usize) {
+        let (word, bit) = Self::locate(value);
+
+        if word >= self.words.len() {
+            self.words.resize(word + 1, 0);
+        }
+
+        self.words[word] |= bit;
+    }
+
+    pub fn contains(&self, value: usize) -> bool {
+        let (word, bit) = Self::locate(value);
+
+        self.words.get(word).map_or(false, |w| w & bit != 0)
+    }
+
+    pub fn clear(&mut self) {
+        for word in self.words.iter_mut() {
+            *word = 0;
+        }
+    }
+
+    pub fn count(&self) -> usize {
+        self.words
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    fn locate(value: usize) -> (usize, u64) {
+        (value / WORD_BITS, 1 << (value % WORD_BITS))
+    }
+}
@@ -0,0 +1,89 @@
+/// Union-find over elements 0..n with path compression and union by size.
+///
+/// Tracks number of disjoint subsets, so merging can be stopped
+/// once the desired number of subsets is reached.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+    n_subsets: usize,
+}
+
+impl DisjointSet {
+    pub fn new(n_elements: usize) -> Self {
+        DisjointSet {
+            parents: (0..n_elements).collect(),
+            sizes: vec![1; n_elements],
+            n_subsets: n_elements,
+        }
+    }
+
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        let mut current = element;
+
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        root
+    }
+
+    /// Merges subsets containing given elements,
+    /// returns root of the merged subset or None if they were already in the same one
+    pub fn union(&mut self, a: usize, b: usize) -> Option<usize> {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+
+        if root_a == root_b {
+            return None;
+        }
+
+        let (root, child) = iif!(
+            self.sizes[root_a] >= self.sizes[root_b],
+            (root_a, root_b),
+            (root_b, root_a)
+        );
+
+        self.parents[child] = root;
+        self.sizes[root] += self.sizes[child];
+        self.n_subsets -= 1;
+
+        Some(root)
+    }
+
+    pub fn size_of(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+
+        self.sizes[root]
+    }
+
+    pub fn n_subsets(&self) -> usize {
+        self.n_subsets
+    }
+
+    /// Labels elements with consecutive subset ids, in order of first appearance
+    pub fn labels(&mut self) -> Vec<usize> {
+        let mut label_by_root = vec![usize::MAX; self.parents.len()];
+        let mut next_label = 0;
+
+        (0..self.parents.len())
+            .map(|element| {
+                let root = self.find(element);
+
+                if label_by_root[root] == usize::MAX {
+                    label_by_root[root] = next_label;
+                    next_label += 1;
+                }
+
+                label_by_root[root]
+            })
+            .collect()
+    }
+}
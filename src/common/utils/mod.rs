@@ -1,7 +1,9 @@
 mod _tests;
 mod balanced_divisors;
+mod bitset;
 mod capacity;
 mod compare_float;
+mod disjoint_set;
 mod euclidean_distance;
 mod exact_sized_chain;
 mod extend_basename;
@@ -9,19 +11,24 @@ mod identity;
 mod map_accum;
 mod measure;
 mod measure_chunks;
+mod objective_weights;
 mod prefix_basename;
 mod produce_until;
-mod random_pair_iter;
 mod select_top_n_items;
+mod simplex_lattice;
 mod split_once;
+mod split_seed;
+mod time_limit;
 mod unique_pair;
 mod usize_ceil_div;
 mod vec_2d;
 mod weighted_sample;
 
 pub use balanced_divisors::balanced_divisors;
+pub use bitset::BitSet;
 pub use capacity::Capacity;
 pub use compare_float::compare_float;
+pub use disjoint_set::DisjointSet;
 pub use euclidean_distance::Euclidean;
 pub use exact_sized_chain::ExactChainExt;
 pub use extend_basename::extend_basename;
@@ -29,11 +36,14 @@ pub use identity::identity;
 pub use map_accum::MapAccumExt;
 pub use measure::measure;
 pub use measure_chunks::{measure_chunks, MeasuredChunk};
+pub use objective_weights::ObjectiveWeights;
 pub use prefix_basename::prefix_basename;
 pub use produce_until::produce_until;
-pub use random_pair_iter::random_pair_iter;
 pub use select_top_n_items::select_top_n_items;
+pub use simplex_lattice::simplex_lattice;
 pub use split_once::split_once;
+pub use split_seed::SplitSeed;
+pub use time_limit::TimeLimit;
 pub use unique_pair::UniquePair;
 pub use usize_ceil_div::ceil_div;
 pub use vec_2d::{Vec2d, Vec2dCoords, Vec2dOffset};
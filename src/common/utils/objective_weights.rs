@@ -0,0 +1,50 @@
+use itertools::Itertools;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Relative importance of objectives, e.g. `0.3,0.7`, normalized to sum up to 1.0
+#[derive(Debug, Clone, PartialEq)]
+pub struct ObjectiveWeights(Vec<f32>);
+
+impl ObjectiveWeights {
+    pub fn get_weights(&self) -> &[f32] {
+        &self.0
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl FromStr for ObjectiveWeights {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse objective weights";
+
+        let weights = s
+            .split(',')
+            .map(str::parse::<f32>)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|_| error)?;
+        let sum: f32 = weights.iter().sum();
+
+        if weights.iter().any(|weight| *weight < 0.0) || sum <= 0.0 {
+            return Err(error);
+        }
+
+        Ok(ObjectiveWeights(
+            weights.into_iter().map(|weight| weight / sum).collect(),
+        ))
+    }
+}
+
+impl Display for ObjectiveWeights {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0.iter().join(","))
+    }
+}
@@ -0,0 +1,33 @@
+/// Evenly spaced weight vectors, each with `dimensions` non-negative weights summing up to 1.0.
+///
+/// Each weight is a multiple of `1 / divisions`, e.g. for 2 dimensions and 2 divisions:
+/// [1.0, 0.0], [0.5, 0.5], [0.0, 1.0]
+pub fn simplex_lattice(dimensions: usize, divisions: usize) -> Vec<Vec<f32>> {
+    let divisions = divisions.max(1);
+
+    compositions(dimensions, divisions)
+        .into_iter()
+        .map(|parts| {
+            parts
+                .into_iter()
+                .map(|part| part as f32 / divisions as f32)
+                .collect()
+        })
+        .collect()
+}
+
+/// All ways to write `total` as an ordered sum of `n_parts` non-negative integers
+fn compositions(n_parts: usize, total: usize) -> Vec<Vec<usize>> {
+    match n_parts {
+        0 => Vec::new(),
+        1 => vec![vec![total]],
+        _ => (0..=total)
+            .rev()
+            .flat_map(|head| {
+                compositions(n_parts - 1, total - head)
+                    .into_iter()
+                    .map(move |tail| std::iter::once(head).chain(tail).collect())
+            })
+            .collect(),
+    }
+}
@@ -0,0 +1,45 @@
+/// Counter-based seed derivation (SplitMix64 finalizer).
+///
+/// Every child stream depends only on the parent seed and its own index,
+/// so values drawn in parallel do not depend on the order of execution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitSeed(u64);
+
+const GOLDEN_GAMMA: u64 = 0x9e37_79b9_7f4a_7c15;
+
+impl SplitSeed {
+    pub fn new(seed: u64) -> Self {
+        SplitSeed(seed)
+    }
+
+    /// Derives an independent stream identified by `stream_idx`
+    pub fn split(&self, stream_idx: u64) -> Self {
+        SplitSeed(Self::mix(
+            self.0 ^ Self::mix(stream_idx.wrapping_add(GOLDEN_GAMMA)),
+        ))
+    }
+
+    pub fn get_seed(&self) -> u64 {
+        self.0
+    }
+
+    /// Pair of floats uniformly distributed in [0, 1)
+    pub fn unit_pair(&self) -> (f32, f32) {
+        let a = Self::mix(self.0.wrapping_add(GOLDEN_GAMMA));
+        let b = Self::mix(self.0.wrapping_add(GOLDEN_GAMMA.wrapping_mul(2)));
+
+        (Self::to_unit(a), Self::to_unit(b))
+    }
+
+    fn to_unit(value: u64) -> f32 {
+        // 24 bits is the precision of f32 mantissa
+        (value >> 40) as f32 / (1u64 << 24) as f32
+    }
+
+    fn mix(value: u64) -> u64 {
+        let z = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        let z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+
+        z ^ (z >> 31)
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+/// Wall-clock duration given with a unit, e.g. `500ms`, `30s` or `2m`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeLimit(pub Duration);
+
+impl TimeLimit {
+    pub fn as_duration(&self) -> Duration {
+        self.0
+    }
+}
+
+impl FromStr for TimeLimit {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse time limit";
+
+        // `ms` has to be checked before `s` and `m`
+        let (value, unit_ms) = if let Option::Some(millis) = s.strip_suffix("ms") {
+            (millis, 1)
+        } else if let Option::Some(secs) = s.strip_suffix('s') {
+            (secs, 1000)
+        } else if let Option::Some(mins) = s.strip_suffix('m') {
+            (mins, 60 * 1000)
+        } else {
+            return Err(error);
+        };
+
+        value
+            .parse::<u64>()
+            .map(|value| TimeLimit(Duration::from_millis(value * unit_ms)))
+            .map_err(|_| error)
+    }
+}
+
+impl Display for TimeLimit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}ms", self.0.as_millis())
+    }
+}
@@ -19,4 +19,13 @@ impl UniquePair {
 
         ((key - floor * floor) as u32, floor as u32)
     }
+
+    /// Same as `generate_key`, but the order of input matters
+    pub fn generate_ordered_key(from: u32, to: u32) -> u64 {
+        ((from as u64) << 32) | to as u64
+    }
+
+    pub fn decode_ordered_key(key: u64) -> (u32, u32) {
+        ((key >> 32) as u32, key as u32)
+    }
 }
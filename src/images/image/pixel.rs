@@ -39,6 +39,19 @@ impl Pixel {
         }
     }
 
+    /// pseudo-random, but stable colour of given label, used to visualize segmentations
+    pub fn from_label(x: usize, y: usize, label: usize) -> Self {
+        let hash = (label as u64 + 1).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+
+        Pixel {
+            x,
+            y,
+            r: (hash >> 16) as u8,
+            g: (hash >> 32) as u8,
+            b: (hash >> 48) as u8,
+        }
+    }
+
     pub fn scale(&self, scaler: f32) -> Self {
         Pixel {
             x: self.x,
@@ -59,6 +72,19 @@ impl Pixel {
         }
     }
 
+    /// Moves colour towards the other pixel, (1 - w) * self + w * other
+    pub fn blend(&self, other: &Pixel, weight: f32) -> Self {
+        let mix = |a: u8, b: u8| ((1.0 - weight) * a as f32 + weight * b as f32).round() as u8;
+
+        Pixel {
+            x: self.x,
+            y: self.y,
+            r: mix(self.r, other.r),
+            g: mix(self.g, other.g),
+            b: mix(self.b, other.b),
+        }
+    }
+
     pub fn invert(&self) -> Self {
         Pixel {
             x: self.x,
@@ -98,6 +124,11 @@ impl Pixel {
         (self.channel_sum() as f32 / 3.0) as u8
     }
 
+    /// Perceived brightness (ITU-R BT.601 luma)
+    pub fn luminance(&self) -> u8 {
+        (0.299 * self.r as f32 + 0.587 * self.g as f32 + 0.114 * self.b as f32).round() as u8
+    }
+
     pub fn iter_channels(&self) -> impl Iterator<Item = u8> + ExactSizeIterator {
         iter::once(self.r)
             .chain_exact(iter::once(self.g))
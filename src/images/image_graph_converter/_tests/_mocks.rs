@@ -0,0 +1,47 @@
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+/// 16x16 image, each quadrant has different colour
+pub fn mock_quadrant_image() -> PixelMap {
+    let pixels = (0..16)
+        .flat_map(|y| {
+            (0..16).map(move |x| match (x < 8, y < 8) {
+                (true, true) => Pixel::new(x, y, 200, 20, 20),
+                (false, true) => Pixel::new(x, y, 20, 200, 20),
+                (true, false) => Pixel::new(x, y, 20, 20, 200),
+                (false, false) => Pixel::new(x, y, 200, 200, 200),
+            })
+        })
+        .collect();
+
+    PixelMap::new(16, 16, pixels)
+}
+
+/// Square image, flat except for a checkerboard in the area selected by `is_textured`
+pub fn mock_textured_image(size: usize, is_textured: fn(usize, usize) -> bool) -> PixelMap {
+    let pixels = (0..size)
+        .flat_map(|y| {
+            (0..size).map(move |x| {
+                let is_bright = is_textured(x, y) && (x + y) % 2 == 0;
+
+                Pixel::grey(x, y, iif!(is_bright, 200, 100))
+            })
+        })
+        .collect();
+
+    PixelMap::new(size, size, pixels)
+}
+
+/// 8x8 image, bottom right quadrant is a checkerboard, the rest is flat
+pub fn mock_textured_quadrant_image() -> PixelMap {
+    mock_textured_image(8, |x, y| x >= 4 && y >= 4)
+}
+
+/// Square image, left half dark and right half bright
+pub fn mock_edge_image(size: usize) -> PixelMap {
+    let pixels = (0..size)
+        .flat_map(|y| (0..size).map(move |x| Pixel::grey(x, y, iif!(x < size / 2, 0, 255))))
+        .collect();
+
+    PixelMap::new(size, size, pixels)
+}
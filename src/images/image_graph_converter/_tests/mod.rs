@@ -0,0 +1,7 @@
+#[cfg(test)]
+mod _mocks;
+
+#[cfg(test)]
+pub use _mocks::{
+    mock_edge_image, mock_quadrant_image, mock_textured_image, mock_textured_quadrant_image,
+};
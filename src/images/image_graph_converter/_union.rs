@@ -3,11 +3,14 @@ use std::str::FromStr;
 use crate::ant_colony::graph::Graph;
 use crate::ant_colony::pheromone::Pheromone;
 
+use crate::images::image::Pixel;
 use crate::images::pixel_map::PixelMap;
 
 use super::{
-    ConverterStringConfig, FromStrAndPixelMap, ImageGraphConverter, KMeansConverter,
-    SpatialEdgeChangeConverter, SuperPixelConverter, WindowToEdgeConverter,
+    CombinedConverter, ConverterStringConfig, FelzenszwalbConverter, FromStrAndPixelMap,
+    GradientConverter, ImageGraphConverter, KMeansConverter, QuadtreeConverter,
+    RegionAdjacencyConverter, SpatialEdgeChangeConverter, SuperPixelConverter,
+    TianEdgeDetectionConverter, WindowToEdgeConverter,
 };
 
 /// using an enum instead of run-time
@@ -17,10 +20,19 @@ pub enum Converters {
     WindowToEdge(WindowToEdgeConverter),
     Kmeans(KMeansConverter),
     SuperPixels(SuperPixelConverter),
+    Felzenszwalb(FelzenszwalbConverter),
+    Quadtree(QuadtreeConverter),
+    RegionAdjacency(RegionAdjacencyConverter),
+    TianEdgeDetection(TianEdgeDetectionConverter),
+    Gradient(GradientConverter),
     /// higher order converter, which inverts
     /// all graph distances to (1 / d)
     /// and inverts all generated pixelmaps (255 - a)
     Inverted(Box<Converters>),
+    /// higher order converter, which blends
+    /// graph distances and pheromone visualizations
+    /// of several weighted converters
+    Combined(CombinedConverter),
 }
 
 impl ImageGraphConverter for Converters {
@@ -30,6 +42,12 @@ impl ImageGraphConverter for Converters {
             Self::WindowToEdge(converter) => converter.img_to_graph(),
             Self::Kmeans(converter) => converter.img_to_graph(),
             Self::SuperPixels(converter) => converter.img_to_graph(),
+            Self::Felzenszwalb(converter) => converter.img_to_graph(),
+            Self::Quadtree(converter) => converter.img_to_graph(),
+            Self::RegionAdjacency(converter) => converter.img_to_graph(),
+            Self::TianEdgeDetection(converter) => converter.img_to_graph(),
+            Self::Gradient(converter) => converter.img_to_graph(),
+            Self::Combined(converter) => converter.img_to_graph(),
             Self::Inverted(converter) => converter.img_to_graph().invert_distances(),
         }
     }
@@ -40,6 +58,12 @@ impl ImageGraphConverter for Converters {
             Self::WindowToEdge(converter) => converter.visualize_pheromone(pheromone),
             Self::Kmeans(converter) => converter.visualize_pheromone(pheromone),
             Self::SuperPixels(converter) => converter.visualize_pheromone(pheromone),
+            Self::Felzenszwalb(converter) => converter.visualize_pheromone(pheromone),
+            Self::Quadtree(converter) => converter.visualize_pheromone(pheromone),
+            Self::RegionAdjacency(converter) => converter.visualize_pheromone(pheromone),
+            Self::TianEdgeDetection(converter) => converter.visualize_pheromone(pheromone),
+            Self::Gradient(converter) => converter.visualize_pheromone(pheromone),
+            Self::Combined(converter) => converter.visualize_pheromone(pheromone),
             Self::Inverted(converter) => converter.visualize_pheromone(pheromone).invert(),
         }
     }
@@ -50,12 +74,52 @@ impl ImageGraphConverter for Converters {
             Self::WindowToEdge(converter) => converter.visualize_conversion(),
             Self::Kmeans(converter) => converter.visualize_conversion(),
             Self::SuperPixels(converter) => converter.visualize_conversion(),
+            Self::Felzenszwalb(converter) => converter.visualize_conversion(),
+            Self::Quadtree(converter) => converter.visualize_conversion(),
+            Self::RegionAdjacency(converter) => converter.visualize_conversion(),
+            Self::TianEdgeDetection(converter) => converter.visualize_conversion(),
+            Self::Gradient(converter) => converter.visualize_conversion(),
+            Self::Combined(converter) => converter.visualize_conversion(),
             Self::Inverted(converter) => converter
                 .visualize_conversion()
                 .as_ref()
                 .map(PixelMap::invert),
         }
     }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        match self {
+            Self::SpatialEdgeChange(converter) => converter.visualize_segments(),
+            Self::WindowToEdge(converter) => converter.visualize_segments(),
+            Self::Kmeans(converter) => converter.visualize_segments(),
+            Self::SuperPixels(converter) => converter.visualize_segments(),
+            Self::Felzenszwalb(converter) => converter.visualize_segments(),
+            Self::Quadtree(converter) => converter.visualize_segments(),
+            Self::RegionAdjacency(converter) => converter.visualize_segments(),
+            Self::TianEdgeDetection(converter) => converter.visualize_segments(),
+            Self::Gradient(converter) => converter.visualize_segments(),
+            Self::Combined(converter) => converter.visualize_segments(),
+            // segmentation does not change with inverted distances
+            Self::Inverted(converter) => converter.visualize_segments(),
+        }
+    }
+
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        match self {
+            Self::SpatialEdgeChange(converter) => converter.pheromone_from_mask(mask),
+            Self::WindowToEdge(converter) => converter.pheromone_from_mask(mask),
+            Self::Kmeans(converter) => converter.pheromone_from_mask(mask),
+            Self::SuperPixels(converter) => converter.pheromone_from_mask(mask),
+            Self::Felzenszwalb(converter) => converter.pheromone_from_mask(mask),
+            Self::Quadtree(converter) => converter.pheromone_from_mask(mask),
+            Self::RegionAdjacency(converter) => converter.pheromone_from_mask(mask),
+            Self::TianEdgeDetection(converter) => converter.pheromone_from_mask(mask),
+            Self::Gradient(converter) => converter.pheromone_from_mask(mask),
+            Self::Combined(converter) => converter.pheromone_from_mask(mask),
+            // inverted converter inverts its masks
+            Self::Inverted(converter) => converter.pheromone_from_mask(&mask.invert()),
+        }
+    }
 }
 
 impl Converters {
@@ -78,6 +142,27 @@ impl Converters {
             ConverterStringConfig::SuperPixels(opts) => {
                 SuperPixelConverter::from_str_and_pixel_map(pixel_map, opts).map(Self::SuperPixels)
             }
+            ConverterStringConfig::Felzenszwalb(opts) => {
+                FelzenszwalbConverter::from_str_and_pixel_map(pixel_map, opts)
+                    .map(Self::Felzenszwalb)
+            }
+            ConverterStringConfig::Quadtree(opts) => {
+                QuadtreeConverter::from_str_and_pixel_map(pixel_map, opts).map(Self::Quadtree)
+            }
+            ConverterStringConfig::RegionAdjacency(opts) => {
+                RegionAdjacencyConverter::from_str_and_pixel_map(pixel_map, opts)
+                    .map(Self::RegionAdjacency)
+            }
+            ConverterStringConfig::TianEdgeDetection(opts) => {
+                TianEdgeDetectionConverter::from_str_and_pixel_map(pixel_map, opts)
+                    .map(Self::TianEdgeDetection)
+            }
+            ConverterStringConfig::Gradient(opts) => {
+                GradientConverter::from_str_and_pixel_map(pixel_map, opts).map(Self::Gradient)
+            }
+            ConverterStringConfig::Combined(configs) => {
+                CombinedConverter::from_configs(pixel_map, configs).map(Self::Combined)
+            }
             ConverterStringConfig::Inverted(config) => {
                 Self::from_string_config_and_pixel_map(pixel_map, config)
                     .map(Box::new)
@@ -86,6 +171,28 @@ impl Converters {
         }
     }
 
+    /// Pheromone of a coarser pyramid level upsampled via its mask to the given image,
+    /// which this converter converts, None when trails cannot be reconstructed from a mask
+    pub fn seed_from_coarser_level(
+        &self,
+        image: &PixelMap,
+        coarser_converter: &Converters,
+        coarser_pheromone: &Pheromone,
+    ) -> Option<Pheromone> {
+        let mask = coarser_converter
+            .visualize_pheromone(coarser_pheromone)
+            .resize(image.width, image.height);
+
+        self.pheromone_from_mask(&mask)
+    }
+
+    /// Whether trails of the given image, which this converter converts, can be
+    /// reconstructed from a mask, i.e. whether a coarser pyramid level can seed them
+    pub fn can_seed_from_coarser_level(&self, image: &PixelMap) -> bool {
+        self.pheromone_from_mask(&image.map(|px| Pixel::grey(px.x, px.y, 0)))
+            .is_some()
+    }
+
     pub fn default(pixel_map: &PixelMap) -> Self {
         Self::SpatialEdgeChange(SpatialEdgeChangeConverter::new(pixel_map))
     }
@@ -0,0 +1,147 @@
+#[cfg(test)]
+mod combined_converter_tests {
+    use crate::ant_colony::pheromone::Pheromone;
+    use crate::images::image::Pixel;
+    use crate::images::pixel_map::PixelMap;
+
+    use super::super::super::_tests::mock_textured_quadrant_image;
+    use super::super::super::{
+        CombinedConverter, ConverterStringConfig, Converters, FromStrAndPixelMap,
+        ImageGraphConverter,
+    };
+
+    fn mock_converter(config: &str) -> Converters {
+        Converters::from_str_and_pixel_map(&mock_textured_quadrant_image(), config).unwrap()
+    }
+
+    fn uniform_pheromone(converter: &impl ImageGraphConverter) -> Pheromone {
+        let graph = converter.img_to_graph();
+
+        graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| pheromone.initialize_pheromone_for_edge(edge.key, 1.0),
+        )
+    }
+
+    #[test]
+    fn it_parses_weighted_converters() {
+        let config = "mix:0.7*i:spatial:8+0.3*window:20,entropy";
+        let parsed = config.parse::<ConverterStringConfig>().unwrap();
+
+        assert_eq!(parsed.to_string(), config);
+        assert!(matches!(
+            parsed,
+            ConverterStringConfig::Combined(ref components) if components.len() == 2
+        ));
+        assert!("mix:0.7spatial".parse::<ConverterStringConfig>().is_err());
+        assert!("mix:0*spatial".parse::<ConverterStringConfig>().is_err());
+        assert!("mix:-1*spatial".parse::<ConverterStringConfig>().is_err());
+        assert!("mix:1*unknown".parse::<ConverterStringConfig>().is_err());
+        assert!("mix:1*2*spatial".parse::<ConverterStringConfig>().is_err());
+    }
+
+    #[test]
+    fn it_blends_distances_of_converters_sharing_topology() {
+        let spatial = mock_converter("spatial").img_to_graph();
+        let inverted = mock_converter("i:spatial").img_to_graph();
+        let combined = CombinedConverter::from_str_and_pixel_map(
+            &mock_textured_quadrant_image(),
+            "3*spatial+1*i:spatial",
+        )
+        .unwrap();
+        let graph = combined.img_to_graph();
+
+        let scale = spatial.avg_edge_length() / inverted.avg_edge_length();
+
+        assert!(combined.shares_topology());
+        assert_eq!(graph.get_edge_index(), spatial.get_edge_index());
+        assert!(graph.get_all_edges().iter().all(|edge| {
+            let expected = 0.75 * spatial.get_edge(edge.key).unwrap().distance
+                + 0.25 * scale * inverted.get_edge(edge.key).unwrap().distance;
+
+            (edge.distance - expected).abs() < 1e-3 * expected
+        }));
+    }
+
+    #[test]
+    fn it_blends_graphs_without_edges() {
+        let pixel = PixelMap::new(1, 1, vec![Pixel::grey(0, 0, 100)]);
+        let spatial = Converters::from_str_and_pixel_map(&pixel, "spatial").unwrap();
+        let combined =
+            CombinedConverter::from_str_and_pixel_map(&pixel, "1*spatial+1*i:spatial").unwrap();
+
+        assert!(combined.shares_topology());
+        assert_eq!(spatial.img_to_graph().avg_edge_length(), 0.0);
+        assert_eq!(combined.img_to_graph(), spatial.img_to_graph());
+    }
+
+    #[test]
+    fn it_blends_visualizations_of_converters_sharing_topology() {
+        let spatial = mock_converter("spatial");
+        let inverted = mock_converter("i:spatial");
+        let combined = mock_converter("mix:0.5*spatial+0.5*i:spatial");
+        let pheromone = uniform_pheromone(&combined);
+
+        let expected = spatial
+            .visualize_pheromone(&pheromone)
+            .blend(&inverted.visualize_pheromone(&pheromone), 0.5);
+
+        assert_eq!(combined.visualize_pheromone(&pheromone), expected);
+    }
+
+    #[test]
+    fn it_searches_graph_of_the_primary_converter_if_topologies_differ() {
+        let spatial = mock_converter("spatial");
+        let combined = CombinedConverter::from_str_and_pixel_map(
+            &mock_textured_quadrant_image(),
+            "0.7*spatial+0.3*window:6",
+        )
+        .unwrap();
+        let pheromone = uniform_pheromone(&combined);
+
+        assert!(!combined.shares_topology());
+        assert_eq!(combined.img_to_graph(), spatial.img_to_graph());
+
+        // mask of the primary converter is translated to windows
+        let window = mock_converter("window:6");
+        let primary_mask = spatial.visualize_pheromone(&pheromone);
+        let translated = window.pheromone_from_mask(&primary_mask).unwrap();
+        let expected = primary_mask.blend(&window.visualize_pheromone(&translated), 0.3);
+        let visualization = combined.visualize_pheromone(&pheromone);
+
+        assert_eq!((visualization.width, visualization.height), (8, 8));
+        assert_eq!(visualization, expected);
+    }
+
+    #[test]
+    fn it_translates_mask_of_the_primary_converter() {
+        let spatial = mock_converter("spatial");
+        let tian = mock_converter("tian");
+        let combined = mock_converter("mix:1*spatial+1*tian");
+        let pheromone = uniform_pheromone(&combined);
+
+        let primary_mask = spatial.visualize_pheromone(&pheromone);
+        let translated = tian.pheromone_from_mask(&primary_mask).unwrap();
+        let expected = primary_mask.blend(&tian.visualize_pheromone(&translated), 0.5);
+
+        assert_eq!(combined.visualize_pheromone(&pheromone), expected);
+        assert!(combined.pheromone_from_mask(&primary_mask).is_some());
+    }
+
+    #[test]
+    fn it_equals_the_single_combined_converter() {
+        let window = mock_converter("window:6");
+        let combined = mock_converter("mix:2*window:6");
+        let pheromone = uniform_pheromone(&window);
+
+        assert_eq!(combined.img_to_graph(), window.img_to_graph());
+        assert_eq!(
+            combined.visualize_pheromone(&pheromone),
+            window.visualize_pheromone(&pheromone)
+        );
+        assert_eq!(
+            combined.visualize_conversion(),
+            window.visualize_conversion()
+        );
+    }
+}
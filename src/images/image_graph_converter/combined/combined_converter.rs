@@ -0,0 +1,198 @@
+use crate::ant_colony::graph::Graph;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::{ConverterStringConfig, Converters, FromStrAndPixelMap, ImageGraphConverter};
+
+/// Higher order converter, which combines cues of several converters with given weights.
+///
+/// The first converter is the primary one, ants search its graph.
+/// If all converters share its topology (same edge keys), graph distances
+/// and pheromone visualizations are blended. Otherwise the mask of the primary
+/// converter is translated to the others via `pheromone_from_mask`, so mixes
+/// of differing topologies are rejected unless every converter supports it.
+pub struct CombinedConverter {
+    /// normalized to sum up to 1
+    components: Vec<(f32, Converters)>,
+    graph: Graph,
+    shares_topology: bool,
+}
+
+impl CombinedConverter {
+    /// Expects at least one component with positive weight
+    pub fn new(components: Vec<(f32, Converters)>) -> Self {
+        let total_weight: f32 = components.iter().map(|(weight, _)| weight).sum();
+        let components = components
+            .into_iter()
+            .map(|(weight, converter)| (weight / total_weight, converter))
+            .collect::<Vec<_>>();
+
+        let graphs = components
+            .iter()
+            .map(|(_, converter)| converter.img_to_graph())
+            .collect::<Vec<_>>();
+        let shares_topology = graphs
+            .iter()
+            .all(|graph| graph.get_edge_index() == graphs[0].get_edge_index());
+
+        let weights = components.iter().map(|(weight, _)| *weight).collect();
+        let graph = iif!(
+            shares_topology,
+            Self::blend_distances(graphs, weights),
+            graphs.into_iter().next().expect("no converters to combine")
+        );
+
+        CombinedConverter {
+            components,
+            graph,
+            shares_topology,
+        }
+    }
+
+    /// Returns None if topologies differ and some converter can not translate
+    /// masks of the image, as the primary one visualizes pheromone over it
+    pub fn from_configs(
+        pixel_map: &PixelMap,
+        configs: &[(f32, ConverterStringConfig)],
+    ) -> Option<Self> {
+        let components = configs
+            .iter()
+            .map(|(weight, config)| {
+                Converters::from_string_config_and_pixel_map(pixel_map, config)
+                    .map(|converter| (*weight, converter))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        if components.is_empty() {
+            return None;
+        }
+
+        let combined = Self::new(components);
+        let translates_masks = combined
+            .components
+            .iter()
+            .all(|(_, converter)| converter.pheromone_from_mask(pixel_map).is_some());
+
+        iif!(
+            combined.shares_topology || translates_masks,
+            Some(combined),
+            None
+        )
+    }
+
+    pub fn shares_topology(&self) -> bool {
+        self.shares_topology
+    }
+
+    /// Distances of each graph are rescaled to the average edge length
+    /// of the primary graph first, so they are of comparable magnitude.
+    /// Graphs of zero average edge length are kept as they are.
+    fn blend_distances(graphs: Vec<Graph>, weights: Vec<f32>) -> Graph {
+        let primary_avg = graphs[0].avg_edge_length();
+        let scales = graphs
+            .iter()
+            .map(|graph| {
+                let avg = graph.avg_edge_length();
+
+                iif!(avg > 0.0 && primary_avg > 0.0, primary_avg / avg, 1.0)
+            })
+            .collect::<Vec<_>>();
+
+        graphs[0].clone().map_distances(|edge| {
+            graphs
+                .iter()
+                .zip(weights.iter().zip(scales.iter()))
+                .map(|(graph, (weight, scale))| {
+                    let distance = graph.get_edge(edge).map_or(edge.distance, |e| e.distance);
+
+                    weight * scale * distance
+                })
+                .sum()
+        })
+    }
+
+    /// Weighted mean of the maps, weights do not need to be normalized
+    fn blend_maps<I: Iterator<Item = (f32, PixelMap)>>(maps: I) -> Option<PixelMap> {
+        maps.fold(
+            Option::None,
+            |blended: Option<(f32, PixelMap)>, (weight, map)| match blended {
+                Some((total_weight, blended_map)) => {
+                    let total_weight = total_weight + weight;
+
+                    Some((total_weight, blended_map.blend(&map, weight / total_weight)))
+                }
+                None => Some((weight, map)),
+            },
+        )
+        .map(|(_, blended_map)| blended_map)
+    }
+
+    fn visualize_component(
+        &self,
+        converter: &Converters,
+        pheromone: &Pheromone,
+        primary_mask: &PixelMap,
+    ) -> PixelMap {
+        if self.shares_topology {
+            return converter.visualize_pheromone(pheromone);
+        }
+
+        // mixes with converters unable to translate masks are rejected on creation
+        converter
+            .pheromone_from_mask(primary_mask)
+            .map(|translated| converter.visualize_pheromone(&translated))
+            .unwrap_or_else(|| primary_mask.clone())
+    }
+}
+
+impl ImageGraphConverter for CombinedConverter {
+    fn img_to_graph(&self) -> Graph {
+        self.graph.clone()
+    }
+
+    fn visualize_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        let (primary_weight, primary) = &self.components[0];
+        let primary_mask = primary.visualize_pheromone(pheromone);
+
+        let other_masks = self.components[1..].iter().map(|(weight, converter)| {
+            (
+                *weight,
+                self.visualize_component(converter, pheromone, &primary_mask),
+            )
+        });
+
+        Self::blend_maps(
+            std::iter::once((*primary_weight, primary_mask.clone())).chain(other_masks),
+        )
+        .unwrap_or(primary_mask)
+    }
+
+    fn visualize_conversion(&self) -> Option<PixelMap> {
+        Self::blend_maps(self.components.iter().filter_map(|(weight, converter)| {
+            converter
+                .visualize_conversion()
+                .map(|conversion| (*weight, conversion))
+        }))
+    }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        self.components
+            .iter()
+            .find_map(|(_, converter)| converter.visualize_segments())
+    }
+
+    /// graph is always of the primary converter's structure
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        self.components[0].1.pheromone_from_mask(mask)
+    }
+}
+
+impl FromStrAndPixelMap for CombinedConverter {
+    /// Options in format `<weight>*<converter>[+<weight>*<converter>...]`,
+    /// e.g. `0.7*i:spatial+0.3*window:20`
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let configs = ConverterStringConfig::parse_weighted(opts).ok()?;
+
+        Self::from_configs(pixel_map, &configs)
+    }
+}
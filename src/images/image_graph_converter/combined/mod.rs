@@ -0,0 +1,4 @@
+mod _tests;
+mod combined_converter;
+
+pub use combined_converter::CombinedConverter;
@@ -1,19 +1,30 @@
+mod _tests;
 mod _union;
+mod combined;
+mod region_adjacency;
 mod segments_to_edge;
 mod spatial;
 
+use std::fmt::Display;
 use std::str::FromStr;
 
 use crate::ant_colony::graph::Graph;
 use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::split_once;
 use crate::images::pixel_map::PixelMap;
 
 pub use _union::Converters;
+pub use combined::CombinedConverter;
+pub use region_adjacency::RegionAdjacencyConverter;
+pub use segments_to_edge::FelzenszwalbConverter;
 pub use segments_to_edge::KMeansConverter;
+pub use segments_to_edge::QuadtreeConverter;
 pub use segments_to_edge::SuperPixelConverter;
 pub use segments_to_edge::WindowToEdgeConverter;
+pub use spatial::GradientConverter;
 pub use spatial::SpatialEdgeChangeConverter;
 pub use spatial::SpatialImageGraphConverter;
+pub use spatial::TianEdgeDetectionConverter;
 
 pub trait ImageGraphConverter: FromStrAndPixelMap {
     /// image in any form is expected to be passed via constructor
@@ -29,6 +40,17 @@ pub trait ImageGraphConverter: FromStrAndPixelMap {
     fn visualize_conversion(&self) -> Option<PixelMap> {
         None
     }
+
+    /// colour-coded segmentation of the image, for converters mapping segments to edges
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        None
+    }
+
+    /// inverse of `visualize_pheromone`, trails in [0.0, 1.0] reconstructed from a mask
+    /// of the same size as the converted image, used to seed pheromone from a coarser image
+    fn pheromone_from_mask(&self, _mask: &PixelMap) -> Option<Pheromone> {
+        None
+    }
 }
 
 pub trait FromStrAndPixelMap: Sized {
@@ -41,7 +63,33 @@ pub enum ConverterStringConfig {
     WindowToEdge(String),
     KMeans(String),
     SuperPixels(String),
+    Felzenszwalb(String),
+    Quadtree(String),
+    RegionAdjacency(String),
+    TianEdgeDetection(String),
+    Gradient(String),
     Inverted(Box<ConverterStringConfig>),
+    Combined(Vec<(f32, ConverterStringConfig)>),
+}
+
+impl ConverterStringConfig {
+    /// Parses weighted converters separated with `+`, e.g. `0.7*i:spatial+0.3*window:20`
+    pub fn parse_weighted(s: &str) -> Result<Vec<(f32, Self)>, &'static str> {
+        s.split('+')
+            .map(|component| {
+                let (weight, config) = split_once(component, "*")
+                    .filter(|_| component.matches('*').count() == 1)
+                    .ok_or("Failed to parse weighted converter")?;
+                let weight = weight
+                    .parse::<f32>()
+                    .ok()
+                    .filter(|weight| weight.is_finite() && *weight > 0.0)
+                    .ok_or("Failed to parse converter weight")?;
+
+                Self::from_str(config).map(|config| (weight, config))
+            })
+            .collect()
+    }
 }
 
 impl FromStr for ConverterStringConfig {
@@ -54,6 +102,10 @@ impl FromStr for ConverterStringConfig {
                 .map(Self::Inverted);
         }
 
+        if let Some(components) = s.strip_prefix("mix:") {
+            return Self::parse_weighted(components).map(Self::Combined);
+        }
+
         let mut config_iter = s.split(":");
         let name = config_iter.next().unwrap_or_default();
         let opts = config_iter.next().map(String::from).unwrap_or_default();
@@ -63,6 +115,11 @@ impl FromStr for ConverterStringConfig {
             "window" => Some(Self::WindowToEdge(opts)),
             "kmeans" => Some(Self::KMeans(opts)),
             "superpixels" => Some(Self::SuperPixels(opts)),
+            "felzenszwalb" => Some(Self::Felzenszwalb(opts)),
+            "quadtree" => Some(Self::Quadtree(opts)),
+            "rag" => Some(Self::RegionAdjacency(opts)),
+            "tian" => Some(Self::TianEdgeDetection(opts)),
+            "gradient" => Some(Self::Gradient(opts)),
             _ => None,
         }
         .ok_or("Failed to parse image converter type")
@@ -76,7 +133,49 @@ impl ToString for ConverterStringConfig {
             Self::WindowToEdge(opts) => format!("window:{}", opts),
             Self::KMeans(opts) => format!("kmeans:{}", opts),
             Self::SuperPixels(opts) => format!("superpixels:{}", opts),
+            Self::Felzenszwalb(opts) => format!("felzenszwalb:{}", opts),
+            Self::Quadtree(opts) => format!("quadtree:{}", opts),
+            Self::RegionAdjacency(opts) => format!("rag:{}", opts),
+            Self::TianEdgeDetection(opts) => format!("tian:{}", opts),
+            Self::Gradient(opts) => format!("gradient:{}", opts),
             Self::Inverted(opts) => format!("i:{}", opts.to_string()),
+            Self::Combined(components) => format!(
+                "mix:{}",
+                components
+                    .iter()
+                    .map(|(weight, config)| format!("{}*{}", weight, config.to_string()))
+                    .collect::<Vec<_>>()
+                    .join("+")
+            ),
         }
     }
 }
+
+/// Converters of multi-objective search, separated with `;`, e.g. `spatial;i:spatial`
+#[derive(Debug, Clone)]
+pub struct ObjectivesStringConfig(pub Vec<ConverterStringConfig>);
+
+impl FromStr for ObjectivesStringConfig {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(';')
+            .map(ConverterStringConfig::from_str)
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
+impl Display for ObjectivesStringConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            self.0
+                .iter()
+                .map(ConverterStringConfig::to_string)
+                .collect::<Vec<_>>()
+                .join(";")
+        )
+    }
+}
@@ -0,0 +1,137 @@
+#[cfg(test)]
+mod region_adjacency_converter_tests {
+    use itertools::Itertools;
+
+    use crate::ant_colony::graph::EdgeMode;
+    use crate::ant_colony::pheromone::Pheromone;
+    use crate::images::image::Pixel;
+
+    use super::super::super::_tests::mock_textured_quadrant_image;
+    use super::super::super::segments_to_edge::{SegmentCost, SlicParams};
+    use super::super::super::{FromStrAndPixelMap, ImageGraphConverter, RegionAdjacencyConverter};
+    use super::super::Segmentation;
+
+    fn mock_converter() -> RegionAdjacencyConverter {
+        RegionAdjacencyConverter::new(
+            &mock_textured_quadrant_image(),
+            Segmentation::Windows,
+            4,
+            SegmentCost::Variance,
+        )
+    }
+
+    #[test]
+    fn it_connects_only_segments_sharing_a_border() {
+        let graph = mock_converter().img_to_graph();
+
+        let connections = graph
+            .get_all_edges()
+            .iter()
+            .map(|edge| (edge.from, edge.to))
+            .sorted()
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            connections,
+            vec![
+                (0, 1),
+                (0, 2),
+                (1, 0),
+                (1, 3),
+                (2, 0),
+                (2, 3),
+                (3, 1),
+                (3, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn it_assigns_edges_distance_of_segment_they_lead_to() {
+        let graph = mock_converter().img_to_graph();
+        let distance = |from, to| {
+            graph
+                .get_edge(EdgeMode::Directed.generate_key(from, to))
+                .unwrap()
+                .distance
+        };
+
+        assert_delta!(distance(1, 3), distance(2, 3));
+        assert_delta!(distance(3, 1), 1.0 / stability_factor!());
+        assert!(distance(1, 3) < distance(3, 1));
+    }
+
+    #[test]
+    fn it_visualizes_trails_entering_segments() {
+        let converter = mock_converter();
+        let key = |from, to| EdgeMode::Directed.generate_key(from, to);
+        let pheromone = Pheromone::from_values(map!(
+            key(0, 1) => 0.5,
+            key(0, 2) => 0.0,
+            key(1, 0) => 0.0,
+            key(1, 3) => 1.0,
+            key(2, 0) => 0.0,
+            key(2, 3) => 1.0,
+            key(3, 1) => 0.0,
+            key(3, 2) => 0.0
+        ));
+
+        let result = converter.visualize_pheromone(&pheromone);
+        let level_at = |x: usize, y: usize| result.pixels()[y * 8 + x].r;
+
+        assert_eq!(level_at(0, 0), 0);
+        assert_eq!(level_at(7, 0), 63);
+        assert_eq!(level_at(0, 7), 0);
+        assert_eq!(level_at(7, 7), 255);
+    }
+
+    #[test]
+    fn it_restores_trails_entering_segments_from_mask() {
+        let converter = mock_converter();
+        let mask = mock_textured_quadrant_image()
+            .map(|px| Pixel::grey(px.x, px.y, iif!(px.x < 4, 255, 51)));
+
+        let pheromone = converter.pheromone_from_mask(&mask).unwrap();
+
+        // every segment is entered by two edges
+        assert_delta!(
+            pheromone.get_pheromone_for_edge(EdgeMode::Directed.generate_key(1, 0)),
+            0.5
+        );
+        assert_delta!(
+            pheromone.get_pheromone_for_edge(EdgeMode::Directed.generate_key(0, 1)),
+            0.1
+        );
+        assert_eq!(converter.visualize_pheromone(&pheromone), mask);
+    }
+
+    #[test]
+    fn it_labels_segments_consecutively() {
+        let params = SlicParams::default();
+        let labels = Segmentation::SuperPixels(params).label(&mock_textured_quadrant_image(), 9);
+        let n_labels = labels.iter().unique().count();
+
+        assert_eq!(labels.iter().max(), Some(&(n_labels - 1)));
+        assert_eq!(
+            Segmentation::Windows.label(&mock_textured_quadrant_image(), 4)[..8],
+            [0, 0, 0, 0, 1, 1, 1, 1]
+        );
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let image = mock_textured_quadrant_image();
+
+        for opts in &[
+            "windows,4",
+            "windows,4,entropy",
+            "superpixels,9,slico,m=10,lbp",
+        ] {
+            assert!(RegionAdjacencyConverter::from_str_and_pixel_map(&image, opts).is_some());
+        }
+
+        for opts in &["", "windows", "windows,1", "windows,4,slico", "foo,4"] {
+            assert!(RegionAdjacencyConverter::from_str_and_pixel_map(&image, opts).is_none());
+        }
+    }
+}
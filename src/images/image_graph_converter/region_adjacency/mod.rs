@@ -0,0 +1,6 @@
+mod _tests;
+mod region_adjacency_converter;
+mod segmentation;
+
+pub use region_adjacency_converter::RegionAdjacencyConverter;
+pub use segmentation::Segmentation;
@@ -0,0 +1,227 @@
+use itertools::Itertools;
+use std::collections::BTreeSet;
+
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
+use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::compare_float;
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::segments_to_edge::{SegmentCost, SegmentId};
+use super::super::{FromStrAndPixelMap, ImageGraphConverter};
+use super::Segmentation;
+
+/// Region adjacency graph converter.
+///
+/// Segments of the image are nodes and segments sharing a border
+/// (in 4-neighbourhood of their pixels) are adjacent, so unlike converters mapping
+/// segments onto edges of a complete graph, only spatially related regions are neighbours.
+///
+/// Desirability belongs to segments, so graph is directed and each edge takes
+/// the distance of the segment it leads to, trail of a segment is the sum of trails
+/// of edges entering it (same as in `TianEdgeDetectionConverter`).
+pub struct RegionAdjacencyConverter {
+    image: PixelMap,
+    labels: Vec<SegmentId>,
+    distances: Vec<f32>,
+    graph: Graph,
+}
+
+impl RegionAdjacencyConverter {
+    pub fn new(
+        pixel_map: &PixelMap,
+        segmentation: Segmentation,
+        n_segments: usize,
+        cost: SegmentCost,
+    ) -> Self {
+        let labels = segmentation.label(pixel_map, n_segments);
+        let distances = Self::calc_segment_distances(pixel_map, &labels, cost);
+        let adjacent_segments = Self::find_adjacent_segments(pixel_map, &labels);
+
+        RegionAdjacencyConverter {
+            image: pixel_map.clone(),
+            graph: Self::construct_graph(&adjacent_segments, &distances),
+            labels,
+            distances,
+        }
+    }
+
+    pub fn get_labels(&self) -> &[SegmentId] {
+        &self.labels
+    }
+
+    fn calc_segment_distances(
+        pixel_map: &PixelMap,
+        labels: &[SegmentId],
+        cost: SegmentCost,
+    ) -> Vec<f32> {
+        let pixels_by_segment_id = labels
+            .iter()
+            .cloned()
+            .zip(pixel_map.pixels().iter().cloned())
+            .into_group_map();
+
+        (0..pixels_by_segment_id.len())
+            .map(|segment_id| cost.calc_distance(&pixels_by_segment_id[&segment_id]))
+            .collect()
+    }
+
+    /// Unique pairs (lower id first) of segments sharing a border
+    fn find_adjacent_segments(
+        pixel_map: &PixelMap,
+        labels: &[SegmentId],
+    ) -> BTreeSet<(SegmentId, SegmentId)> {
+        pixel_map
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let label = labels[pixel.y * pixel_map.width + pixel.x];
+
+                [(1, 0), (0, 1)].iter().filter_map(move |(dx, dy)| {
+                    let neighbour = pixel_map.get_pixel_by_delta(pixel.x, pixel.y, *dx, *dy)?;
+                    let neighbour_label = labels[neighbour.y * pixel_map.width + neighbour.x];
+
+                    iif!(
+                        label == neighbour_label,
+                        None,
+                        Some((label.min(neighbour_label), label.max(neighbour_label)))
+                    )
+                })
+            })
+            .collect()
+    }
+
+    fn construct_graph(
+        adjacent_segments: &BTreeSet<(SegmentId, SegmentId)>,
+        distances: &[f32],
+    ) -> Graph {
+        let edges = adjacent_segments
+            .iter()
+            .flat_map(|(segment_a, segment_b)| {
+                let (node_a, node_b) = (*segment_a as NodeId, *segment_b as NodeId);
+
+                vec![
+                    AdjacencyListEntry::new_directed(node_a, node_b, distances[*segment_b]),
+                    AdjacencyListEntry::new_directed(node_b, node_a, distances[*segment_a]),
+                ]
+            })
+            .collect();
+
+        Graph::from_directed_edges(edges)
+    }
+
+    fn calc_segment_trails(&self, pheromone: &Pheromone) -> Vec<f32> {
+        self.graph.get_all_edges().iter().fold(
+            vec![0.0; self.distances.len()],
+            |mut trails, edge| {
+                trails[edge.to as usize] += pheromone.get_pheromone_for_edge(edge);
+
+                trails
+            },
+        )
+    }
+}
+
+impl ImageGraphConverter for RegionAdjacencyConverter {
+    fn img_to_graph(&self) -> Graph {
+        self.graph.clone()
+    }
+
+    fn visualize_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        let trails = self.calc_segment_trails(pheromone);
+
+        let max = trails
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .filter(|max| *max > 0.0)
+            .unwrap_or(1.0);
+
+        self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::grey(px.x, px.y, (255.0 * trails[segment_id] / max) as u8)
+        })
+    }
+
+    /// Inverse of `visualize_pheromone`, trail of a segment is the mean intensity
+    /// of its pixels, split evenly between edges entering it
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        if self.labels.len() != mask.pixels().len() {
+            return None;
+        }
+
+        let levels_by_segment_id = self
+            .labels
+            .iter()
+            .cloned()
+            .zip(mask.pixels().iter().map(|px| f32::from(px.r)))
+            .into_group_map();
+        let edges = self.graph.get_all_edges();
+        let in_degrees = edges.iter().map(|edge| edge.to).counts();
+
+        let pheromone = edges.iter().fold(
+            Pheromone::with_edge_index(self.graph.get_edge_index()),
+            |pheromone, edge| {
+                let levels = &levels_by_segment_id[&(edge.to as SegmentId)];
+                let mean_level = levels.iter().sum::<f32>() / levels.len() as f32;
+                let trail = mean_level / (255.0 * in_degrees[&edge.to] as f32);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, trail)
+            },
+        );
+
+        Some(pheromone)
+    }
+
+    fn visualize_conversion(&self) -> Option<PixelMap> {
+        let distance_max = self
+            .distances
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .unwrap_or(1.0);
+
+        // segments with less distance are highlighted
+        Some(self.image.map_with_labels(&self.labels, |px, segment_id| {
+            let intensity = 255.0 * (1.0 - self.distances[segment_id] / distance_max);
+
+            Pixel::grey(px.x, px.y, intensity as u8)
+        }))
+    }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        Some(self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::from_label(px.x, px.y, segment_id)
+        }))
+    }
+}
+
+impl FromStrAndPixelMap for RegionAdjacencyConverter {
+    /// Options in format `<segmentation>,<n_segments>[,<option>...]`, where option is either
+    /// segment cost or option of the segmentation, e.g. `superpixels,200,entropy,slico`
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let mut opts_iter = opts.split(',');
+        let segmentation = opts_iter.next()?.parse::<Segmentation>().ok()?;
+        let n_segments = opts_iter
+            .next()?
+            .parse()
+            .ok()
+            .filter(|n_segments| *n_segments > 1)?;
+
+        let (cost, segmentation) = opts_iter.try_fold(
+            (SegmentCost::default(), segmentation),
+            |(cost, segmentation), option| match option.parse::<SegmentCost>() {
+                Ok(cost) => Some((cost, segmentation)),
+                Err(_) => segmentation
+                    .with_option(option)
+                    .map(|segmentation| (cost, segmentation)),
+            },
+        )?;
+
+        Some(RegionAdjacencyConverter::new(
+            pixel_map,
+            segmentation,
+            n_segments,
+            cost,
+        ))
+    }
+}
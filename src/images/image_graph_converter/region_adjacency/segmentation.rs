@@ -0,0 +1,94 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::common::utils::balanced_divisors;
+use crate::images::pixel_map::{PixelMap, PixelMapWindows};
+
+use super::super::segments_to_edge::{SegmentId, SlicParams};
+
+/// Segmentation, which provides nodes of the region adjacency graph
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Segmentation {
+    /// grid of non-overlapping windows, as in `WindowToEdgeConverter`
+    Windows,
+    /// SLIC superpixels, as in `SuperPixelConverter`
+    SuperPixels(SlicParams),
+}
+
+impl Segmentation {
+    /// Labels of pixels, consecutive from 0 in order of first appearance
+    pub fn label(&self, pixel_map: &PixelMap, n_segments: usize) -> Vec<SegmentId> {
+        let labels = match self {
+            Self::Windows => Self::label_windows(pixel_map, n_segments),
+            Self::SuperPixels(params) => params.run(pixel_map, n_segments),
+        };
+
+        Self::compact_labels(labels)
+    }
+
+    /// Applies single option of the segmentation, only superpixels accept any (SLIC parameters)
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        match self {
+            Self::Windows => None,
+            Self::SuperPixels(params) => params.with_option(option).map(Self::SuperPixels),
+        }
+    }
+
+    fn label_windows(pixel_map: &PixelMap, n_segments: usize) -> Vec<SegmentId> {
+        let (n_y_windows, n_x_windows) = balanced_divisors(n_segments);
+        let mut labels = vec![0; pixel_map.pixels().len()];
+
+        let offsets = PixelMapWindows::generate_offsets(
+            pixel_map.width,
+            pixel_map.height,
+            n_x_windows,
+            n_y_windows,
+        );
+
+        for (window_idx, (row_from, _, row_to), (col_from, _, col_to)) in offsets {
+            for y in row_from..=row_to {
+                for x in col_from..=col_to {
+                    labels[y * pixel_map.width + x] = window_idx;
+                }
+            }
+        }
+
+        labels
+    }
+
+    /// segmentations may leave some labels unused (e.g. SLIC clusters absorbed by neighbours)
+    fn compact_labels(labels: Vec<SegmentId>) -> Vec<SegmentId> {
+        let mut compacted: HashMap<SegmentId, SegmentId> = HashMap::new();
+
+        labels
+            .into_iter()
+            .map(|label| {
+                let next_label = compacted.len();
+
+                *compacted.entry(label).or_insert(next_label)
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Segmentation {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "windows" => Ok(Self::Windows),
+            "superpixels" => Ok(Self::SuperPixels(SlicParams::default())),
+            _ => Err("Failed to parse segmentation"),
+        }
+    }
+}
+
+impl Display for Segmentation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Windows => write!(f, "windows"),
+            Self::SuperPixels(_) => write!(f, "superpixels"),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod felzenszwalb_converter_tests {
+    use itertools::Itertools;
+
+    use super::super::super::super::_tests::mock_quadrant_image;
+    use super::super::super::super::{
+        FelzenszwalbConverter, FromStrAndPixelMap, ImageGraphConverter,
+    };
+
+    fn quadrant_labels(converter: &FelzenszwalbConverter) -> Vec<usize> {
+        [(0, 0), (15, 0), (0, 15), (15, 15)]
+            .iter()
+            .map(|(x, y)| converter.get_labels()[y * 16 + x])
+            .collect()
+    }
+
+    #[test]
+    fn it_follows_colour_boundaries() {
+        let converter = FelzenszwalbConverter::new(&mock_quadrant_image(), 4, 10.0, 1);
+        let labels = converter.get_labels();
+
+        assert_eq!(labels.iter().unique().count(), 4);
+        assert_eq!(quadrant_labels(&converter), vec![0, 1, 2, 3]);
+        assert!(mock_quadrant_image().pixels().iter().all(|px| {
+            let quadrant = iif!(px.x < 8, 0, 1) + iif!(px.y < 8, 0, 2);
+
+            labels[px.y * 16 + px.x] == quadrant
+        }));
+    }
+
+    #[test]
+    fn it_merges_segments_down_to_number_of_edges() {
+        let converter = FelzenszwalbConverter::new(&mock_quadrant_image(), 2, 10.0, 1);
+
+        assert!(converter.get_labels().iter().all(|label| *label == 0));
+        assert_eq!(converter.img_to_graph().get_amount_of_edges(), 2);
+    }
+
+    #[test]
+    fn it_merges_segments_smaller_than_min_size() {
+        let image = mock_quadrant_image().map(|px| iif!(px.x == 3 && px.y == 3, px.invert(), *px));
+
+        let fine = FelzenszwalbConverter::new(&image, 4, 10.0, 1);
+        let coarse = FelzenszwalbConverter::new(&image, 4, 10.0, 2);
+
+        assert_eq!(fine.get_labels().iter().unique().count(), 5);
+        assert_eq!(coarse.get_labels().iter().unique().count(), 4);
+    }
+
+    #[test]
+    fn it_leaves_out_edges_without_segments() {
+        let converter = FelzenszwalbConverter::new(&mock_quadrant_image(), 4, 10.0, 1);
+
+        // 4 segments out of 6 possible edges, both directions of each
+        assert_eq!(converter.img_to_graph().get_amount_of_edges(), 8);
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let image = mock_quadrant_image();
+
+        assert!(FelzenszwalbConverter::from_str_and_pixel_map(&image, "4,300,20").is_some());
+
+        for opts in &["", "4", "4,300", "1,300,20", "4,-1,20", "4,300,20,1"] {
+            assert!(FelzenszwalbConverter::from_str_and_pixel_map(&image, opts).is_none());
+        }
+    }
+}
@@ -1,3 +1,8 @@
+mod felzenszwalb_converter;
+mod quadtree_converter;
+mod segment_cost;
+mod superpixel_converter;
+
 #[cfg(test)]
 mod window_to_edge_converter_tests {
     use crate::ant_colony::graph::{AdjacencyListEntry, Graph, Node};
@@ -201,6 +206,33 @@ mod window_to_edge_converter_tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn it_restores_pheromone_from_visualized_mask() {
+        let converter = WindowToEdgeConverter::new(&mock_image(), 6);
+        let pheromone = mock_pheromone();
+        let mask = converter.visualize_pheromone(&pheromone);
+
+        let restored = converter.pheromone_from_mask(&mask).unwrap();
+
+        assert_eq!(restored.get_values().len(), 15);
+        assert!(pheromone
+            .iter()
+            .all(
+                |(key, value)| (restored.get_pheromone_for_edge(key) - value).abs() < 1.0 / 255.0
+            ));
+        assert_eq!(converter.visualize_pheromone(&restored), mask);
+    }
+
+    #[test]
+    fn it_does_not_restore_pheromone_from_mask_of_other_size() {
+        let converter = WindowToEdgeConverter::new(&mock_image(), 6);
+        let mask = converter
+            .visualize_pheromone(&mock_pheromone())
+            .resize(3, 3);
+
+        assert!(converter.pheromone_from_mask(&mask).is_none());
+    }
+
     #[test]
     fn it_build_correct_window_idx_lookup() {
         let n_nodes = 6;
@@ -0,0 +1,107 @@
+#[cfg(test)]
+mod quadtree_converter_tests {
+    use crate::images::image::Pixel;
+    use crate::images::pixel_map::PixelMap;
+
+    use super::super::super::super::_tests::mock_textured_image;
+    use super::super::super::super::{FromStrAndPixelMap, ImageGraphConverter, QuadtreeConverter};
+    use super::super::super::quadtree_converter::QuadtreeBlock;
+
+    #[test]
+    fn it_splits_block_into_quadrants() {
+        let block = QuadtreeBlock::new(2, 4, 5, 3);
+
+        assert_eq!(
+            block.split(false),
+            vec![
+                QuadtreeBlock::new(2, 4, 2, 1),
+                QuadtreeBlock::new(4, 4, 3, 1),
+                QuadtreeBlock::new(2, 5, 2, 2),
+                QuadtreeBlock::new(4, 5, 3, 2),
+            ]
+        );
+    }
+
+    #[test]
+    fn it_splits_block_into_halves_along_longer_side() {
+        assert_eq!(
+            QuadtreeBlock::new(0, 0, 4, 2).split(true),
+            vec![
+                QuadtreeBlock::new(0, 0, 2, 2),
+                QuadtreeBlock::new(2, 0, 2, 2)
+            ]
+        );
+        assert_eq!(
+            QuadtreeBlock::new(0, 0, 1, 3).split(true),
+            vec![
+                QuadtreeBlock::new(0, 0, 1, 1),
+                QuadtreeBlock::new(0, 1, 1, 2)
+            ]
+        );
+        assert!(!QuadtreeBlock::new(0, 0, 1, 1).is_splittable());
+    }
+
+    #[test]
+    fn it_covers_image_with_exact_number_of_leaves() {
+        let image = mock_textured_image(16, |x, _| x >= 8);
+
+        for n_nodes in 2..8 {
+            let converter = QuadtreeConverter::new(&image, n_nodes);
+            let leaves = converter.get_leaves();
+            let area: usize = leaves.iter().map(|block| block.width * block.height).sum();
+
+            assert_eq!(leaves.len(), n_nodes * (n_nodes - 1) / 2);
+            assert_eq!(area, 16 * 16);
+            assert_eq!(
+                converter.img_to_graph().get_amount_of_edges(),
+                2 * leaves.len()
+            );
+        }
+    }
+
+    #[test]
+    fn it_splits_complex_areas_first() {
+        let converter = QuadtreeConverter::new(&mock_textured_image(16, |x, _| x >= 8), 4);
+
+        let flat_leaves = converter
+            .get_leaves()
+            .iter()
+            .filter(|block| block.x < 8)
+            .collect::<Vec<_>>();
+
+        assert_eq!(flat_leaves.len(), 2);
+        assert!(flat_leaves
+            .iter()
+            .all(|block| block.width * block.height == 64));
+    }
+
+    #[test]
+    fn it_stops_splitting_at_single_pixels() {
+        let image = PixelMap::new(
+            2,
+            2,
+            vec![
+                Pixel::grey(0, 0, 0),
+                Pixel::grey(1, 0, 255),
+                Pixel::grey(0, 1, 255),
+                Pixel::grey(1, 1, 0),
+            ],
+        );
+        let converter = QuadtreeConverter::new(&image, 4);
+
+        assert_eq!(converter.get_leaves().len(), 4);
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let image = mock_textured_image(16, |x, _| x >= 8);
+
+        for opts in &["4", "4,entropy", "4,homogeneity"] {
+            assert!(QuadtreeConverter::from_str_and_pixel_map(&image, opts).is_some());
+        }
+
+        for opts in &["", "1", "4,foo"] {
+            assert!(QuadtreeConverter::from_str_and_pixel_map(&image, opts).is_none());
+        }
+    }
+}
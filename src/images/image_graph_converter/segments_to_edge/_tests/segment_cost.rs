@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod segment_cost_tests {
+    use crate::images::image::Pixel;
+    use crate::images::pixel_map::PixelMap;
+
+    use super::super::super::super::{
+        FromStrAndPixelMap, ImageGraphConverter, SuperPixelConverter, WindowToEdgeConverter,
+    };
+    use super::super::super::SegmentCost;
+
+    /// 8x8 horizontal gradient, each column brighter by 32 levels
+    fn mock_gradient() -> Vec<Pixel> {
+        (0..8)
+            .flat_map(|y| (0..8).map(move |x| Pixel::grey(x, y, (x * 32) as u8)))
+            .collect()
+    }
+
+    /// 8x8 checkerboard with low contrast
+    fn mock_texture() -> Vec<Pixel> {
+        (0..8)
+            .flat_map(|y| (0..8).map(move |x| Pixel::grey(x, y, iif!((x + y) % 2 == 0, 100, 140))))
+            .collect()
+    }
+
+    #[test]
+    fn it_calculates_entropy_of_intensities() {
+        let uniform = vec![Pixel::grey(0, 0, 10); 4];
+        let distinct = (0..4)
+            .map(|x| Pixel::grey(x, 0, x as u8))
+            .collect::<Vec<_>>();
+
+        assert_delta!(SegmentCost::Entropy.calc_complexity(&uniform), 0.0);
+        assert_delta!(SegmentCost::Entropy.calc_complexity(&distinct), 2.0);
+    }
+
+    #[test]
+    fn it_rates_texture_above_smooth_gradient_unlike_variance() {
+        let (gradient, texture) = (mock_gradient(), mock_texture());
+
+        assert!(
+            SegmentCost::Variance.calc_complexity(&gradient)
+                > SegmentCost::Variance.calc_complexity(&texture)
+        );
+
+        for cost in &[
+            SegmentCost::LocalBinaryPattern,
+            SegmentCost::GlcmContrast,
+            SegmentCost::GlcmHomogeneity,
+        ] {
+            assert!(cost.calc_complexity(&texture) > cost.calc_complexity(&gradient));
+        }
+    }
+
+    #[test]
+    fn it_calculates_texture_descriptors() {
+        let (gradient, texture) = (mock_gradient(), mock_texture());
+
+        // every inner pixel of the gradient has the same pattern
+        assert_delta!(
+            SegmentCost::LocalBinaryPattern.calc_complexity(&gradient),
+            0.0
+        );
+        assert_delta!(
+            SegmentCost::LocalBinaryPattern.calc_complexity(&texture),
+            1.0
+        );
+
+        // horizontal neighbours differ by one level, vertical ones are equal
+        assert_delta!(SegmentCost::GlcmContrast.calc_complexity(&gradient), 0.5);
+        assert_delta!(
+            SegmentCost::GlcmHomogeneity.calc_complexity(&gradient),
+            0.25
+        );
+
+        assert_delta!(SegmentCost::GlcmContrast.calc_complexity(&texture), 1.0);
+        assert_delta!(SegmentCost::GlcmHomogeneity.calc_complexity(&texture), 0.5);
+    }
+
+    #[test]
+    fn it_considers_only_pixels_of_the_segment() {
+        let single_pixel = vec![Pixel::grey(3, 3, 100)];
+
+        assert_delta!(
+            SegmentCost::LocalBinaryPattern.calc_complexity(&single_pixel),
+            0.0
+        );
+        assert_delta!(
+            SegmentCost::GlcmContrast.calc_complexity(&single_pixel),
+            0.0
+        );
+        assert_delta!(
+            SegmentCost::GlcmHomogeneity.calc_complexity(&single_pixel),
+            0.0
+        );
+    }
+
+    #[test]
+    fn it_parses_and_displays_segment_cost() {
+        for cost in &[
+            SegmentCost::Variance,
+            SegmentCost::Entropy,
+            SegmentCost::LocalBinaryPattern,
+            SegmentCost::GlcmContrast,
+            SegmentCost::GlcmHomogeneity,
+        ] {
+            assert_eq!(cost.to_string().parse::<SegmentCost>(), Ok(*cost));
+        }
+
+        assert_eq!("".parse::<SegmentCost>(), Ok(SegmentCost::Variance));
+        assert!("foo".parse::<SegmentCost>().is_err());
+    }
+
+    #[test]
+    fn it_selects_segment_cost_from_converter_options() {
+        let image = PixelMap::new(8, 8, mock_texture());
+
+        let default = WindowToEdgeConverter::from_str_and_pixel_map(&image, "4").unwrap();
+        let entropy = WindowToEdgeConverter::from_str_and_pixel_map(&image, "4,entropy").unwrap();
+
+        assert_eq!(
+            default.img_to_graph(),
+            WindowToEdgeConverter::new(&image, 4).img_to_graph()
+        );
+        assert_eq!(
+            entropy.img_to_graph(),
+            WindowToEdgeConverter::with_cost(&image, 4, SegmentCost::Entropy).img_to_graph()
+        );
+
+        assert!(SuperPixelConverter::from_str_and_pixel_map(&image, "4,lbp").is_some());
+        assert!(WindowToEdgeConverter::from_str_and_pixel_map(&image, "4,foo").is_none());
+        assert!(WindowToEdgeConverter::from_str_and_pixel_map(&image, "foo").is_none());
+    }
+}
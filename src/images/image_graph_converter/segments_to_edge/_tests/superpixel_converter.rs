@@ -0,0 +1,85 @@
+#[cfg(test)]
+mod superpixel_converter_tests {
+    use itertools::Itertools;
+
+    use super::super::super::super::_tests::mock_quadrant_image;
+    use super::super::super::super::{
+        FromStrAndPixelMap, ImageGraphConverter, SuperPixelConverter, WindowToEdgeConverter,
+    };
+    use super::super::super::superpixel_converter::SlicParams;
+
+    #[test]
+    fn it_applies_slic_options() {
+        let params = SlicParams::default()
+            .with_option("m=20")
+            .and_then(|params| params.with_option("iter=3"))
+            .and_then(|params| params.with_option("slico"));
+
+        assert_eq!(
+            params,
+            Some(SlicParams {
+                compactness: 20,
+                n_iterations: 3,
+                adaptive: true,
+            })
+        );
+    }
+
+    #[test]
+    fn it_rejects_invalid_slic_options() {
+        for option in &["m=0", "iter=0", "iter=", "slico=1", "foo"] {
+            assert_eq!(SlicParams::default().with_option(option), None);
+        }
+    }
+
+    #[test]
+    fn it_assigns_every_pixel_to_a_superpixel_in_adaptive_mode() {
+        let image = mock_quadrant_image();
+        let params = SlicParams {
+            adaptive: true,
+            ..SlicParams::default()
+        };
+
+        let labels = params.run(&image, 4);
+
+        assert_eq!(labels.len(), 16 * 16);
+        assert!(labels.iter().all(|label| *label < 4));
+    }
+
+    #[test]
+    fn it_parses_converter_options_in_any_order() {
+        let image = mock_quadrant_image();
+
+        for opts in &["3", "3,entropy", "3,slico,m=10", "3,iter=2,lbp,slico"] {
+            assert!(SuperPixelConverter::from_str_and_pixel_map(&image, opts).is_some());
+        }
+
+        for opts in &["", "3,m=0", "3,foo", "slico"] {
+            assert!(SuperPixelConverter::from_str_and_pixel_map(&image, opts).is_none());
+        }
+    }
+
+    #[test]
+    fn it_visualizes_segments_with_distinct_colours() {
+        let image = mock_quadrant_image();
+        let converter = SuperPixelConverter::from_str_and_pixel_map(&image, "3,slico").unwrap();
+
+        let segments = converter.visualize_segments().unwrap();
+        let n_colours = segments
+            .pixels()
+            .iter()
+            .map(|px| (px.r, px.g, px.b))
+            .unique()
+            .count();
+
+        assert_eq!((segments.width, segments.height), (16, 16));
+        assert!(n_colours > 1 && n_colours <= 3);
+    }
+
+    #[test]
+    fn it_does_not_visualize_segments_of_windows() {
+        let converter = WindowToEdgeConverter::new(&mock_quadrant_image(), 3);
+
+        assert!(converter.visualize_segments().is_none());
+    }
+}
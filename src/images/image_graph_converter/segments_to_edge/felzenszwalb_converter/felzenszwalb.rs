@@ -0,0 +1,114 @@
+use crate::common::utils::{compare_float, DisjointSet};
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+/// Half of the 8-neighbourhood, so each pair of adjacent pixels is visited once
+const NEIGHBOUR_OFFSETS: [(isize, isize); 4] = [(1, 0), (-1, 1), (0, 1), (1, 1)];
+
+/// (pixel_a_idx, pixel_b_idx, colour distance)
+type PixelEdge = (usize, usize, f32);
+
+/// Implementation of Efficient Graph-Based Image Segmentation
+/// http://people.cs.uchicago.edu/~pff/papers/seg-ijcv.pdf
+///
+/// Pixels are nodes of 8-connected grid graph weighted with colour distance,
+/// edges are processed from the lightest one and two segments are merged
+/// unless the edge is heavier than internal difference + k / size of any of them.
+/// Gaussian smoothing of the original method is omitted.
+///
+/// Segments smaller than min_size are merged with their neighbours,
+/// afterwards segments are merged along the lightest edges until
+/// there are at most max_segments of them.
+/// Returns list of labels in vector of size equal to vector of pixels.
+pub struct Felzenszwalb {
+    k: f32,
+    min_size: usize,
+    max_segments: usize,
+}
+
+impl Felzenszwalb {
+    pub fn new(k: f32, min_size: usize, max_segments: usize) -> Self {
+        Felzenszwalb {
+            k,
+            min_size,
+            max_segments,
+        }
+    }
+
+    pub fn segment(&self, pixel_map: &PixelMap) -> Vec<usize> {
+        let edges = Self::build_sorted_edges(pixel_map);
+        let mut segments = DisjointSet::new(pixel_map.pixels().len());
+        let mut internal_diffs = vec![0.0; pixel_map.pixels().len()];
+
+        for (pixel_a, pixel_b, weight) in edges.iter().cloned() {
+            let (root_a, root_b) = (segments.find(pixel_a), segments.find(pixel_b));
+
+            if root_a == root_b {
+                continue;
+            }
+
+            let threshold_a = internal_diffs[root_a] + self.k / segments.size_of(root_a) as f32;
+            let threshold_b = internal_diffs[root_b] + self.k / segments.size_of(root_b) as f32;
+
+            if weight <= threshold_a.min(threshold_b) {
+                // edges are sorted, so the weight is the heaviest one in merged segment
+                if let Some(root) = segments.union(root_a, root_b) {
+                    internal_diffs[root] = weight;
+                }
+            }
+        }
+
+        for (pixel_a, pixel_b, _) in edges.iter().cloned() {
+            if segments.size_of(pixel_a) < self.min_size
+                || segments.size_of(pixel_b) < self.min_size
+            {
+                segments.union(pixel_a, pixel_b);
+            }
+        }
+
+        for (pixel_a, pixel_b, _) in edges.iter().cloned() {
+            if segments.n_subsets() <= self.max_segments {
+                break;
+            }
+
+            segments.union(pixel_a, pixel_b);
+        }
+
+        segments.labels()
+    }
+
+    fn pixel_to_idx(pixel_map: &PixelMap, pixel: &Pixel) -> usize {
+        pixel.y * pixel_map.width + pixel.x
+    }
+
+    fn colour_distance(pixel_a: &Pixel, pixel_b: &Pixel) -> f32 {
+        pixel_a
+            .iter_channels()
+            .zip(pixel_b.iter_channels())
+            .map(|(a, b)| (f32::from(a) - f32::from(b)).powi(2))
+            .sum::<f32>()
+            .sqrt()
+    }
+
+    fn build_sorted_edges(pixel_map: &PixelMap) -> Vec<PixelEdge> {
+        let mut edges = pixel_map
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                NEIGHBOUR_OFFSETS.iter().filter_map(move |(dx, dy)| {
+                    let neighbour = pixel_map.get_pixel_by_delta(pixel.x, pixel.y, *dx, *dy)?;
+
+                    Some((
+                        Self::pixel_to_idx(pixel_map, pixel),
+                        Self::pixel_to_idx(pixel_map, &neighbour),
+                        Self::colour_distance(pixel, &neighbour),
+                    ))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        edges.sort_by(|(_, _, weight_a), (_, _, weight_b)| compare_float(weight_a, weight_b));
+
+        edges
+    }
+}
@@ -0,0 +1,118 @@
+mod felzenszwalb;
+
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::ant_colony::graph::NodeId;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::FromStrAndPixelMap;
+use super::{SegmentCost, SegmentDistances, SegmentId, SegmentToEdgeConverter};
+
+use felzenszwalb::Felzenszwalb;
+
+/// Segments image with Felzenszwalb-Huttenlocher algorithm into at most
+/// n_nodes * (n_nodes - 1) / 2 segments, each mapped to an edge of the complete graph.
+///
+/// Larger k prefers larger segments, segments smaller than min_size are merged
+/// with their neighbours. When the image yields fewer segments than edges,
+/// the remaining edges are left out of the graph.
+pub struct FelzenszwalbConverter {
+    image: PixelMap,
+    labels: Vec<usize>,
+    pixels_by_segment_id: HashMap<SegmentId, Vec<Pixel>>,
+    segment_to_node_pair: HashMap<SegmentId, (NodeId, NodeId)>,
+}
+
+impl FelzenszwalbConverter {
+    pub fn new(pixel_map: &PixelMap, target_n_nodes: usize, k: f32, min_size: usize) -> Self {
+        let n_segments = target_n_nodes * (target_n_nodes - 1) / 2;
+        let labels = Felzenszwalb::new(k, min_size, n_segments).segment(pixel_map);
+
+        let pixels_by_segment_id = labels
+            .iter()
+            .cloned()
+            .zip(pixel_map.pixels().iter().cloned())
+            .into_group_map();
+
+        FelzenszwalbConverter {
+            image: pixel_map.clone(),
+            labels,
+            pixels_by_segment_id,
+            segment_to_node_pair: Self::build_segment_idx_node_lookup(target_n_nodes),
+        }
+    }
+
+    pub fn get_labels(&self) -> &[usize] {
+        &self.labels
+    }
+
+    fn segment_to_cost((segment_id, pixels): (&SegmentId, &Vec<Pixel>)) -> (SegmentId, f32) {
+        (*segment_id, SegmentCost::Variance.calc_distance(pixels))
+    }
+}
+
+impl SegmentToEdgeConverter for FelzenszwalbConverter {
+    fn distances(&self) -> Vec<SegmentDistances> {
+        self.pixels_by_segment_id
+            .iter()
+            .map(Self::segment_to_cost)
+            .sorted_by_key(|(id, _)| *id)
+            .collect()
+    }
+
+    fn visualize_normalized_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        self.image.map_with_labels(&self.labels, |px, segment_id| {
+            self.map_pixel_with_segment_id(px, pheromone, segment_id)
+        })
+    }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        Some(self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::from_label(px.x, px.y, segment_id)
+        }))
+    }
+
+    fn lookup_nodes_by_segment_id(&self, segment_id: SegmentId) -> (NodeId, NodeId) {
+        self.segment_to_node_pair
+            .get(&segment_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn label_pixels(&self) -> Vec<SegmentId> {
+        self.labels.clone()
+    }
+
+    fn map_image_with_intensity_map(
+        &self,
+        intensity_by_segment_id: HashMap<SegmentId, u8>,
+    ) -> PixelMap {
+        self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::grey(
+                px.x,
+                px.y,
+                intensity_by_segment_id
+                    .get(&segment_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+        })
+    }
+}
+
+impl FromStrAndPixelMap for FelzenszwalbConverter {
+    /// Options in format `<target_n_nodes>,<k>,<min_size>`, e.g. `10,300,20`
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let (target_n_nodes, k, min_size) = opts.split(',').collect_tuple()?;
+
+        Some(FelzenszwalbConverter::new(
+            pixel_map,
+            target_n_nodes.parse().ok().filter(|n_nodes| *n_nodes > 1)?,
+            k.parse().ok().filter(|k: &f32| *k >= 0.0)?,
+            min_size.parse().ok()?,
+        ))
+    }
+}
@@ -68,6 +68,10 @@ impl SegmentToEdgeConverter for KMeansConverter {
             .unwrap_or_default()
     }
 
+    fn label_pixels(&self) -> Vec<SegmentId> {
+        self.pixel_map_clusters.get_clusters().clone()
+    }
+
     fn map_image_with_intensity_map(
         &self,
         intensity_by_segment_id: HashMap<SegmentId, u8>,
@@ -1,5 +1,8 @@
 mod _tests;
+mod felzenszwalb_converter;
 mod kmeans_converter;
+mod quadtree_converter;
+mod segment_cost;
 mod superpixel_converter;
 mod window_to_edge_converter;
 
@@ -7,7 +10,7 @@ use itertools::Itertools;
 use std::collections::HashMap;
 use std::iter::once;
 
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph, Node, NodeId};
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
 use crate::ant_colony::pheromone::Pheromone;
 use crate::images::image::Pixel;
 use crate::images::pixel_map::PixelMap;
@@ -16,8 +19,11 @@ use crate::common::utils::compare_float;
 
 use super::{FromStrAndPixelMap, ImageGraphConverter};
 
+pub use felzenszwalb_converter::FelzenszwalbConverter;
 pub use kmeans_converter::KMeansConverter;
-pub use superpixel_converter::SuperPixelConverter;
+pub use quadtree_converter::QuadtreeConverter;
+pub use segment_cost::SegmentCost;
+pub use superpixel_converter::{SlicParams, SuperPixelConverter};
 pub use window_to_edge_converter::WindowToEdgeConverter;
 
 pub type SegmentId = usize;
@@ -29,6 +35,10 @@ pub trait SegmentToEdgeConverter: FromStrAndPixelMap {
 
     fn visualize_normalized_pheromone(&self, pheromone: &Pheromone) -> PixelMap;
 
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        None
+    }
+
     /// maps (0..n_edges) to unique pairs of indicies of graph adjacency matrix
     /// see tests for example
     fn build_segment_idx_node_lookup(n_segments: usize) -> SegmentIdToNodes {
@@ -43,6 +53,9 @@ pub trait SegmentToEdgeConverter: FromStrAndPixelMap {
 
     fn lookup_nodes_by_segment_id(&self, segment_id: SegmentId) -> (NodeId, NodeId);
 
+    /// segment id of every pixel, in the order of pixels of the converted image
+    fn label_pixels(&self) -> Vec<SegmentId>;
+
     fn map_image_with_intensity_map(
         &self,
         intensity_by_segment_id: HashMap<SegmentId, u8>,
@@ -75,14 +88,9 @@ impl<C: SegmentToEdgeConverter> ImageGraphConverter for C {
 
                 once(edge_a).chain(once(edge_b))
             })
-            .into_group_map_by(|edge| edge.from);
-
-        let nodes = edges
-            .into_iter()
-            .map(|(id, adjacency_list)| Node { id, adjacency_list })
-            .collect::<Vec<_>>();
+            .collect();
 
-        Graph::from_node_vector(nodes)
+        Graph::from_edges(edges)
     }
 
     fn visualize_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
@@ -111,4 +119,40 @@ impl<C: SegmentToEdgeConverter> ImageGraphConverter for C {
 
         Some(image)
     }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        SegmentToEdgeConverter::visualize_segments(self)
+    }
+
+    /// Trail of a segment's edge is the mean intensity of the segment's pixels
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        let labels = self.label_pixels();
+
+        if labels.len() != mask.pixels().len() {
+            return None;
+        }
+
+        let graph = self.img_to_graph();
+        let levels_by_segment_id = labels
+            .into_iter()
+            .zip(mask.pixels().iter().map(|px| f32::from(px.r)))
+            .into_group_map();
+
+        let pheromone = levels_by_segment_id.into_iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, (segment_id, levels)| {
+                let (from, to) = self.lookup_nodes_by_segment_id(segment_id);
+                let edge_key = AdjacencyListEntry::get_key(from, to);
+                let trail = levels.iter().sum::<f32>() / (levels.len() as f32 * 255.0);
+
+                iif!(
+                    graph.get_edge(edge_key).is_some(),
+                    pheromone.initialize_pheromone_for_edge(edge_key, trail),
+                    pheromone
+                )
+            },
+        );
+
+        Some(pheromone)
+    }
 }
@@ -0,0 +1,238 @@
+use itertools::Itertools;
+use std::collections::HashMap;
+
+use crate::ant_colony::graph::NodeId;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::compare_float;
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::FromStrAndPixelMap;
+use super::{SegmentCost, SegmentDistances, SegmentId, SegmentToEdgeConverter};
+
+/// Rectangular block of the image, leaf of the quadtree
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadtreeBlock {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl QuadtreeBlock {
+    pub fn new(x: usize, y: usize, width: usize, height: usize) -> Self {
+        QuadtreeBlock {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    pub fn is_splittable(&self) -> bool {
+        self.width > 1 || self.height > 1
+    }
+
+    /// Splits the block into quadrants, or only into halves
+    /// along the longer side when `in_halves` is set
+    pub fn split(&self, in_halves: bool) -> Vec<Self> {
+        let split_x = self.width > 1 && (!in_halves || self.width >= self.height);
+        let split_y = self.height > 1 && (!in_halves || !split_x);
+
+        let halve = |from: usize, len: usize, is_split: bool| {
+            iif!(
+                is_split,
+                vec![(from, len / 2), (from + len / 2, len - len / 2)],
+                vec![(from, len)]
+            )
+        };
+
+        let columns = halve(self.x, self.width, split_x);
+        let rows = halve(self.y, self.height, split_y);
+
+        rows.iter()
+            .flat_map(|(y, height)| {
+                columns
+                    .iter()
+                    .map(move |(x, width)| QuadtreeBlock::new(*x, *y, *width, *height))
+            })
+            .collect()
+    }
+
+    pub fn pixels(&self, pixel_map: &PixelMap) -> Vec<Pixel> {
+        (self.y..self.y + self.height)
+            .flat_map(|y| {
+                (self.x..self.x + self.width)
+                    .map(move |x| pixel_map.pixels()[y * pixel_map.width + x])
+            })
+            .collect()
+    }
+}
+
+/// Segments image into a quadtree with n_nodes * (n_nodes - 1) / 2 leaves,
+/// each leaf is mapped to an edge on the graph.
+///
+/// Starting with the whole image, the leaf with the highest complexity scaled
+/// by its area is split, until there are as many leaves as edges.
+/// Flat areas end up in few large blocks, while complex ones are split finely.
+/// Last splits only halve the block, so the target count is hit exactly
+/// (unless blocks are down to single pixels).
+pub struct QuadtreeConverter {
+    image: PixelMap,
+    cost: SegmentCost,
+    leaves: Vec<QuadtreeBlock>,
+    labels: Vec<SegmentId>,
+    segment_to_node_pair: HashMap<SegmentId, (NodeId, NodeId)>,
+}
+
+impl QuadtreeConverter {
+    pub fn new(pixel_map: &PixelMap, n_nodes: usize) -> Self {
+        QuadtreeConverter::with_cost(pixel_map, n_nodes, SegmentCost::Variance)
+    }
+
+    pub fn with_cost(pixel_map: &PixelMap, n_nodes: usize, cost: SegmentCost) -> Self {
+        let n_segments = n_nodes * (n_nodes - 1) / 2;
+        let leaves = Self::build_leaves(pixel_map, n_segments, cost);
+
+        QuadtreeConverter {
+            image: pixel_map.clone(),
+            cost,
+            labels: Self::build_labels(pixel_map, &leaves),
+            leaves,
+            segment_to_node_pair: Self::build_segment_idx_node_lookup(n_nodes),
+        }
+    }
+
+    /// Leaves ordered by position of their top left corner, index is the segment id
+    pub fn get_leaves(&self) -> &[QuadtreeBlock] {
+        &self.leaves
+    }
+
+    fn calc_split_priority(pixel_map: &PixelMap, cost: SegmentCost, block: &QuadtreeBlock) -> f32 {
+        let pixels = block.pixels(pixel_map);
+
+        cost.calc_complexity(&pixels) * pixels.len() as f32
+    }
+
+    fn build_leaves(
+        pixel_map: &PixelMap,
+        n_segments: usize,
+        cost: SegmentCost,
+    ) -> Vec<QuadtreeBlock> {
+        let root = QuadtreeBlock::new(0, 0, pixel_map.width, pixel_map.height);
+        let mut leaves = vec![(root, Self::calc_split_priority(pixel_map, cost, &root))];
+
+        while leaves.len() < n_segments {
+            let remaining = n_segments - leaves.len();
+            let splitted_idx = leaves
+                .iter()
+                .enumerate()
+                .filter(|(_, (block, _))| block.is_splittable())
+                .max_by(|(_, (_, priority_a)), (_, (_, priority_b))| {
+                    compare_float(priority_a, priority_b)
+                })
+                .map(|(idx, _)| idx);
+
+            let (block, _) = match splitted_idx {
+                Some(idx) => leaves.swap_remove(idx),
+                None => break,
+            };
+
+            leaves.extend(
+                block
+                    .split(remaining < 3)
+                    .into_iter()
+                    .map(|child| (child, Self::calc_split_priority(pixel_map, cost, &child))),
+            );
+        }
+
+        leaves
+            .into_iter()
+            .map(|(block, _)| block)
+            .sorted_by_key(|block| (block.y, block.x))
+            .collect()
+    }
+
+    fn build_labels(pixel_map: &PixelMap, leaves: &[QuadtreeBlock]) -> Vec<SegmentId> {
+        let mut labels = vec![0; pixel_map.pixels().len()];
+
+        for (segment_id, block) in leaves.iter().enumerate() {
+            for pixel in block.pixels(pixel_map) {
+                labels[pixel.y * pixel_map.width + pixel.x] = segment_id;
+            }
+        }
+
+        labels
+    }
+}
+
+impl SegmentToEdgeConverter for QuadtreeConverter {
+    fn distances(&self) -> Vec<SegmentDistances> {
+        self.leaves
+            .iter()
+            .enumerate()
+            .map(|(segment_id, block)| {
+                (
+                    segment_id,
+                    self.cost.calc_distance(&block.pixels(&self.image)),
+                )
+            })
+            .collect()
+    }
+
+    fn visualize_normalized_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        self.image.map_with_labels(&self.labels, |px, segment_id| {
+            self.map_pixel_with_segment_id(px, pheromone, segment_id)
+        })
+    }
+
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        Some(self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::from_label(px.x, px.y, segment_id)
+        }))
+    }
+
+    fn lookup_nodes_by_segment_id(&self, segment_id: SegmentId) -> (NodeId, NodeId) {
+        self.segment_to_node_pair
+            .get(&segment_id)
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn label_pixels(&self) -> Vec<SegmentId> {
+        self.labels.clone()
+    }
+
+    fn map_image_with_intensity_map(
+        &self,
+        intensity_by_segment_id: HashMap<SegmentId, u8>,
+    ) -> PixelMap {
+        self.image.map_with_labels(&self.labels, |px, segment_id| {
+            Pixel::grey(
+                px.x,
+                px.y,
+                intensity_by_segment_id
+                    .get(&segment_id)
+                    .cloned()
+                    .unwrap_or_default(),
+            )
+        })
+    }
+}
+
+impl FromStrAndPixelMap for QuadtreeConverter {
+    /// Options in format `<n_nodes>[,<cost>]`, e.g. `20,entropy`
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let (n_nodes, cost) = opts
+            .splitn(2, ',')
+            .chain(std::iter::once(""))
+            .take(2)
+            .collect_tuple()?;
+
+        Some(QuadtreeConverter::with_cost(
+            pixel_map,
+            n_nodes.parse().ok().filter(|n_nodes| *n_nodes > 1)?,
+            cost.parse().ok()?,
+        ))
+    }
+}
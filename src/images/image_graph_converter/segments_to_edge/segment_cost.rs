@@ -0,0 +1,180 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+/// Number of grey levels intensities are quantized to for co-occurrence matrices
+const GLCM_LEVELS: u8 = 8;
+
+/// Offsets of pixel pairs counted in co-occurrence matrices (right and bottom neighbour)
+const GLCM_OFFSETS: [(isize, isize); 2] = [(1, 0), (0, 1)];
+
+/// 8-neighbourhood in clockwise order, starting from top left corner
+const LBP_OFFSETS: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+];
+
+/// Complexity measure of an image segment, the more complex
+/// the segment the shorter the edge it is mapped to.
+///
+/// Only pixels of the segment are taken into account, neighbourhoods
+/// (LBP, GLCM) are resolved by pixel coordinates, so the segment
+/// does not have to be rectangular.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SegmentCost {
+    /// variance of pixel intensities
+    Variance,
+    /// Shannon entropy (in bits) of intensity histogram
+    Entropy,
+    /// Shannon entropy (in bits) of local binary pattern histogram
+    LocalBinaryPattern,
+    /// mean squared difference of quantized levels of adjacent pixels
+    GlcmContrast,
+    /// 1 - mean of 1 / (1 + |i - j|) over quantized levels of adjacent pixels
+    GlcmHomogeneity,
+}
+
+impl Default for SegmentCost {
+    fn default() -> Self {
+        Self::Variance
+    }
+}
+
+impl SegmentCost {
+    pub fn calc_complexity(&self, pixels: &[Pixel]) -> f32 {
+        match self {
+            Self::Variance => PixelMap::variance_of_pixels(pixels),
+            Self::Entropy => {
+                Self::calc_entropy(pixels.iter().map(|pixel| pixel.intensity() as usize))
+            }
+            Self::LocalBinaryPattern => Self::calc_entropy(Self::calc_lbp_codes(pixels)),
+            Self::GlcmContrast => {
+                Self::calc_glcm_mean(pixels, |level_a, level_b| (level_a - level_b).powi(2))
+                    .unwrap_or(0.0)
+            }
+            Self::GlcmHomogeneity => {
+                1.0 - Self::calc_glcm_mean(pixels, |level_a, level_b| {
+                    1.0 / (1.0 + (level_a - level_b).abs())
+                })
+                .unwrap_or(1.0)
+            }
+        }
+    }
+
+    pub fn calc_distance(&self, pixels: &[Pixel]) -> f32 {
+        1.0 / (self.calc_complexity(pixels) + stability_factor!())
+    }
+
+    fn calc_entropy<I: Iterator<Item = usize>>(values: I) -> f32 {
+        let mut histogram: HashMap<usize, usize> = HashMap::new();
+
+        for value in values {
+            *histogram.entry(value).or_default() += 1;
+        }
+
+        let total = histogram.values().sum::<usize>() as f32;
+
+        histogram
+            .values()
+            .map(|count| *count as f32 / total)
+            .map(|probability| -probability * probability.log2())
+            .sum()
+    }
+
+    fn build_intensity_lookup(pixels: &[Pixel]) -> HashMap<(usize, usize), u8> {
+        pixels
+            .iter()
+            .map(|pixel| ((pixel.x, pixel.y), pixel.intensity()))
+            .collect()
+    }
+
+    fn get_by_delta(
+        lookup: &HashMap<(usize, usize), u8>,
+        pixel: &Pixel,
+        (dx, dy): (isize, isize),
+    ) -> Option<u8> {
+        let x = pixel.x as isize + dx;
+        let y = pixel.y as isize + dy;
+
+        iif!(
+            x < 0 || y < 0,
+            None,
+            lookup.get(&(x as usize, y as usize)).cloned()
+        )
+    }
+
+    /// Codes of pixels, whose whole 8-neighbourhood lies within the segment
+    fn calc_lbp_codes(pixels: &[Pixel]) -> impl Iterator<Item = usize> + '_ {
+        let lookup = Self::build_intensity_lookup(pixels);
+
+        pixels.iter().filter_map(move |pixel| {
+            let center = pixel.intensity();
+
+            LBP_OFFSETS.iter().try_fold(0, |code, offset| {
+                let neighbour = Self::get_by_delta(&lookup, pixel, *offset)?;
+
+                Some((code << 1) | iif!(neighbour >= center, 1, 0))
+            })
+        })
+    }
+
+    /// Mean of the measure over pairs of adjacent pixels, None if there are no such pairs
+    fn calc_glcm_mean<F: Fn(f32, f32) -> f32>(pixels: &[Pixel], measure: F) -> Option<f32> {
+        let lookup = &Self::build_intensity_lookup(pixels);
+        let measure = &measure;
+        let quantize = |intensity: u8| f32::from(intensity / (u8::MAX / GLCM_LEVELS + 1));
+
+        let values = pixels
+            .iter()
+            .flat_map(|pixel| {
+                GLCM_OFFSETS.iter().filter_map(move |offset| {
+                    let neighbour = Self::get_by_delta(lookup, pixel, *offset)?;
+
+                    Some(measure(quantize(pixel.intensity()), quantize(neighbour)))
+                })
+            })
+            .collect::<Vec<_>>();
+
+        iif!(
+            values.is_empty(),
+            None,
+            Some(values.iter().sum::<f32>() / values.len() as f32)
+        )
+    }
+}
+
+impl FromStr for SegmentCost {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" | "variance" => Ok(Self::Variance),
+            "entropy" => Ok(Self::Entropy),
+            "lbp" => Ok(Self::LocalBinaryPattern),
+            "contrast" => Ok(Self::GlcmContrast),
+            "homogeneity" => Ok(Self::GlcmHomogeneity),
+            _ => Err("Failed to parse segment cost"),
+        }
+    }
+}
+
+impl Display for SegmentCost {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Variance => write!(f, "variance"),
+            Self::Entropy => write!(f, "entropy"),
+            Self::LocalBinaryPattern => write!(f, "lbp"),
+            Self::GlcmContrast => write!(f, "contrast"),
+            Self::GlcmHomogeneity => write!(f, "homogeneity"),
+        }
+    }
+}
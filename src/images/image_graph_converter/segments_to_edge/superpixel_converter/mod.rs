@@ -9,13 +9,14 @@ use crate::images::image::Pixel;
 use crate::images::pixel_map::PixelMap;
 
 use super::super::FromStrAndPixelMap;
-use super::{SegmentDistances, SegmentId, SegmentToEdgeConverter};
+use super::{SegmentCost, SegmentDistances, SegmentId, SegmentToEdgeConverter};
 
-use slic::Slic;
+pub use slic::SlicParams;
 
 pub struct SuperPixelConverter {
     image: PixelMap,
     n_superpixels: usize,
+    cost: SegmentCost,
     labels: Vec<usize>,
     pixels_by_group_id: HashMap<usize, Vec<Pixel>>,
     segment_to_node_pair: HashMap<SegmentId, (NodeId, NodeId)>,
@@ -23,8 +24,21 @@ pub struct SuperPixelConverter {
 
 impl SuperPixelConverter {
     pub fn new(pixel_map: &PixelMap, target_n_nodes: usize) -> Self {
+        SuperPixelConverter::with_cost(pixel_map, target_n_nodes, SegmentCost::Variance)
+    }
+
+    pub fn with_cost(pixel_map: &PixelMap, target_n_nodes: usize, cost: SegmentCost) -> Self {
+        SuperPixelConverter::with_params(pixel_map, target_n_nodes, cost, SlicParams::default())
+    }
+
+    pub fn with_params(
+        pixel_map: &PixelMap,
+        target_n_nodes: usize,
+        cost: SegmentCost,
+        params: SlicParams,
+    ) -> Self {
         let n_superpixels = target_n_nodes * (target_n_nodes - 1) / 2;
-        let labels = Slic::from_pixel_map(pixel_map, n_superpixels, 50).run_iterations(10);
+        let labels = params.run(pixel_map, n_superpixels);
 
         let pixels_by_group_id = labels
             .iter()
@@ -35,16 +49,18 @@ impl SuperPixelConverter {
         SuperPixelConverter {
             image: pixel_map.clone(),
             n_superpixels,
+            cost,
             labels,
             pixels_by_group_id,
             segment_to_node_pair: Self::build_segment_idx_node_lookup(target_n_nodes),
         }
     }
 
-    fn super_pixel_to_cost((segment_id, pixels): (&SegmentId, &Vec<Pixel>)) -> (SegmentId, f32) {
-        let variance = PixelMap::variance_of_pixels(pixels);
-
-        (*segment_id, 1.0 / (variance + stability_factor!()))
+    fn super_pixel_to_cost(
+        &self,
+        (segment_id, pixels): (&SegmentId, &Vec<Pixel>),
+    ) -> (SegmentId, f32) {
+        (*segment_id, self.cost.calc_distance(pixels))
     }
 }
 
@@ -52,7 +68,7 @@ impl SegmentToEdgeConverter for SuperPixelConverter {
     fn distances(&self) -> Vec<SegmentDistances> {
         self.pixels_by_group_id
             .iter()
-            .map(Self::super_pixel_to_cost)
+            .map(|segment| self.super_pixel_to_cost(segment))
             .sorted_by_key(|(id, _)| id.clone())
             .collect()
     }
@@ -69,6 +85,18 @@ impl SegmentToEdgeConverter for SuperPixelConverter {
         PixelMap::new(self.image.height, self.image.width, pixels)
     }
 
+    fn visualize_segments(&self) -> Option<PixelMap> {
+        let pixels = self
+            .image
+            .pixels()
+            .iter()
+            .zip(self.labels.iter().cloned())
+            .map(|(px, segment_id)| Pixel::from_label(px.x, px.y, segment_id))
+            .collect();
+
+        Some(PixelMap::new(self.image.height, self.image.width, pixels))
+    }
+
     fn lookup_nodes_by_segment_id(&self, segment_id: SegmentId) -> (NodeId, NodeId) {
         self.segment_to_node_pair
             .get(&segment_id)
@@ -76,6 +104,10 @@ impl SegmentToEdgeConverter for SuperPixelConverter {
             .unwrap_or_default()
     }
 
+    fn label_pixels(&self) -> Vec<SegmentId> {
+        self.labels.clone()
+    }
+
     fn map_image_with_intensity_map(
         &self,
         intensity_by_segment_id: HashMap<SegmentId, u8>,
@@ -102,13 +134,25 @@ impl SegmentToEdgeConverter for SuperPixelConverter {
 }
 
 impl FromStrAndPixelMap for SuperPixelConverter {
+    /// Options in format `<target_n_nodes>[,<option>...]`, where option is either
+    /// segment cost or SLIC parameter, e.g. `20,lbp,m=20,iter=5,slico`
     fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
-        let (target_n_nodes,): (usize,) = opts
-            .splitn(1, ',')
-            .map(str::parse)
-            .filter_map(Result::ok)
-            .collect_tuple()?;
-
-        Some(SuperPixelConverter::new(pixel_map, target_n_nodes))
+        let mut opts_iter = opts.split(',');
+        let target_n_nodes = opts_iter.next()?.parse().ok()?;
+
+        let (cost, params) = opts_iter.try_fold(
+            (SegmentCost::default(), SlicParams::default()),
+            |(cost, params), option| match option.parse::<SegmentCost>() {
+                Ok(cost) => Some((cost, params)),
+                Err(_) => params.with_option(option).map(|params| (cost, params)),
+            },
+        )?;
+
+        Some(SuperPixelConverter::with_params(
+            pixel_map,
+            target_n_nodes,
+            cost,
+            params,
+        ))
     }
 }
@@ -3,17 +3,72 @@ use std::{collections::BTreeMap, iter::once};
 use crate::images::image::{LABColor, Pixel};
 use crate::images::pixel_map::PixelMap;
 
-use crate::common::utils::{compare_float, MapAccumExt, Vec2d, Vec2dCoords};
+use crate::common::utils::{compare_float, split_once, MapAccumExt, Vec2d, Vec2dCoords};
+
+/// Parameters of SLIC segmentation, selectable via converter options
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SlicParams {
+    pub compactness: usize,
+    pub n_iterations: usize,
+    pub adaptive: bool,
+}
+
+impl SlicParams {
+    /// Applies single option `m=<compactness>`, `iter=<n_iterations>` or `slico`
+    pub fn with_option(self, option: &str) -> Option<Self> {
+        let (key, value) = split_once(option, "=").unwrap_or((option, ""));
+        let parse_positive = || value.parse::<usize>().ok().filter(|value| *value > 0);
+
+        match key {
+            "m" => Some(SlicParams {
+                compactness: parse_positive()?,
+                ..self
+            }),
+            "iter" => Some(SlicParams {
+                n_iterations: parse_positive()?,
+                ..self
+            }),
+            "slico" if value.is_empty() => Some(SlicParams {
+                adaptive: true,
+                ..self
+            }),
+            _ => None,
+        }
+    }
+
+    pub fn run(&self, pixel_map: &PixelMap, n_superpixels: usize) -> Vec<usize> {
+        let slic = Slic::from_pixel_map(pixel_map, n_superpixels, self.compactness);
+
+        iif!(self.adaptive, slic.with_adaptive_compactness(), slic)
+            .run_iterations(self.n_iterations)
+    }
+}
+
+impl Default for SlicParams {
+    fn default() -> Self {
+        SlicParams {
+            compactness: 50,
+            n_iterations: 10,
+            adaptive: false,
+        }
+    }
+}
 
 /// Implementation of Simple Linear Iterative Clustering algorithm
 /// https://www.iro.umontreal.ca/~mignotte/IFT6150/Articles/SLIC_Superpixels.pdf
 ///
 /// divides given image into n_superpixels, returns list of labels
 /// in vector of size equal to vector of pixels
+///
+/// In adaptive mode (SLICO) colour distance of each cluster is normalized
+/// by the highest colour distance observed within the cluster in previous iteration,
+/// so compactness is only used as the initial normalization.
 pub struct Slic {
     n_pixels: usize,
     n_superpixels: usize,
     compactness: usize,
+    adaptive: bool,
+    max_color_distances: Vec<f32>,
     grid_interval_s: usize,
     width: usize,
     height: usize,
@@ -41,6 +96,8 @@ impl Slic {
             n_pixels,
             n_superpixels,
             compactness,
+            adaptive: false,
+            max_color_distances: vec![compactness as f32; clusters.len()],
             grid_interval_s,
             width: pixel_map.width,
             height: pixel_map.height,
@@ -51,6 +108,13 @@ impl Slic {
         }
     }
 
+    pub fn with_adaptive_compactness(self) -> Self {
+        Slic {
+            adaptive: true,
+            ..self
+        }
+    }
+
     pub fn run_iterations(self, n_iter: usize) -> Vec<usize> {
         (0..n_iter)
             .fold(self, Self::perform_iteration)
@@ -137,16 +201,28 @@ impl Slic {
             height,
             grid_interval_s,
             compactness,
+            adaptive,
+            max_color_distances,
             ..
         } = self;
 
+        // normalization of colour distances changes between iterations in adaptive mode,
+        // so distances from the previous iteration are not comparable
+        let distances = iif!(adaptive, Vec2d::fill(f32::MAX, width, height), distances);
+
         let indexed_clusters = (0..).zip(clusters.iter());
-        let ((labels, distances), clusters) = indexed_clusters
+        let ((labels, distances), clusters): ((_, _), Vec<_>) = indexed_clusters
             .map_accum(
                 (labels, distances),
                 |(labels, distances), (cluster_idx, cluster)| {
-                    let pixels_in_cluster_area =
-                        Self::pixels_around_cluster(&pixels, cluster, grid_interval_s, compactness);
+                    let color_norm = iif!(adaptive, Some(max_color_distances[cluster_idx]), None);
+                    let pixels_in_cluster_area = Self::pixels_around_cluster(
+                        &pixels,
+                        cluster,
+                        grid_interval_s,
+                        compactness,
+                        color_norm,
+                    );
 
                     let (labels, distances) = Self::update_labels_and_distances_around_cluster(
                         pixels_in_cluster_area,
@@ -163,6 +239,12 @@ impl Slic {
             )
             .collect_with_state();
 
+        let max_color_distances = iif!(
+            adaptive,
+            Self::calculate_max_color_distances(&pixels, &labels, &clusters),
+            max_color_distances
+        );
+
         Slic {
             clusters,
             labels,
@@ -172,21 +254,47 @@ impl Slic {
             height,
             grid_interval_s,
             compactness,
+            adaptive,
+            max_color_distances,
             ..self
         }
     }
 
+    /// Highest colour distance between cluster center and pixels assigned to it,
+    /// at least 1.0 to avoid blowing up distances in homogenous clusters
+    fn calculate_max_color_distances(
+        pixels: &Vec2d<LABColor>,
+        labels: &Vec2d<usize>,
+        clusters: &[LABColor],
+    ) -> Vec<f32> {
+        labels.iter().fold(
+            vec![1.0; clusters.len()],
+            |mut max_distances, (x, y, cluster_idx)| {
+                let distance = pixels[(x, y)].diff_sq(&clusters[*cluster_idx]).sqrt();
+                max_distances[*cluster_idx] = max_distances[*cluster_idx].max(distance);
+
+                max_distances
+            },
+        )
+    }
+
     fn pixels_around_cluster<'a>(
         pixels: &'a Vec2d<LABColor>,
         cluster: &'a LABColor,
         grid_interval_s: usize,
         compactness: usize,
+        color_norm: Option<f32>,
     ) -> impl Iterator<Item = (LABColor, f32)> + 'a {
         pixels
             .iter_block((cluster.x, cluster.y), grid_interval_s)
             .cloned()
             .map(move |px| {
-                let distance = Self::lab_distance(&px, &cluster, compactness, grid_interval_s);
+                let distance = match color_norm {
+                    Some(norm) => {
+                        Self::normalized_lab_distance(&px, cluster, norm, grid_interval_s)
+                    }
+                    None => Self::lab_distance(&px, cluster, compactness, grid_interval_s),
+                };
 
                 (px, distance)
             })
@@ -208,6 +316,21 @@ impl Slic {
         d_lab + ratio * d_xy
     }
 
+    /// D = sqrt((d_lab / m_c)^2 + (d_xy / S)^2)
+    fn normalized_lab_distance(
+        px_a: &LABColor,
+        px_b: &LABColor,
+        color_norm: f32,
+        grid_interval_s: usize,
+    ) -> f32 {
+        let d_lab = px_a.diff_sq(px_b).sqrt();
+        let d_x = (px_a.x as isize - px_b.x as isize).pow(2) as f32;
+        let d_y = (px_a.y as isize - px_b.y as isize).pow(2) as f32;
+        let d_xy = (d_x + d_y).sqrt();
+
+        ((d_lab / color_norm).powi(2) + (d_xy / grid_interval_s as f32).powi(2)).sqrt()
+    }
+
     fn update_labels_and_distances_around_cluster<I: Iterator<Item = (LABColor, f32)>>(
         pixels_in_cluster_area: I,
         labels: Vec2d<usize>,
@@ -10,15 +10,16 @@ use crate::images::pixel_map::{PixelMap, PixelMapWindows, WindowId};
 use crate::common::utils::balanced_divisors;
 
 use super::super::FromStrAndPixelMap;
-use super::{SegmentDistances, SegmentId, SegmentToEdgeConverter};
+use super::{SegmentCost, SegmentDistances, SegmentId, SegmentToEdgeConverter};
 
 /// Segments image into n_x_windows * n_y_windows non-overlapping windows.
 ///
 /// Each window is mapped to an edge on the graph, the edge length
-/// is 1/window.variance() which hopefully will make edges/windows
-/// with complex structure more desirable.
+/// is 1/complexity of the window (variance by default) which hopefully
+/// will make edges/windows with complex structure more desirable.
 pub struct WindowToEdgeConverter {
     pixel_map_windows: PixelMapWindows,
+    cost: SegmentCost,
     n_nodes: usize,
     n_x_windows: usize,
     n_y_windows: usize,
@@ -27,11 +28,16 @@ pub struct WindowToEdgeConverter {
 
 impl WindowToEdgeConverter {
     pub fn new(pixel_map: &PixelMap, n_nodes: usize) -> Self {
+        WindowToEdgeConverter::with_cost(pixel_map, n_nodes, SegmentCost::Variance)
+    }
+
+    pub fn with_cost(pixel_map: &PixelMap, n_nodes: usize, cost: SegmentCost) -> Self {
         let n_edges = n_nodes * (n_nodes - 1) / 2;
         let (n_y_windows, n_x_windows) = balanced_divisors(n_edges);
 
         WindowToEdgeConverter {
             pixel_map_windows: pixel_map.windows(n_x_windows, n_y_windows),
+            cost,
             n_nodes,
             n_x_windows,
             n_y_windows,
@@ -39,8 +45,8 @@ impl WindowToEdgeConverter {
         }
     }
 
-    fn window_to_distance((idx, window): (WindowId, PixelMap)) -> (WindowId, f32) {
-        (idx, 1.0 / (window.variance() + stability_factor!()))
+    fn window_to_distance(&self, (idx, window): (WindowId, PixelMap)) -> (WindowId, f32) {
+        (idx, self.cost.calc_distance(window.pixels()))
     }
 }
 
@@ -48,7 +54,7 @@ impl SegmentToEdgeConverter for WindowToEdgeConverter {
     fn distances(&self) -> Vec<SegmentDistances> {
         self.pixel_map_windows
             .iter()
-            .map(Self::window_to_distance)
+            .map(|window| self.window_to_distance(window))
             .collect()
     }
 
@@ -64,6 +70,10 @@ impl SegmentToEdgeConverter for WindowToEdgeConverter {
             .unwrap_or_default()
     }
 
+    fn label_pixels(&self) -> Vec<SegmentId> {
+        self.pixel_map_windows.get_window_ids()
+    }
+
     fn map_image_with_intensity_map(
         &self,
         intensity_by_segment_id: HashMap<SegmentId, u8>,
@@ -82,13 +92,18 @@ impl SegmentToEdgeConverter for WindowToEdgeConverter {
 }
 
 impl FromStrAndPixelMap for WindowToEdgeConverter {
+    /// Options in format `<n_nodes>[,<cost>]`, e.g. `20,entropy`
     fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
-        let (n_nodes,): (usize,) = opts
-            .splitn(1, ',')
-            .map(str::parse)
-            .filter_map(Result::ok)
+        let (n_nodes, cost) = opts
+            .splitn(2, ',')
+            .chain(std::iter::once(""))
+            .take(2)
             .collect_tuple()?;
 
-        Some(WindowToEdgeConverter::new(pixel_map, n_nodes))
+        Some(WindowToEdgeConverter::with_cost(
+            pixel_map,
+            n_nodes.parse().ok()?,
+            cost.parse().ok()?,
+        ))
     }
 }
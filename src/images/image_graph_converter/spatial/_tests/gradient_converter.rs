@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod gradient_converter_tests {
+    use super::super::super::super::_tests::mock_edge_image;
+    use super::super::super::super::{FromStrAndPixelMap, GradientConverter, ImageGraphConverter};
+    use super::super::super::gradient_converter::GradientKernel;
+    use super::super::super::Neighbourhood;
+
+    #[test]
+    fn it_calculates_magnitude_with_selected_kernel() {
+        let luminance = mock_edge_image(4).to_luminance();
+        let pixel = luminance.pixels()[5];
+
+        assert_delta!(
+            GradientKernel::Prewitt.calc_magnitude(&luminance, &pixel),
+            3.0 * 255.0
+        );
+        assert_delta!(
+            GradientKernel::Sobel.calc_magnitude(&luminance, &pixel),
+            4.0 * 255.0
+        );
+        assert_delta!(
+            GradientKernel::Scharr.calc_magnitude(&luminance, &pixel),
+            16.0 * 255.0
+        );
+        assert_delta!(
+            GradientKernel::Sobel.calc_magnitude(&luminance, &luminance.pixels()[0]),
+            0.0
+        );
+    }
+
+    #[test]
+    fn it_attracts_ants_towards_edges() {
+        let converter = GradientConverter::new(
+            &mock_edge_image(4),
+            GradientKernel::Sobel,
+            Neighbourhood::Four,
+        );
+        let graph = converter.img_to_graph();
+
+        let gradient_levels = converter
+            .get_gradient_image()
+            .pixels()
+            .iter()
+            .map(|pixel| pixel.r)
+            .collect::<Vec<_>>();
+        assert_eq!(&gradient_levels[0..4], &[0, 255, 255, 0]);
+
+        let across_edge = graph.get_adjacent_edges(&1).iter().find(|e| e.to == 2);
+        let in_flat_region = graph.get_adjacent_edges(&0).iter().find(|e| e.to == 4);
+
+        assert!(across_edge.unwrap().distance < in_flat_region.unwrap().distance);
+    }
+
+    #[test]
+    fn it_parses_options() {
+        let img = mock_edge_image(4);
+
+        assert!(GradientConverter::from_str_and_pixel_map(&img, "").is_some());
+        assert!(GradientConverter::from_str_and_pixel_map(&img, "scharr").is_some());
+        assert!(GradientConverter::from_str_and_pixel_map(&img, "prewitt,8").is_some());
+        assert!(GradientConverter::from_str_and_pixel_map(&img, "canny").is_none());
+        assert!(GradientConverter::from_str_and_pixel_map(&img, "sobel,3").is_none());
+    }
+}
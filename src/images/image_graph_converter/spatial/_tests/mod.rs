@@ -1,3 +1,6 @@
+mod gradient_converter;
+mod tian_edge_detection_converter;
+
 #[cfg(test)]
 mod edge_change_converter_tests {
     use crate::ant_colony::graph::{AdjacencyListEntry, Graph, Node};
@@ -6,7 +9,8 @@ mod edge_change_converter_tests {
     use crate::images::image::Pixel;
     use crate::images::pixel_map::PixelMap;
 
-    use super::super::super::{ImageGraphConverter, SpatialEdgeChangeConverter};
+    use super::super::super::{Converters, ImageGraphConverter, SpatialEdgeChangeConverter};
+    use super::super::Neighbourhood;
 
     const MAX_PIXEL_DISTANCE: f32 = 255.0 * 255.0 * 3.0;
 
@@ -175,4 +179,132 @@ mod edge_change_converter_tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn it_seeds_pheromone_from_coarser_pyramid_level() {
+        let image = PixelMap::new(
+            4,
+            4,
+            (0..4)
+                .flat_map(|y| (0..4).map(move |x| Pixel::grey(x, y, (40 * (x + y)) as u8)))
+                .collect(),
+        );
+        let pyramid = image.pyramid(2);
+        let coarse = Converters::SpatialEdgeChange(SpatialEdgeChangeConverter::new(&pyramid[0]));
+        let fine = Converters::SpatialEdgeChange(SpatialEdgeChangeConverter::new(&pyramid[1]));
+        let coarse_graph = coarse.img_to_graph();
+        let coarse_pheromone = coarse_graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(coarse_graph.get_edge_index()),
+            |pheromone, edge| pheromone.initialize_pheromone_for_edge(edge.key, 0.5),
+        );
+
+        let seed = fine
+            .seed_from_coarser_level(&pyramid[1], &coarse, &coarse_pheromone)
+            .unwrap();
+
+        // uniform trails of the coarse level upsample to uniform (normalized) trails
+        assert_eq!(
+            seed.get_values().len(),
+            fine.img_to_graph().get_all_edges().len() / 2
+        );
+        assert!(seed.iter().all(|(_, value)| (value - 1.0).abs() < 1e-3));
+    }
+
+    #[test]
+    fn it_checks_whether_converter_can_be_seeded_from_coarser_level() {
+        let img = mock_image();
+        let converter = Converters::SpatialEdgeChange(SpatialEdgeChangeConverter::new(&img));
+
+        assert!(converter.can_seed_from_coarser_level(&img));
+        assert!(!converter.can_seed_from_coarser_level(&img.resize(1, 1)));
+    }
+
+    #[test]
+    fn it_restores_pheromone_from_mask() {
+        let img = mock_image();
+        let mask = PixelMap::new(
+            2,
+            3,
+            vec![
+                Pixel::grey(0, 0, 0),
+                Pixel::grey(1, 0, 51),
+                Pixel::grey(2, 0, 102),
+                Pixel::grey(0, 1, 153),
+                Pixel::grey(1, 1, 204),
+                Pixel::grey(2, 1, 255),
+            ],
+        );
+
+        let pheromone = SpatialEdgeChangeConverter::new(&img)
+            .pheromone_from_mask(&mask)
+            .unwrap();
+
+        assert_eq!(pheromone.get_values().len(), 7);
+        assert_delta!(
+            pheromone.get_pheromone_for_edge(UniquePair::generate_key(0, 1)),
+            0.1
+        );
+        assert_delta!(
+            pheromone.get_pheromone_for_edge(UniquePair::generate_key(1, 4)),
+            0.5
+        );
+        assert_delta!(
+            pheromone.get_pheromone_for_edge(UniquePair::generate_key(4, 5)),
+            0.9
+        );
+    }
+
+    #[test]
+    fn it_weights_distances_by_geometry_in_8_neighbourhood() {
+        let img = mock_image();
+
+        let graph = SpatialEdgeChangeConverter::with_neighbourhood(&img, Neighbourhood::Eight)
+            .img_to_graph();
+
+        let adjacent_to_0 = graph.get_adjacent_edges(&0);
+        let diagonal = adjacent_to_0.iter().find(|edge| edge.to == 4).unwrap();
+        let straight = adjacent_to_0.iter().find(|edge| edge.to == 3).unwrap();
+
+        assert_eq!(adjacent_to_0.len(), 3);
+        assert_delta!(
+            diagonal.distance,
+            2f32.sqrt() / ((1600.0 * 3.0) / MAX_PIXEL_DISTANCE + stability_factor!())
+        );
+        assert_delta!(
+            straight.distance,
+            1.0 / ((900.0 * 3.0) / MAX_PIXEL_DISTANCE + stability_factor!())
+        );
+    }
+
+    #[test]
+    fn it_connects_pixels_within_radius() {
+        let pixels = (0..5)
+            .flat_map(|y| (0..5).map(move |x| Pixel::grey(x, y, (10 * (x + y)) as u8)))
+            .collect();
+        let img = PixelMap::new(5, 5, pixels);
+
+        let graph = SpatialEdgeChangeConverter::with_neighbourhood(&img, Neighbourhood::Radius(2))
+            .img_to_graph();
+        let four_graph =
+            SpatialEdgeChangeConverter::with_neighbourhood(&img, Neighbourhood::Radius(1))
+                .img_to_graph();
+
+        assert_eq!(graph.get_adjacent_edges(&12).len(), 12);
+        assert_eq!(graph.get_adjacent_edges(&0).len(), 5);
+        assert_eq!(
+            four_graph.get_edge_index(),
+            SpatialEdgeChangeConverter::new(&img)
+                .img_to_graph()
+                .get_edge_index()
+        );
+    }
+
+    #[test]
+    fn it_parses_neighbourhood() {
+        assert_eq!("".parse(), Ok(Neighbourhood::Four));
+        assert_eq!("8".parse(), Ok(Neighbourhood::Eight));
+        assert_eq!("r=2".parse(), Ok(Neighbourhood::Radius(2)));
+        assert!("r=0".parse::<Neighbourhood>().is_err());
+        assert!("6".parse::<Neighbourhood>().is_err());
+    }
 }
@@ -0,0 +1,100 @@
+#[cfg(test)]
+mod tian_edge_detection_converter_tests {
+    use crate::ant_colony::graph::EdgeMode;
+    use crate::ant_colony::pheromone::Pheromone;
+    use crate::images::image::Pixel;
+    use crate::images::pixel_map::PixelMap;
+
+    use super::super::super::super::_tests::mock_edge_image;
+    use super::super::super::super::{ImageGraphConverter, TianEdgeDetectionConverter};
+
+    #[test]
+    fn it_prefers_pixels_with_high_variation() {
+        let converter = TianEdgeDetectionConverter::new(&mock_edge_image(5));
+        let variations = converter.get_variations();
+
+        // central pixel lies on the edge, corner pixel in the flat region
+        assert_delta!(variations[12], 1.0);
+        assert!(variations[0] < variations[12]);
+        assert!(variations.iter().all(|val| (0.0..=1.0).contains(val)));
+    }
+
+    #[test]
+    fn it_assigns_distance_of_the_entered_pixel() {
+        let converter = TianEdgeDetectionConverter::new(&mock_edge_image(5));
+        let graph = converter.img_to_graph();
+
+        assert!(graph.is_directed());
+        assert_eq!(graph.get_adjacent_edges(&12).len(), 8);
+        assert_eq!(graph.get_adjacent_edges(&0).len(), 3);
+
+        let into_center = graph
+            .get_edge(EdgeMode::Directed.generate_key(6, 12))
+            .unwrap();
+        let from_other_side = graph
+            .get_edge(EdgeMode::Directed.generate_key(18, 12))
+            .unwrap();
+        let out_of_center = graph
+            .get_edge(EdgeMode::Directed.generate_key(12, 6))
+            .unwrap();
+
+        assert_eq!(into_center.distance, from_other_side.distance);
+        assert!(into_center.distance < out_of_center.distance);
+    }
+
+    #[test]
+    fn it_visualizes_trails_entering_pixels() {
+        let converter = TianEdgeDetectionConverter::new(&mock_edge_image(5));
+        let graph = converter.img_to_graph();
+
+        let pheromone = graph
+            .get_all_edges()
+            .iter()
+            .fold(Pheromone::new(), |pheromone, edge| {
+                let value = iif!(edge.to == 12, 1.0, 0.0);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, value)
+            });
+
+        let visualization = converter.visualize_pheromone(&pheromone);
+        let pixels = visualization.pixels();
+
+        assert_eq!(pixels[12].r, 255);
+        assert!(pixels
+            .iter()
+            .enumerate()
+            .all(|(idx, px)| idx == 12 || px.r == 0));
+    }
+
+    #[test]
+    fn it_restores_trails_entering_pixels_from_mask() {
+        let converter = TianEdgeDetectionConverter::new(&mock_edge_image(5));
+        let mask = PixelMap::new(
+            5,
+            5,
+            (0..5)
+                .flat_map(|y| (0..5).map(move |x| Pixel::grey(x, y, iif!(x == 2, 255, 51))))
+                .collect(),
+        );
+
+        let pheromone = converter.pheromone_from_mask(&mask).unwrap();
+
+        let into_center = EdgeMode::Directed.generate_key(6, 12);
+        let out_of_center = EdgeMode::Directed.generate_key(12, 6);
+
+        assert_delta!(pheromone.get_pheromone_for_edge(into_center), 1.0);
+        assert_delta!(pheromone.get_pheromone_for_edge(out_of_center), 0.2);
+    }
+
+    #[test]
+    fn it_restores_no_trails_from_mask_of_different_size() {
+        let converter = TianEdgeDetectionConverter::new(&mock_edge_image(5));
+        let mask = PixelMap::new(
+            4,
+            4,
+            (0..16).map(|id| Pixel::grey(id % 4, id / 4, 255)).collect(),
+        );
+
+        assert!(converter.pheromone_from_mask(&mask).is_none());
+    }
+}
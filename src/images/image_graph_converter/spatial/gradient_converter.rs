@@ -0,0 +1,192 @@
+use itertools::Itertools;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::ant_colony::graph::Graph;
+use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::compare_float;
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::{FromStrAndPixelMap, ImageGraphConverter};
+use super::{Neighbourhood, SpatialImageGraphConverter};
+
+/// 3x3 derivative kernel, separable into a central difference
+/// and a smoothing in the perpendicular direction with weights [side, center, side]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GradientKernel {
+    Prewitt,
+    Sobel,
+    Scharr,
+}
+
+impl Default for GradientKernel {
+    fn default() -> Self {
+        Self::Sobel
+    }
+}
+
+impl GradientKernel {
+    fn get_smoothing_weights(&self) -> [f32; 3] {
+        match self {
+            Self::Prewitt => [1.0, 1.0, 1.0],
+            Self::Sobel => [1.0, 2.0, 1.0],
+            Self::Scharr => [3.0, 10.0, 3.0],
+        }
+    }
+
+    /// Gradient magnitude at given pixel of a grey image,
+    /// pixels outside of the image are replicated from its border
+    pub fn calc_magnitude(&self, luminance: &PixelMap, pixel: &Pixel) -> f32 {
+        let level = |dx: isize, dy: isize| {
+            f32::from(
+                luminance
+                    .get_pixel_by_delta_clamped(pixel.x, pixel.y, dx, dy)
+                    .r,
+            )
+        };
+
+        let (grad_x, grad_y) = self.get_smoothing_weights().iter().zip(-1..=1).fold(
+            (0.0, 0.0),
+            |(grad_x, grad_y), (weight, offset)| {
+                (
+                    grad_x + weight * (level(1, offset) - level(-1, offset)),
+                    grad_y + weight * (level(offset, 1) - level(offset, -1)),
+                )
+            },
+        );
+
+        (grad_x.powi(2) + grad_y.powi(2)).sqrt()
+    }
+}
+
+impl FromStr for GradientKernel {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" | "sobel" => Ok(Self::Sobel),
+            "scharr" => Ok(Self::Scharr),
+            "prewitt" => Ok(Self::Prewitt),
+            _ => Err("Failed to parse gradient kernel"),
+        }
+    }
+}
+
+impl Display for GradientKernel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Prewitt => write!(f, "prewitt"),
+            Self::Sobel => write!(f, "sobel"),
+            Self::Scharr => write!(f, "scharr"),
+        }
+    }
+}
+
+/// Image-graph converter, which measures complexity with gradient magnitude
+/// computed on the luminance of the image.
+///
+/// Magnitudes (normalized to the highest one) are stored as a grey image,
+/// distance between adjacent pixels is inversly proportional to their mean magnitude,
+/// so strong edges and textures attract ants.
+pub struct GradientConverter {
+    source_image: PixelMap,
+    gradient_image: PixelMap,
+    neighbourhood: Neighbourhood,
+    graph: Graph,
+}
+
+impl GradientConverter {
+    pub fn new(pixel_map: &PixelMap, kernel: GradientKernel, neighbourhood: Neighbourhood) -> Self {
+        let gradient_image = Self::calc_gradient_image(pixel_map, kernel);
+
+        GradientConverter {
+            source_image: pixel_map.clone(),
+            graph: Self::construct_graph(&gradient_image, neighbourhood),
+            gradient_image,
+            neighbourhood,
+        }
+    }
+
+    pub fn get_gradient_image(&self) -> &PixelMap {
+        &self.gradient_image
+    }
+
+    fn calc_gradient_image(pixel_map: &PixelMap, kernel: GradientKernel) -> PixelMap {
+        let luminance = pixel_map.to_luminance();
+        let magnitudes = luminance
+            .pixels()
+            .iter()
+            .map(|pixel| kernel.calc_magnitude(&luminance, pixel))
+            .collect::<Vec<_>>();
+
+        let max = magnitudes
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .filter(|max| *max > 0.0)
+            .unwrap_or(1.0);
+
+        let pixels = luminance
+            .pixels()
+            .iter()
+            .zip(magnitudes)
+            .map(|(pixel, magnitude)| {
+                Pixel::grey(pixel.x, pixel.y, (255.0 * magnitude / max) as u8)
+            })
+            .collect();
+
+        PixelMap::new(pixel_map.height, pixel_map.width, pixels)
+    }
+}
+
+impl SpatialImageGraphConverter for GradientConverter {
+    /// Pixels of the gradient image are expected
+    fn calc_distance_between_pixels(pixel_a: &Pixel, pixel_b: &Pixel) -> f32 {
+        let magnitude_sum = f32::from(pixel_a.r) + f32::from(pixel_b.r);
+
+        magnitude_sum / (2.0 * 255.0) + stability_factor!()
+    }
+
+    fn calc_intensity_from_distance(distance: f32) -> u8 {
+        (distance * 255.0) as u8
+    }
+}
+
+impl ImageGraphConverter for GradientConverter {
+    fn img_to_graph(&self) -> Graph {
+        self.graph.clone()
+    }
+
+    fn visualize_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        Self::construct_pheromone_visualization(&self.source_image, &self.graph, pheromone)
+    }
+
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        Self::construct_pheromone_from_mask(mask, &self.graph)
+    }
+
+    fn visualize_conversion(&self) -> Option<PixelMap> {
+        Some(Self::construct_conversion_visualization(
+            &self.gradient_image,
+            self.neighbourhood,
+        ))
+    }
+}
+
+impl FromStrAndPixelMap for GradientConverter {
+    /// Options in format `<kernel>,<neighbourhood>`, both optional, e.g. `scharr,8`
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let (kernel, neighbourhood) = opts
+            .splitn(2, ',')
+            .chain(std::iter::repeat(""))
+            .take(2)
+            .collect_tuple()?;
+
+        Some(GradientConverter::new(
+            pixel_map,
+            kernel.parse().ok()?,
+            neighbourhood.parse().ok()?,
+        ))
+    }
+}
@@ -1,19 +1,23 @@
 mod _tests;
+mod gradient_converter;
+mod neighbourhood;
 mod spatial_edge_change_converter;
+mod tian_edge_detection_converter;
 
-use crate::ant_colony::graph::{AdjacencyListEntry, Graph, Node, NodeId};
+use crate::ant_colony::graph::{AdjacencyListEntry, Graph, NodeId};
 use crate::ant_colony::pheromone::Pheromone;
 use crate::images::image::Pixel;
 use crate::images::pixel_map::PixelMap;
 
+pub use gradient_converter::GradientConverter;
+pub use neighbourhood::Neighbourhood;
 pub use spatial_edge_change_converter::SpatialEdgeChangeConverter;
+pub use tian_edge_detection_converter::TianEdgeDetectionConverter;
 
 /// Trait shared by all ImageGraphConverters that create graphs
 /// that have 1:1 mapping between pixel and node.
-/// Structs implementing need to provide list of pixels which will be adjacent for given pixel.
+/// Pixels adjacent to given pixel are determined by the `Neighbourhood`.
 pub trait SpatialImageGraphConverter {
-    fn get_pixel_neighbours(pixel_map: &PixelMap, pixel: &Pixel) -> Vec<Pixel>;
-
     fn calc_distance_between_pixels(pixel_a: &Pixel, pixel_b: &Pixel) -> f32;
 
     fn calc_intensity_from_distance(distance: f32) -> u8;
@@ -22,35 +26,43 @@ pub trait SpatialImageGraphConverter {
         (pixel.y * pixel_map.width + pixel.x) as NodeId
     }
 
-    fn construct_graph(pixel_map: &PixelMap) -> Graph {
-        let nodes = pixel_map
+    fn construct_graph(pixel_map: &PixelMap, neighbourhood: Neighbourhood) -> Graph {
+        let node_ids = pixel_map
             .pixels()
             .iter()
-            .map(|pixel| {
-                let node_id = Self::pixel_to_id(pixel_map, pixel);
+            .map(|pixel| Self::pixel_to_id(pixel_map, pixel))
+            .collect();
 
-                let adjacency_list = Self::get_pixel_adjacency_list(pixel_map, pixel, node_id);
+        let edges = pixel_map
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let node_id = Self::pixel_to_id(pixel_map, pixel);
 
-                Node {
-                    id: node_id,
-                    adjacency_list,
-                }
+                Self::get_pixel_adjacency_list(pixel_map, pixel, node_id, neighbourhood)
             })
             .collect();
 
-        Graph::from_node_vector(nodes)
+        Graph::from_nodes_and_edges(node_ids, edges)
     }
 
+    /// Distance is divided by geometric distance between pixels,
+    /// so the same change spread over a longer span is less desirable
     fn get_pixel_adjacency_list(
         pixel_map: &PixelMap,
         pixel: &Pixel,
         node_id: NodeId,
+        neighbourhood: Neighbourhood,
     ) -> Vec<AdjacencyListEntry> {
-        Self::get_pixel_neighbours(pixel_map, pixel)
+        neighbourhood
+            .get_neighbours(pixel_map, pixel)
             .iter()
             .map(|neighbour_pixel| {
                 let neighbour_id = Self::pixel_to_id(pixel_map, neighbour_pixel);
-                let distance = 1.0 / Self::calc_distance_between_pixels(pixel, neighbour_pixel);
+                let geometric_distance =
+                    Neighbourhood::calc_geometric_distance(pixel, neighbour_pixel);
+                let distance =
+                    geometric_distance / Self::calc_distance_between_pixels(pixel, neighbour_pixel);
 
                 AdjacencyListEntry::new(node_id, neighbour_id, distance)
             })
@@ -65,7 +77,7 @@ pub trait SpatialImageGraphConverter {
 
         let intensity_sum: f32 = adjacent_edges
             .into_iter()
-            .map(|edge| pheromone.get_pheromone_for_edge(edge.key))
+            .map(|edge| pheromone.get_pheromone_for_edge(edge))
             .sum();
 
         (255.0 * intensity_sum / num_of_edges as f32) as u8
@@ -83,16 +95,40 @@ pub trait SpatialImageGraphConverter {
             let edges_adjacent_to_pixel = graph.get_adjacent_edges(&node_id);
             let intensity_level = Self::calculate_pixel_intensity_from_pheromone(
                 &pheromone_norm,
-                &edges_adjacent_to_pixel,
+                edges_adjacent_to_pixel,
             );
 
             Pixel::grey(pixel.x, pixel.y, intensity_level)
         })
     }
 
-    fn construct_conversion_visualization(pixel_map: &PixelMap) -> PixelMap {
+    /// Inverse of `construct_pheromone_visualization`, trail of an edge
+    /// is the mean intensity of the pixels it connects, None if the mask has other size
+    fn construct_pheromone_from_mask(mask: &PixelMap, graph: &Graph) -> Option<Pheromone> {
+        if mask.pixels().len() != graph.get_amount_of_nodes() {
+            return None;
+        }
+
+        let level = |node_id: NodeId| f32::from(mask.pixels()[node_id as usize].r);
+
+        let pheromone = graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(graph.get_edge_index()),
+            |pheromone, edge| {
+                let trail = (level(edge.from) + level(edge.to)) / (2.0 * 255.0);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, trail)
+            },
+        );
+
+        Some(pheromone)
+    }
+
+    fn construct_conversion_visualization(
+        pixel_map: &PixelMap,
+        neighbourhood: Neighbourhood,
+    ) -> PixelMap {
         pixel_map.map(|pixel| {
-            let neighbours = Self::get_pixel_neighbours(pixel_map, pixel);
+            let neighbours = neighbourhood.get_neighbours(pixel_map, pixel);
             let neighbour_count = neighbours.len();
             let distances: f32 = neighbours
                 .into_iter()
@@ -0,0 +1,89 @@
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+/// Pixels which are adjacent in graphs of spatial converters
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Neighbourhood {
+    /// pixels sharing a side
+    Four,
+    /// pixels sharing a side or a corner
+    Eight,
+    /// pixels within euclidean distance r, `Radius(1)` is the same as `Four`
+    Radius(usize),
+}
+
+impl Default for Neighbourhood {
+    fn default() -> Self {
+        Self::Four
+    }
+}
+
+impl Neighbourhood {
+    pub fn get_neighbours(&self, pixel_map: &PixelMap, pixel: &Pixel) -> Vec<Pixel> {
+        match self {
+            Self::Four => pixel_map.get_neighbours_4(pixel.x, pixel.y).collect(),
+            Self::Eight => pixel_map.get_neighbours_8(pixel.x, pixel.y).collect(),
+            Self::Radius(radius) => Self::get_offsets_within(*radius)
+                .into_iter()
+                .filter_map(|(dx, dy)| pixel_map.get_pixel_by_delta(pixel.x, pixel.y, dx, dy))
+                .collect(),
+        }
+    }
+
+    /// Euclidean distance between pixel coordinates
+    pub fn calc_geometric_distance(pixel_a: &Pixel, pixel_b: &Pixel) -> f32 {
+        let dx = pixel_a.x as f32 - pixel_b.x as f32;
+        let dy = pixel_a.y as f32 - pixel_b.y as f32;
+
+        (dx.powi(2) + dy.powi(2)).sqrt()
+    }
+
+    fn get_offsets_within(radius: usize) -> Vec<(isize, isize)> {
+        let radius = radius as isize;
+        let range = -radius..=radius;
+
+        range
+            .clone()
+            .flat_map(|dy| range.clone().map(move |dx| (dx, dy)))
+            .filter(|(dx, dy)| {
+                let dist = dx.pow(2) + dy.pow(2);
+
+                dist > 0 && dist <= radius.pow(2)
+            })
+            .collect()
+    }
+}
+
+impl FromStr for Neighbourhood {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = "Failed to parse neighbourhood";
+
+        match s {
+            "" | "4" => Ok(Self::Four),
+            "8" => Ok(Self::Eight),
+            _ => s
+                .strip_prefix("r=")
+                .ok_or(error)?
+                .parse::<usize>()
+                .ok()
+                .filter(|radius| *radius > 0)
+                .map(Self::Radius)
+                .ok_or(error),
+        }
+    }
+}
+
+impl Display for Neighbourhood {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Four => write!(f, "4"),
+            Self::Eight => write!(f, "8"),
+            Self::Radius(radius) => write!(f, "r={}", radius),
+        }
+    }
+}
@@ -4,13 +4,14 @@ use crate::images::image::Pixel;
 use crate::images::pixel_map::PixelMap;
 
 use super::super::{FromStrAndPixelMap, ImageGraphConverter};
-use super::SpatialImageGraphConverter;
+use super::{Neighbourhood, SpatialImageGraphConverter};
 
-/// Image-graph converted build around 4-kind neighbourhood
+/// Image-graph converted build around 4-kind neighbourhood (or 8-kind, or within given radius)
 /// Attributes distance proportional to pixel distance
 /// (the more pixels differ, the higher distance between them)
 pub struct SpatialEdgeChangeConverter {
     source_image: PixelMap,
+    neighbourhood: Neighbourhood,
     graph: Graph,
 }
 
@@ -18,9 +19,14 @@ const MAX_DISTANCE: f32 = 255.0 * 255.0 * 3.0;
 
 impl SpatialEdgeChangeConverter {
     pub fn new(pixel_map: &PixelMap) -> Self {
+        SpatialEdgeChangeConverter::with_neighbourhood(pixel_map, Neighbourhood::Four)
+    }
+
+    pub fn with_neighbourhood(pixel_map: &PixelMap, neighbourhood: Neighbourhood) -> Self {
         SpatialEdgeChangeConverter {
             source_image: pixel_map.clone(),
-            graph: Self::construct_graph(pixel_map),
+            neighbourhood,
+            graph: Self::construct_graph(pixel_map, neighbourhood),
         }
     }
 }
@@ -38,10 +44,6 @@ impl SpatialImageGraphConverter for SpatialEdgeChangeConverter {
     fn calc_intensity_from_distance(distance: f32) -> u8 {
         (distance * MAX_DISTANCE / 3.0).sqrt() as u8
     }
-
-    fn get_pixel_neighbours(pixel_map: &PixelMap, pixel: &Pixel) -> Vec<Pixel> {
-        pixel_map.get_neighbours_4(pixel.x, pixel.y).collect()
-    }
 }
 
 impl ImageGraphConverter for SpatialEdgeChangeConverter {
@@ -53,13 +55,22 @@ impl ImageGraphConverter for SpatialEdgeChangeConverter {
         Self::construct_pheromone_visualization(&self.source_image, &self.graph, pheromone)
     }
 
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        Self::construct_pheromone_from_mask(mask, &self.graph)
+    }
+
     fn visualize_conversion(&self) -> Option<PixelMap> {
-        Some(Self::construct_conversion_visualization(&self.source_image))
+        Some(Self::construct_conversion_visualization(
+            &self.source_image,
+            self.neighbourhood,
+        ))
     }
 }
 
 impl FromStrAndPixelMap for SpatialEdgeChangeConverter {
-    fn from_str_and_pixel_map(pixel_map: &PixelMap, _: &str) -> Option<Self> {
-        Some(Self::new(pixel_map))
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, opts: &str) -> Option<Self> {
+        let neighbourhood = opts.parse::<Neighbourhood>().ok()?;
+
+        Some(Self::with_neighbourhood(pixel_map, neighbourhood))
     }
 }
@@ -0,0 +1,186 @@
+use crate::ant_colony::graph::{AdjacencyListEntry, EdgeMode, Graph, NodeId};
+use crate::ant_colony::pheromone::Pheromone;
+use crate::common::utils::compare_float;
+use crate::images::image::Pixel;
+use crate::images::pixel_map::PixelMap;
+
+use super::super::{FromStrAndPixelMap, ImageGraphConverter};
+
+/// Pairs of pixels opposite to each other in the 5x5 clique around the central pixel
+const CLIQUE_OFFSETS: [(isize, isize); 8] = [
+    (-2, -1),
+    (-2, 1),
+    (-1, -2),
+    (-1, 2),
+    (-1, -1),
+    (-1, 1),
+    (-1, 0),
+    (0, -1),
+];
+
+/// ACO edge detection (`Tian2008EdgeDetection`).
+///
+/// Ants move between 8-neighbouring pixels, desirability of a pixel
+/// is the intensity variation over its clique, normalized to the highest one
+///         V_c(I_{x,y}) = \sum_{(dx, dy)} |I_{x-dx,y-dy} - I_{x+dx,y+dy}|
+///
+/// In the original method trails are deposited on pixels, not on pairs of them.
+/// Here graph is directed and each edge takes the distance of the pixel it leads to,
+/// trail of a pixel is the sum of trails of edges entering it.
+pub struct TianEdgeDetectionConverter {
+    source_image: PixelMap,
+    variations: Vec<f32>,
+    graph: Graph,
+}
+
+impl TianEdgeDetectionConverter {
+    pub fn new(pixel_map: &PixelMap) -> Self {
+        let variations = Self::calc_variations(pixel_map);
+        let graph = Self::construct_graph(pixel_map, &variations);
+
+        TianEdgeDetectionConverter {
+            source_image: pixel_map.clone(),
+            variations,
+            graph,
+        }
+    }
+
+    pub fn get_variations(&self) -> &[f32] {
+        &self.variations
+    }
+
+    fn pixel_to_id(pixel_map: &PixelMap, pixel: &Pixel) -> NodeId {
+        (pixel.y * pixel_map.width + pixel.x) as NodeId
+    }
+
+    fn calc_variation(pixel_map: &PixelMap, pixel: &Pixel) -> f32 {
+        CLIQUE_OFFSETS
+            .iter()
+            .filter_map(|(dx, dy)| {
+                let a = pixel_map.get_pixel_by_delta(pixel.x, pixel.y, -dx, -dy)?;
+                let b = pixel_map.get_pixel_by_delta(pixel.x, pixel.y, *dx, *dy)?;
+
+                Some((f32::from(a.intensity()) - f32::from(b.intensity())).abs())
+            })
+            .sum()
+    }
+
+    fn calc_variations(pixel_map: &PixelMap) -> Vec<f32> {
+        let variations = pixel_map
+            .pixels()
+            .iter()
+            .map(|pixel| Self::calc_variation(pixel_map, pixel))
+            .collect::<Vec<_>>();
+
+        let max = variations
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .filter(|max| *max > 0.0)
+            .unwrap_or(1.0);
+
+        variations.into_iter().map(|val| val / max).collect()
+    }
+
+    fn construct_graph(pixel_map: &PixelMap, variations: &[f32]) -> Graph {
+        let edges = pixel_map
+            .pixels()
+            .iter()
+            .flat_map(|pixel| {
+                let node_id = Self::pixel_to_id(pixel_map, pixel);
+
+                pixel_map
+                    .get_neighbours_8(pixel.x, pixel.y)
+                    .map(|neighbour| {
+                        let neighbour_id = Self::pixel_to_id(pixel_map, &neighbour);
+                        let variation = variations[neighbour_id as usize];
+
+                        AdjacencyListEntry::new_directed(
+                            node_id,
+                            neighbour_id,
+                            1.0 / (variation + stability_factor!()),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+
+        Graph::from_directed_edges(edges)
+    }
+
+    fn calc_pixel_trail(&self, pheromone: &Pheromone, pixel: &Pixel) -> f32 {
+        let node_id = Self::pixel_to_id(&self.source_image, pixel);
+
+        self.source_image
+            .get_neighbours_8(pixel.x, pixel.y)
+            .map(|neighbour| {
+                let neighbour_id = Self::pixel_to_id(&self.source_image, &neighbour);
+                let key = EdgeMode::Directed.generate_key(neighbour_id, node_id);
+
+                pheromone.get_pheromone_for_edge(key)
+            })
+            .sum()
+    }
+}
+
+impl ImageGraphConverter for TianEdgeDetectionConverter {
+    fn img_to_graph(&self) -> Graph {
+        self.graph.clone()
+    }
+
+    fn visualize_pheromone(&self, pheromone: &Pheromone) -> PixelMap {
+        let trails = self
+            .source_image
+            .pixels()
+            .iter()
+            .map(|pixel| self.calc_pixel_trail(pheromone, pixel))
+            .collect::<Vec<_>>();
+
+        let max = trails
+            .iter()
+            .cloned()
+            .max_by(compare_float)
+            .filter(|max| *max > 0.0)
+            .unwrap_or(1.0);
+
+        self.source_image.map(|pixel| {
+            let node_id = Self::pixel_to_id(&self.source_image, pixel);
+            let intensity = 255.0 * trails[node_id as usize] / max;
+
+            Pixel::grey(pixel.x, pixel.y, intensity as u8)
+        })
+    }
+
+    /// Each edge takes intensity of the pixel it leads to
+    fn pheromone_from_mask(&self, mask: &PixelMap) -> Option<Pheromone> {
+        if (mask.width, mask.height) != (self.source_image.width, self.source_image.height) {
+            return None;
+        }
+
+        let pheromone = self.graph.get_all_edges().iter().fold(
+            Pheromone::with_edge_index(self.graph.get_edge_index()),
+            |pheromone, edge| {
+                let level = f32::from(mask.pixels()[edge.to as usize].r);
+
+                pheromone.initialize_pheromone_for_edge(edge.key, level / 255.0)
+            },
+        );
+
+        Some(pheromone)
+    }
+
+    fn visualize_conversion(&self) -> Option<PixelMap> {
+        Some(self.source_image.map(|pixel| {
+            let node_id = Self::pixel_to_id(&self.source_image, pixel);
+            let intensity = 255.0 * self.variations[node_id as usize];
+
+            Pixel::grey(pixel.x, pixel.y, intensity as u8)
+        }))
+    }
+}
+
+impl FromStrAndPixelMap for TianEdgeDetectionConverter {
+    fn from_str_and_pixel_map(pixel_map: &PixelMap, _: &str) -> Option<Self> {
+        Some(Self::new(pixel_map))
+    }
+}
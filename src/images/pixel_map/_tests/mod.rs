@@ -118,6 +118,49 @@ mod images_pixel_map_tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn it_maps_pixels_along_with_labels() {
+        let map = mock_image();
+        let labels = (0..16).map(|idx| idx / 8).collect::<Vec<_>>();
+
+        let result = map.map_with_labels(&labels, |pixel, label| {
+            Pixel::new(pixel.x, pixel.y, label as u8, pixel.g, pixel.b)
+        });
+
+        assert_eq!(result.pixels()[7], Pixel::new(3, 1, 0, 0, 80));
+        assert_eq!(result.pixels()[8], Pixel::new(0, 2, 1, 0, 90));
+        assert_eq!((result.width, result.height), (map.width, map.height));
+    }
+
+    #[test]
+    fn it_builds_pyramid_from_coarsest_level() {
+        let map = mock_image();
+
+        let pyramid = map.pyramid(3);
+        let sizes = pyramid
+            .iter()
+            .map(|level| (level.width, level.height))
+            .collect::<Vec<_>>();
+
+        assert_eq!(sizes, vec![(1, 1), (2, 2), (4, 4)]);
+        assert_eq!(pyramid[2], map);
+        assert_eq!(map.pyramid(0), vec![map.clone()]);
+        assert_eq!(map.pyramid(5)[0].width, 1);
+    }
+
+    #[test]
+    fn it_blends_with_other_map() {
+        let map = mock_image();
+        let black = map.map(|pixel| Pixel::black(pixel.x, pixel.y));
+
+        let result = map.blend(&black, 0.25);
+
+        assert_eq!(result.pixels()[0], Pixel::new(0, 0, 0, 0, 8));
+        assert_eq!(result.pixels()[15], Pixel::new(3, 3, 0, 0, 113));
+        assert_eq!(map.blend(&black, 0.0), map);
+        assert_eq!(map.blend(&black, 1.0), black);
+    }
+
     #[test]
     fn it_allows_for_iterating_windows_of_eql_size() {
         let map = mock_image();
@@ -40,6 +40,22 @@ impl PixelMap {
         PixelMap::new(self.height, self.width, pixels)
     }
 
+    /// Maps pixels along with labels of a segmentation, given in the same order as pixels
+    pub fn map_with_labels<F: Fn(&Pixel, usize) -> Pixel>(
+        &self,
+        labels: &[usize],
+        mapper: F,
+    ) -> PixelMap {
+        let pixels = self
+            .pixels()
+            .iter()
+            .zip(labels.iter().cloned())
+            .map(|(pixel, label)| mapper(pixel, label))
+            .collect();
+
+        PixelMap::new(self.height, self.width, pixels)
+    }
+
     pub fn scale(&self, scaler: f32) -> PixelMap {
         self.map(|pixel| pixel.scale(scaler))
     }
@@ -54,10 +70,48 @@ impl PixelMap {
             .into_pixel_map()
     }
 
+    /// Maps from the coarsest one (width and height halved per level) up to this one
+    pub fn pyramid(&self, n_levels: usize) -> Vec<PixelMap> {
+        (0..n_levels.max(1))
+            .rev()
+            .map(|level| {
+                let scale = 1usize.checked_shl(level as u32).unwrap_or(usize::MAX);
+
+                iif!(
+                    level == 0,
+                    self.clone(),
+                    self.resize((self.width / scale).max(1), (self.height / scale).max(1))
+                )
+            })
+            .collect()
+    }
+
     pub fn invert(&self) -> Self {
         self.map(Pixel::invert)
     }
 
+    /// Moves pixels towards the other map, which is resized if dimensions differ
+    pub fn blend(&self, other: &PixelMap, weight: f32) -> Self {
+        let other = iif!(
+            self.width == other.width && self.height == other.height,
+            other.clone(),
+            other.resize(self.width, self.height)
+        );
+        let pixels = self
+            .pixels
+            .iter()
+            .zip(other.pixels.iter())
+            .map(|(pixel, other_pixel)| pixel.blend(other_pixel, weight))
+            .collect();
+
+        PixelMap::new(self.height, self.width, pixels)
+    }
+
+    /// Grey image with luminance of each pixel
+    pub fn to_luminance(&self) -> Self {
+        self.map(|pixel| Pixel::grey(pixel.x, pixel.y, pixel.luminance()))
+    }
+
     pub fn get_neighbours_4(&self, x: usize, y: usize) -> impl Iterator<Item = Pixel> {
         self.get_pixel_by_delta(x, y, 0, -1)
             .into_iter()
@@ -92,6 +146,17 @@ impl PixelMap {
             .cloned()
     }
 
+    /// Same as `get_pixel_by_delta`, but coordinates outside of the image
+    /// are clamped to its border (border pixels are replicated)
+    pub fn get_pixel_by_delta_clamped(&self, x: usize, y: usize, dx: isize, dy: isize) -> Pixel {
+        let max_x = self.width as isize - 1;
+        let max_y = self.height as isize - 1;
+        let pixel_x = (x as isize + dx).min(max_x).max(0) as usize;
+        let pixel_y = (y as isize + dy).min(max_y).max(0) as usize;
+
+        self.pixels[pixel_y * self.width + pixel_x]
+    }
+
     pub fn windows(&self, n_x_windows: usize, n_y_windows: usize) -> PixelMapWindows {
         PixelMapWindows::new(self, n_x_windows, n_y_windows)
     }
@@ -55,6 +55,11 @@ impl PixelMapClusters {
             })
     }
 
+    /// Cluster of every pixel, in the order of pixels of the image
+    pub fn get_clusters(&self) -> &Clusters {
+        &self.clusters
+    }
+
     pub fn map_pixels<F: Fn(&Pixel, ClusterId, ClusterMean) -> Pixel>(
         &self,
         mapper: F,
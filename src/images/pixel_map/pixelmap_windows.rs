@@ -53,6 +53,11 @@ impl PixelMapWindows {
     }
 
     pub fn map_pixels<F: Fn(&Pixel, WindowId) -> Pixel>(&self, mapper: F) -> PixelMap {
+        self.image.map_with_labels(&self.get_window_ids(), mapper)
+    }
+
+    /// Window of every pixel, in the order of pixels of the image
+    pub fn get_window_ids(&self) -> Vec<WindowId> {
         let offsets = Self::generate_offsets(
             self.image.width,
             self.image.height,
@@ -61,15 +66,17 @@ impl PixelMapWindows {
         )
         .collect::<Vec<_>>();
 
-        self.image.map(|pixel| {
-            let current_window_idx = offsets
-                .iter()
-                .find(|offsets| Self::is_pixel_in_offset(pixel, offsets))
-                .map(|(idx, _, _)| *idx)
-                .unwrap_or_default();
-
-            mapper(pixel, current_window_idx)
-        })
+        self.image
+            .pixels()
+            .iter()
+            .map(|pixel| {
+                offsets
+                    .iter()
+                    .find(|offsets| Self::is_pixel_in_offset(pixel, offsets))
+                    .map(|(idx, _, _)| *idx)
+                    .unwrap_or_default()
+            })
+            .collect()
     }
 
     pub fn generate_offsets(
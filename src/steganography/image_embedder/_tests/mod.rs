@@ -1,4 +1,5 @@
 mod _mocks;
+mod round_trip;
 
 #[cfg(test)]
 mod image_embedder_tests {
@@ -0,0 +1,119 @@
+#[cfg(test)]
+mod round_trip_tests {
+    use rand::{prelude::StdRng, SeedableRng};
+    use std::sync::Arc;
+
+    use crate::ant_colony::ant_dispatcher::BiasedAntDispatcher;
+    use crate::ant_colony::colony::{
+        Colony, Config, ConfigurableColony, StagnationCriterion, StepwiseParallelColony,
+    };
+    use crate::ant_colony::pheromone_updater::{
+        CyclicalPheromoneUpdater, LocalColonyPheromoneUpdater, PheromoneUpdater,
+    };
+    use crate::ant_colony::problem::Problems;
+    use crate::images::image::Pixel;
+    use crate::images::image_graph_converter::{ImageGraphConverter, SpatialEdgeChangeConverter};
+    use crate::images::pixel_map::PixelMap;
+    use crate::steganography::data::Data;
+
+    use super::super::super::{EmbedInImage, MaskImageEmbedder};
+
+    const SIZE: usize = 16;
+
+    fn mock_transport_image() -> PixelMap {
+        let pixels = (0..SIZE)
+            .flat_map(|y| (0..SIZE).map(move |x| (x, y)))
+            .map(|(x, y)| {
+                let value = ((x * 37 + y * 91 + x * y * 13) % 256) as u8;
+
+                Pixel::new(x, y, value, value / 2, 255 - value)
+            })
+            .collect();
+
+        PixelMap::new(SIZE, SIZE, pixels)
+    }
+
+    /// Whole mask generation, the same as `embed` and `extract` perform
+    fn generate_mask<U: PheromoneUpdater>(
+        transport_image: &PixelMap,
+        pheromone_updater: U,
+    ) -> PixelMap {
+        let converter = SpatialEdgeChangeConverter::new(transport_image);
+        let graph = Arc::new(converter.img_to_graph());
+
+        let config = Config {
+            ant_count: 50,
+            num_of_steps_per_cycle: 20,
+            pheromone_updater,
+            ant_dispatcher: BiasedAntDispatcher::new(1.0, 1.0),
+            rng: StdRng::seed_from_u64(42),
+            stagnation: StagnationCriterion::Never,
+            problem: Problems::default(),
+        };
+
+        let colony = StepwiseParallelColony::new(config, graph).execute_n_cycles(3);
+
+        converter.visualize_pheromone(colony.get_pheromone())
+    }
+
+    /// Ants without local updates move in parallel, ants with local updates
+    /// select edges in parallel and some of them select again in order of their indices
+    fn generate_mask_with_threads(
+        transport_image: &PixelMap,
+        n_threads: usize,
+        local_update: bool,
+    ) -> PixelMap {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(n_threads)
+            .build()
+            .unwrap()
+            .install(|| {
+                iif!(
+                    local_update,
+                    generate_mask(
+                        transport_image,
+                        LocalColonyPheromoneUpdater::new(0.1, 0.1, 0.1, 20)
+                    ),
+                    generate_mask(
+                        transport_image,
+                        CyclicalPheromoneUpdater::new(0.1, 0.1, 0.1, 20)
+                    )
+                )
+            })
+    }
+
+    #[test]
+    fn it_extracts_data_embedded_with_different_number_of_threads() {
+        let transport_image = mock_transport_image();
+        let data = Data::from_bytes(b"ants");
+
+        for local_update in &[false, true] {
+            let embedding_mask = generate_mask_with_threads(&transport_image, 4, *local_update);
+            let steganogram = MaskImageEmbedder::new(&embedding_mask)
+                .embed(&mut data.iter_bits(), &transport_image);
+
+            let extraction_mask = generate_mask_with_threads(&transport_image, 1, *local_update);
+            let extracted = MaskImageEmbedder::new(&extraction_mask).extract(&steganogram);
+
+            assert_eq!(extraction_mask, embedding_mask);
+            assert_eq!(extracted, data);
+        }
+    }
+
+    /// Run also with `--features singlethread`, where the same masks are generated sequentially
+    #[test]
+    fn it_generates_the_same_mask_regardless_of_number_of_threads() {
+        let transport_image = mock_transport_image();
+
+        for local_update in &[false, true] {
+            let single_thread_mask = generate_mask_with_threads(&transport_image, 1, *local_update);
+
+            for n_threads in &[2, 4, 8] {
+                assert_eq!(
+                    generate_mask_with_threads(&transport_image, *n_threads, *local_update),
+                    single_thread_mask
+                );
+            }
+        }
+    }
+}